@@ -0,0 +1,1159 @@
+use anyhow::Result;
+use std::path::PathBuf;
+
+use crate::error::{CliError, ExitCode, EXIT_CODES_HELP};
+use crate::keys::DEFAULT_SS58_FORMAT;
+use crate::theme::Theme;
+use crate::timestamps::Zone;
+use crate::tui::leaderboard::TableLayout;
+use crate::tui::DEFAULT_REFRESH_SECS;
+use crate::views::export::ExportFormat;
+
+pub const USAGE: &str = "\
+Usage: bounty-cli [OPTIONS] [COMMAND]
+
+Without a command, starts the interactive menu.
+
+Commands:
+  status <HOTKEY>       Print the status of a hotkey and exit
+  leaderboard           Open the live leaderboard
+  stats                 Print challenge stats
+  register              Register a GitHub username without prompting
+  issues                List synced issues
+  export                Export all miner data (sudo owner only)
+  import <FILE>         Restore a JSON export (sudo owner only)
+  verify-snapshot <FILE>
+                        Check the signature of a saved signed snapshot
+  whoami                Print the hotkey a mnemonic derives to (offline)
+
+Status options:
+      --json            Print the raw status as JSON
+      --explain         Explain in prose how the weight is derived
+      --fail-if-unregistered
+                        With --json, exit 3 if the hotkey is not registered
+
+Leaderboard options:
+      --sort <FIELD>    Order by: score, valid, invalid, stars, net
+      --json            Print the leaderboard as JSON (a snapshot for --diff)
+      --signed          With --json, sign the snapshot with the key in
+                        --mnemonic-file, for verify-snapshot
+      --group-by github Combine hotkeys linked to the same GitHub user
+      --diff <PATH>     Show rank and point changes since a saved snapshot
+      --top-movers      Show the biggest net-point gains and losses
+      --window <DAYS>   Window for --top-movers, e.g. 7d (1d-30d) [default: 7d]
+      --layout <LAYOUT> Table columns: compact, normal, wide
+                        [default: compact under 80 columns, else normal]
+      --follow          Reprint a plain table every --interval seconds until
+                        Ctrl-C (NDJSON with --json)
+      --interval <SECS> Seconds between reprints with --follow [default: 5]
+      --no-pager        Print --group-by and --diff tables without paging
+                        (they are paged when taller than the terminal)
+      --csv-out <PATH>  Write the leaderboard as CSV to a file, replaced
+                        atomically; the first line records the fetch time
+      --json-out <PATH> Write the leaderboard as JSON to a file, replaced
+                        atomically, with the fetch time (usable with --diff)
+
+Stats options:
+      --watch           Re-fetch and reprint until Ctrl-C
+      --interval <SECS> Seconds between polls with --watch [default: 5]
+      --json            Print JSON (one object per line with --watch)
+      --detailed        Add issue pipeline counts: open, closed, valid,
+                        invalid, pending, credited and unclaimed
+
+Issues options:
+      --state <STATE>   Filter: all, open, closed, valid, invalid [default: all]
+  -n, --limit <N>       Show at most N issues
+      --offset <N>      Skip the first N matching issues
+
+Register options:
+      --github <USER>   GitHub username to register
+      --mnemonic-file <PATH>
+                        Read the mnemonic from a file (\"-\" for stdin) and sign locally
+      --hotkey <SS58>   Pre-signed registration: hotkey
+      --signature <HEX> Pre-signed registration: signature over
+                        register_github:{github_lowercase}:{timestamp}
+      --timestamp <SECS>
+                        Pre-signed registration: signed timestamp
+
+Export options:
+      --format <FORMAT> Output format: json, csv [default: json]
+      --output <PATH>   Write to a file instead of stdout
+      --mnemonic-file <PATH>
+                        The sudo owner's mnemonic (\"-\" for stdin)
+
+Import options:
+      --force           Import even if miners are already registered
+      --mnemonic-file <PATH>
+                        The sudo owner's mnemonic (\"-\" for stdin)
+
+Whoami options:
+      --mnemonic-file <PATH>
+                        Read the mnemonic from a file (\"-\" for stdin)
+                        [default: prompt, or stdin when piped]
+
+Options:
+      --no-color        Disable colored output (also honors NO_COLOR)
+      --theme <THEME>   Color theme: default, colorblind
+      --ascii           Draw borders and bars with ASCII characters
+                        (auto-detected from LANG and the Windows console)
+      --absolute        Show full timestamps instead of \"3 hours ago\"
+      --timezone <TZ>   Zone for --absolute: UTC, local, an offset like +02:00
+                        or an IANA name like Europe/Berlin [default: UTC]
+      --local           Same as --timezone local
+      --insecure        Skip TLS certificate verification (unsafe)
+      --ca-cert <PATH>  Trust an additional PEM root certificate
+      --proxy <URL>     Send all requests through this proxy
+                        [default: from HTTP_PROXY/HTTPS_PROXY/NO_PROXY]
+      --refresh <SECS>  Auto-refresh interval of the live dashboards [default: 5]
+      --check-version   Warn if the server API version is incompatible
+                        (always checked in the interactive menu)
+      --dry-run         Print the first RPC request the command would send
+                        (method, path, headers and body) and exit 0 without
+                        sending it
+      --ss58-format <N> Network prefix of derived hotkey addresses
+                        [default: BOUNTY_SS58_FORMAT, else 42]
+  -h, --help            Print help";
+
+pub const SORT_FIELDS: &[&str] = &["score", "valid", "invalid", "stars", "net"];
+pub const DEFAULT_WATCH_INTERVAL_SECS: u64 = 5;
+pub const ISSUE_STATES: &[&str] = &["all", "open", "closed", "valid", "invalid"];
+/// Environment fallback of `--ss58-format`.
+pub const SS58_FORMAT_ENV: &str = "BOUNTY_SS58_FORMAT";
+
+pub enum Command {
+    Status {
+        hotkey: String,
+        json: bool,
+        explain: bool,
+        fail_if_unregistered: bool,
+    },
+    Leaderboard {
+        sort: Option<String>,
+        json: bool,
+        diff: Option<PathBuf>,
+        /// `Some(window)` for `--top-movers`.
+        top_movers: Option<String>,
+        layout: Option<TableLayout>,
+        /// `--json --signed`: sign the snapshot with the mnemonic in this
+        /// file and print the envelope.
+        signed: Option<PathBuf>,
+        /// `--group-by github`: one row per GitHub user.
+        group_by_github: bool,
+        /// `--no-pager`: never page table output.
+        no_pager: bool,
+        /// `Some(interval_secs)` for `--follow`.
+        follow: Option<u64>,
+        /// `--csv-out`: write a CSV file instead of printing.
+        csv_out: Option<PathBuf>,
+        /// `--json-out`: write a JSON file instead of printing.
+        json_out: Option<PathBuf>,
+    },
+    Stats {
+        watch: bool,
+        interval_secs: u64,
+        json: bool,
+        /// `--detailed`: add the `/issues/stats` counts.
+        detailed: bool,
+    },
+    Register(RegisterArgs),
+    Issues {
+        state: Option<String>,
+        limit: Option<u64>,
+        offset: Option<u64>,
+    },
+    Export {
+        format: ExportFormat,
+        output: Option<PathBuf>,
+        mnemonic_file: PathBuf,
+    },
+    Import {
+        file: PathBuf,
+        force: bool,
+        mnemonic_file: PathBuf,
+    },
+    VerifySnapshot {
+        file: PathBuf,
+    },
+    Whoami {
+        /// `None` prompts for the mnemonic, or reads piped stdin.
+        mnemonic_file: Option<PathBuf>,
+    },
+}
+
+pub struct RegisterArgs {
+    pub github: String,
+    pub auth: RegisterAuth,
+}
+
+pub enum RegisterAuth {
+    /// Derive the hotkey from a mnemonic read from a file (`-` for stdin).
+    MnemonicFile { path: PathBuf },
+    /// Submit a signature produced elsewhere.
+    Signed {
+        hotkey: String,
+        signature: Vec<u8>,
+        timestamp: i64,
+    },
+}
+
+/// Raw register flags as given on the command line.
+#[derive(Default)]
+struct RegisterFlags {
+    github: Option<String>,
+    mnemonic_file: Option<PathBuf>,
+    hotkey: Option<String>,
+    signature: Option<String>,
+    timestamp: Option<String>,
+}
+
+impl RegisterFlags {
+    fn any(&self) -> bool {
+        self.github.is_some()
+            || self.mnemonic_file.is_some()
+            || self.hotkey.is_some()
+            || self.signature.is_some()
+            || self.timestamp.is_some()
+    }
+
+    fn build(self) -> Result<RegisterArgs> {
+        let github = match self.github {
+            Some(g) if !g.trim().is_empty() => g.trim().to_string(),
+            _ => return Err(invalid("register requires --github <USER>".to_string())),
+        };
+        let signed_given =
+            self.hotkey.is_some() || self.signature.is_some() || self.timestamp.is_some();
+
+        let auth = match self.mnemonic_file {
+            Some(_) if signed_given => {
+                return Err(invalid(
+                    "Use either --mnemonic-file or --hotkey/--signature/--timestamp, not both"
+                        .to_string(),
+                ))
+            }
+            Some(path) => RegisterAuth::MnemonicFile { path },
+            None => {
+                let (hotkey, signature, timestamp) =
+                    match (self.hotkey, self.signature, self.timestamp) {
+                        (Some(h), Some(s), Some(t)) => (h, s, t),
+                        _ => {
+                            return Err(invalid(
+                                "register requires --mnemonic-file, or all of --hotkey, \
+                                 --signature and --timestamp"
+                                    .to_string(),
+                            ))
+                        }
+                    };
+                let signature = match hex::decode(signature.trim_start_matches("0x")) {
+                    Ok(bytes) if bytes.len() == 64 => bytes,
+                    _ => return Err(invalid("--signature must be 64 bytes of hex".to_string())),
+                };
+                let timestamp = match timestamp.parse::<i64>() {
+                    Ok(t) if t > 0 => t,
+                    _ => {
+                        return Err(invalid(format!(
+                            "--timestamp must be a positive unix timestamp, got '{}'",
+                            timestamp
+                        )))
+                    }
+                };
+                RegisterAuth::Signed {
+                    hotkey,
+                    signature,
+                    timestamp,
+                }
+            }
+        };
+
+        Ok(RegisterArgs { github, auth })
+    }
+}
+
+pub struct CliOptions {
+    pub no_color: bool,
+    pub ascii: bool,
+    pub theme: Theme,
+    pub zone: Zone,
+    pub absolute: bool,
+    pub insecure: bool,
+    pub ca_cert: Option<PathBuf>,
+    pub proxy: Option<String>,
+    pub check_version: bool,
+    pub dry_run: bool,
+    pub refresh_secs: u64,
+    /// `--ss58-format`; see [`resolve_ss58_format`].
+    pub ss58_format: Option<u16>,
+    pub command: Option<Command>,
+}
+
+fn invalid(message: String) -> anyhow::Error {
+    CliError::new(ExitCode::InvalidInput, format!("{}\n\n{}", message, USAGE)).into()
+}
+
+/// Value of a flag given as `--flag value` or `--flag=value`.
+fn require(
+    flag: &str,
+    inline_value: Option<String>,
+    args: &mut impl Iterator<Item = String>,
+) -> Result<String> {
+    match inline_value.or_else(|| args.next()) {
+        Some(v) => Ok(v),
+        None => Err(invalid(format!("{} requires a value", flag))),
+    }
+}
+
+fn parse_ss58_format(source: &str, value: &str) -> Result<u16> {
+    match value.trim().parse::<u16>() {
+        // Prefixes are 14-bit.
+        Ok(n) if n < 16384 => Ok(n),
+        _ => Err(invalid(format!(
+            "{} must be a network prefix from 0 to 16383, got '{}'",
+            source, value
+        ))),
+    }
+}
+
+/// The prefix of derived hotkey addresses: `--ss58-format`, else the
+/// `BOUNTY_SS58_FORMAT` value `env`, else the generic prefix.
+pub fn resolve_ss58_format(flag: Option<u16>, env: Option<String>) -> Result<u16> {
+    match (flag, env) {
+        (Some(format), _) => Ok(format),
+        (None, Some(value)) if !value.trim().is_empty() => {
+            parse_ss58_format(SS58_FORMAT_ENV, &value)
+        }
+        _ => Ok(DEFAULT_SS58_FORMAT),
+    }
+}
+
+fn parse_count(flag: &str, value: String) -> Result<u64> {
+    value.parse::<u64>().map_err(|_| {
+        invalid(format!(
+            "{} must be a non-negative integer, got '{}'",
+            flag, value
+        ))
+    })
+}
+
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<CliOptions> {
+    let mut opts = CliOptions {
+        no_color: false,
+        ascii: false,
+        theme: Theme::Default,
+        zone: Zone::Utc,
+        absolute: false,
+        insecure: false,
+        ca_cert: None,
+        proxy: None,
+        check_version: false,
+        dry_run: false,
+        refresh_secs: DEFAULT_REFRESH_SECS,
+        ss58_format: None,
+        command: None,
+    };
+    let mut positional: Vec<String> = Vec::new();
+    let mut sort: Option<String> = None;
+    let mut watch = false;
+    let mut detailed = false;
+    let mut interval_secs: Option<u64> = None;
+    let mut json = false;
+    let mut fail_if_unregistered = false;
+    let mut explain = false;
+    let mut signed = false;
+    let mut group_by_github = false;
+    let mut no_pager = false;
+    let mut follow = false;
+    let mut register = RegisterFlags::default();
+    let mut issue_state: Option<String> = None;
+    let mut issue_limit: Option<u64> = None;
+    let mut issue_offset: Option<u64> = None;
+    let mut diff: Option<PathBuf> = None;
+    let mut csv_out: Option<PathBuf> = None;
+    let mut json_out: Option<PathBuf> = None;
+    let mut top_movers = false;
+    let mut window: Option<String> = None;
+    let mut layout: Option<TableLayout> = None;
+    let mut export_format: Option<ExportFormat> = None;
+    let mut output: Option<PathBuf> = None;
+    let mut force = false;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        if !arg.starts_with('-') {
+            positional.push(arg);
+            continue;
+        }
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((f, v)) => (f.to_string(), Some(v.to_string())),
+            None => (arg.clone(), None),
+        };
+        match flag.as_str() {
+            "--no-color" => opts.no_color = true,
+            "--ascii" => opts.ascii = true,
+            "--theme" => {
+                let value = match inline_value.or_else(|| args.next()) {
+                    Some(v) => v,
+                    None => return Err(invalid("--theme requires a value".to_string())),
+                };
+                opts.theme = match Theme::parse(&value) {
+                    Some(t) => t,
+                    None => return Err(invalid(format!("Unknown theme '{}'", value))),
+                };
+            }
+            "--absolute" => opts.absolute = true,
+            "--local" => opts.zone = Zone::Local,
+            "--timezone" => {
+                let value = require(&flag, inline_value, &mut args)?;
+                opts.zone = Zone::parse(&value).map_err(invalid)?;
+            }
+            "--insecure" => opts.insecure = true,
+            "--check-version" => opts.check_version = true,
+            "--dry-run" => opts.dry_run = true,
+            "--refresh" => {
+                let value = require(&flag, inline_value, &mut args)?;
+                opts.refresh_secs = match value.parse::<u64>() {
+                    Ok(n) if n > 0 => n,
+                    _ => {
+                        return Err(invalid(format!(
+                            "--refresh must be a positive number of seconds, got '{}'",
+                            value
+                        )))
+                    }
+                };
+            }
+            "--ca-cert" => match inline_value.or_else(|| args.next()) {
+                Some(v) => opts.ca_cert = Some(PathBuf::from(v)),
+                None => return Err(invalid("--ca-cert requires a path".to_string())),
+            },
+            "--ss58-format" => {
+                let value = require(&flag, inline_value, &mut args)?;
+                opts.ss58_format = Some(parse_ss58_format(&flag, &value)?);
+            }
+            "--proxy" => opts.proxy = Some(require(&flag, inline_value, &mut args)?),
+            "--sort" => {
+                let value = match inline_value.or_else(|| args.next()) {
+                    Some(v) => v,
+                    None => return Err(invalid("--sort requires a value".to_string())),
+                };
+                if !SORT_FIELDS.contains(&value.as_str()) {
+                    return Err(invalid(format!("Unknown sort field '{}'", value)));
+                }
+                sort = Some(value);
+            }
+            "--watch" => watch = true,
+            "--detailed" => detailed = true,
+            "--json" => json = true,
+            "--fail-if-unregistered" => fail_if_unregistered = true,
+            "--explain" => explain = true,
+            "--signed" => signed = true,
+            "--no-pager" => no_pager = true,
+            "--follow" => follow = true,
+            "--group-by" => {
+                let value = require(&flag, inline_value, &mut args)?;
+                if value != "github" {
+                    return Err(invalid(format!(
+                        "Unknown --group-by field '{}' (expected github)",
+                        value
+                    )));
+                }
+                group_by_github = true;
+            }
+            "--interval" => {
+                let value = match inline_value.or_else(|| args.next()) {
+                    Some(v) => v,
+                    None => return Err(invalid("--interval requires a value".to_string())),
+                };
+                interval_secs = match value.parse::<u64>() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => {
+                        return Err(invalid(format!(
+                            "--interval must be a positive number of seconds, got '{}'",
+                            value
+                        )))
+                    }
+                };
+            }
+            "--github" => register.github = Some(require(&flag, inline_value, &mut args)?),
+            "--hotkey" => register.hotkey = Some(require(&flag, inline_value, &mut args)?),
+            "--signature" => register.signature = Some(require(&flag, inline_value, &mut args)?),
+            "--timestamp" => register.timestamp = Some(require(&flag, inline_value, &mut args)?),
+            "--mnemonic-file" => {
+                register.mnemonic_file =
+                    Some(PathBuf::from(require(&flag, inline_value, &mut args)?))
+            }
+            "--state" => {
+                let value = require(&flag, inline_value, &mut args)?;
+                if !ISSUE_STATES.contains(&value.as_str()) {
+                    return Err(invalid(format!("Unknown issue state '{}'", value)));
+                }
+                issue_state = Some(value);
+            }
+            "-n" | "--limit" => {
+                issue_limit = Some(parse_count(
+                    &flag,
+                    require(&flag, inline_value, &mut args)?,
+                )?)
+            }
+            "--offset" => {
+                issue_offset = Some(parse_count(
+                    &flag,
+                    require(&flag, inline_value, &mut args)?,
+                )?)
+            }
+            "--top-movers" => top_movers = true,
+            "--layout" => {
+                let value = require(&flag, inline_value, &mut args)?;
+                layout = match TableLayout::parse(&value) {
+                    Some(l) => Some(l),
+                    None => return Err(invalid(format!("Unknown layout '{}'", value))),
+                };
+            }
+            "--window" => window = Some(require(&flag, inline_value, &mut args)?),
+            "--format" => {
+                let value = require(&flag, inline_value, &mut args)?;
+                export_format = match ExportFormat::parse(&value) {
+                    Some(f) => Some(f),
+                    None => return Err(invalid(format!("Unknown export format '{}'", value))),
+                };
+            }
+            "--force" => force = true,
+            "--output" => output = Some(PathBuf::from(require(&flag, inline_value, &mut args)?)),
+            "--diff" => diff = Some(PathBuf::from(require(&flag, inline_value, &mut args)?)),
+            "--csv-out" => csv_out = Some(PathBuf::from(require(&flag, inline_value, &mut args)?)),
+            "--json-out" => {
+                json_out = Some(PathBuf::from(require(&flag, inline_value, &mut args)?))
+            }
+            "--mnemonic" => {
+                return Err(invalid(
+                    "Refusing to read a mnemonic from the command line (it would end up in \
+                     shell history). Use --mnemonic-file <PATH>, or --mnemonic-file - for stdin."
+                        .to_string(),
+                ))
+            }
+            "-h" | "--help" => {
+                println!("{}\n\n{}", USAGE, EXIT_CODES_HELP);
+                std::process::exit(0);
+            }
+            _ => return Err(invalid(format!("Unknown argument '{}'", arg))),
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    opts.command = match positional.next().as_deref() {
+        None => None,
+        Some("status") => match positional.next() {
+            Some(hotkey) => {
+                if json && explain {
+                    return Err(invalid(
+                        "--json and --explain cannot be combined".to_string(),
+                    ));
+                }
+                let cmd = Command::Status {
+                    hotkey,
+                    json,
+                    explain,
+                    fail_if_unregistered,
+                };
+                json = false;
+                explain = false;
+                fail_if_unregistered = false;
+                Some(cmd)
+            }
+            None => return Err(invalid("status requires a <HOTKEY>".to_string())),
+        },
+        Some("leaderboard") => {
+            if json && diff.is_some() {
+                return Err(invalid("--json and --diff cannot be combined".to_string()));
+            }
+            if top_movers && (diff.is_some() || sort.is_some()) {
+                return Err(invalid(
+                    "--top-movers cannot be combined with --diff or --sort".to_string(),
+                ));
+            }
+            if window.is_some() && !top_movers {
+                return Err(invalid("--window requires --top-movers".to_string()));
+            }
+            if group_by_github && (diff.is_some() || top_movers || signed) {
+                return Err(invalid(
+                    "--group-by cannot be combined with --diff, --top-movers or --signed"
+                        .to_string(),
+                ));
+            }
+            if follow && (diff.is_some() || top_movers || group_by_github || signed) {
+                return Err(invalid(
+                    "--follow cannot be combined with --diff, --top-movers, --group-by or --signed"
+                        .to_string(),
+                ));
+            }
+            if (csv_out.is_some() || json_out.is_some())
+                && (json || diff.is_some() || top_movers || group_by_github || follow)
+            {
+                return Err(invalid(
+                    "--csv-out and --json-out cannot be combined with --json, --diff, \
+                     --top-movers, --group-by or --follow"
+                        .to_string(),
+                ));
+            }
+            if interval_secs.is_some() && !follow {
+                return Err(invalid("--interval requires --follow".to_string()));
+            }
+            if signed && (!json || top_movers) {
+                return Err(invalid(
+                    "--signed requires --json and cannot be combined with --top-movers".to_string(),
+                ));
+            }
+            if signed && register.mnemonic_file.is_none() {
+                return Err(invalid(
+                    "--signed requires --mnemonic-file with the signing key's mnemonic".to_string(),
+                ));
+            }
+            let cmd = Command::Leaderboard {
+                sort: sort.take(),
+                json,
+                diff: diff.take(),
+                top_movers: if top_movers {
+                    Some(window.take().unwrap_or_else(|| "7d".to_string()))
+                } else {
+                    None
+                },
+                layout: layout.take(),
+                signed: if signed {
+                    register.mnemonic_file.take()
+                } else {
+                    None
+                },
+                group_by_github,
+                no_pager,
+                follow: if follow {
+                    Some(interval_secs.take().unwrap_or(DEFAULT_WATCH_INTERVAL_SECS))
+                } else {
+                    None
+                },
+                csv_out: csv_out.take(),
+                json_out: json_out.take(),
+            };
+            json = false;
+            top_movers = false;
+            signed = false;
+            group_by_github = false;
+            no_pager = false;
+            follow = false;
+            Some(cmd)
+        }
+        Some("stats") => {
+            let cmd = Command::Stats {
+                watch,
+                interval_secs: interval_secs.take().unwrap_or(DEFAULT_WATCH_INTERVAL_SECS),
+                json,
+                detailed,
+            };
+            watch = false;
+            json = false;
+            detailed = false;
+            Some(cmd)
+        }
+        Some("issues") => Some(Command::Issues {
+            state: issue_state.take(),
+            limit: issue_limit.take(),
+            offset: issue_offset.take(),
+        }),
+        Some("export") => {
+            let mnemonic_file = match register.mnemonic_file.take() {
+                Some(path) => path,
+                None => {
+                    return Err(invalid(
+                        "export requires --mnemonic-file with the sudo owner's mnemonic"
+                            .to_string(),
+                    ))
+                }
+            };
+            Some(Command::Export {
+                format: export_format.take().unwrap_or(ExportFormat::Json),
+                output: output.take(),
+                mnemonic_file,
+            })
+        }
+        Some("import") => {
+            let file = match positional.next() {
+                Some(f) => PathBuf::from(f),
+                None => return Err(invalid("import requires a <FILE>".to_string())),
+            };
+            let mnemonic_file = match register.mnemonic_file.take() {
+                Some(path) => path,
+                None => {
+                    return Err(invalid(
+                        "import requires --mnemonic-file with the sudo owner's mnemonic"
+                            .to_string(),
+                    ))
+                }
+            };
+            let cmd = Command::Import {
+                file,
+                force,
+                mnemonic_file,
+            };
+            force = false;
+            Some(cmd)
+        }
+        Some("verify-snapshot") => match positional.next() {
+            Some(file) => Some(Command::VerifySnapshot {
+                file: PathBuf::from(file),
+            }),
+            None => return Err(invalid("verify-snapshot requires a <FILE>".to_string())),
+        },
+        Some("whoami") => Some(Command::Whoami {
+            mnemonic_file: register.mnemonic_file.take(),
+        }),
+        Some("register") => {
+            let flags = std::mem::take(&mut register);
+            Some(Command::Register(flags.build()?))
+        }
+        Some(other) => return Err(invalid(format!("Unknown command '{}'", other))),
+    };
+    if let Some(extra) = positional.next() {
+        return Err(invalid(format!("Unexpected argument '{}'", extra)));
+    }
+    if opts.dry_run && opts.command.is_none() {
+        return Err(invalid(
+            "--dry-run requires a command; the interactive menu always sends".to_string(),
+        ));
+    }
+    if sort.is_some() || signed || group_by_github || no_pager || follow {
+        return Err(invalid(
+            "--sort, --signed, --group-by, --no-pager and --follow are only valid with the \
+             leaderboard command"
+                .to_string(),
+        ));
+    }
+    if diff.is_some() || top_movers || window.is_some() || layout.is_some() {
+        return Err(invalid(
+            "--diff, --top-movers, --window and --layout are only valid with the leaderboard \
+             command"
+                .to_string(),
+        ));
+    }
+    if csv_out.is_some() || json_out.is_some() {
+        return Err(invalid(
+            "--csv-out and --json-out are only valid with the leaderboard command".to_string(),
+        ));
+    }
+    if watch || interval_secs.is_some() {
+        return Err(invalid(
+            "--watch and --interval are only valid with the stats command (or --interval with \
+             leaderboard --follow)"
+                .to_string(),
+        ));
+    }
+    if detailed {
+        return Err(invalid(
+            "--detailed is only valid with the stats command".to_string(),
+        ));
+    }
+    if json {
+        return Err(invalid(
+            "--json is only valid with the status, stats and leaderboard commands".to_string(),
+        ));
+    }
+    if fail_if_unregistered || explain {
+        return Err(invalid(
+            "--fail-if-unregistered and --explain are only valid with the status command"
+                .to_string(),
+        ));
+    }
+    if issue_state.is_some() || issue_limit.is_some() || issue_offset.is_some() {
+        return Err(invalid(
+            "--state, --limit and --offset are only valid with the issues command".to_string(),
+        ));
+    }
+    if force {
+        return Err(invalid(
+            "--force is only valid with the import command".to_string(),
+        ));
+    }
+    if export_format.is_some() || output.is_some() {
+        return Err(invalid(
+            "--format and --output are only valid with the export command".to_string(),
+        ));
+    }
+    if register.any() {
+        return Err(invalid(
+            "--github, --hotkey, --signature, --timestamp and --mnemonic-file are only \
+             valid with the register command (--mnemonic-file also with export, import, \
+             whoami and leaderboard --signed)"
+                .to_string(),
+        ));
+    }
+
+    Ok(opts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::exit_code_for;
+
+    fn parse(args: &[&str]) -> Result<CliOptions> {
+        parse_args(args.iter().map(|a| a.to_string()))
+    }
+
+    fn parse_err(args: &[&str]) -> String {
+        let err = parse(args).err().expect("expected an error");
+        assert_eq!(exit_code_for(&err), ExitCode::InvalidInput);
+        err.to_string()
+    }
+
+    #[test]
+    fn test_register_with_mnemonic_file() {
+        let opts = parse(&["register", "--github", "alice", "--mnemonic-file", "-"]).unwrap();
+        match opts.command {
+            Some(Command::Register(RegisterArgs {
+                github,
+                auth: RegisterAuth::MnemonicFile { path },
+            })) => {
+                assert_eq!(github, "alice");
+                assert_eq!(path, PathBuf::from("-"));
+            }
+            _ => panic!("expected a mnemonic-file register command"),
+        }
+    }
+
+    #[test]
+    fn test_register_pre_signed() {
+        let sig = format!("0x{}", "ab".repeat(64));
+        let opts = parse(&[
+            "register",
+            "--github=alice",
+            "--hotkey",
+            "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY",
+            "--signature",
+            &sig,
+            "--timestamp",
+            "1705590000",
+        ])
+        .unwrap();
+        match opts.command {
+            Some(Command::Register(RegisterArgs {
+                auth:
+                    RegisterAuth::Signed {
+                        signature,
+                        timestamp,
+                        ..
+                    },
+                ..
+            })) => {
+                assert_eq!(signature, vec![0xab; 64]);
+                assert_eq!(timestamp, 1705590000);
+            }
+            _ => panic!("expected a pre-signed register command"),
+        }
+    }
+
+    #[test]
+    fn test_register_rejects_invalid_args() {
+        assert!(parse_err(&["register", "--mnemonic-file", "-"]).contains("--github"));
+        assert!(parse_err(&["register", "--github", "alice"]).contains("--mnemonic-file"));
+        assert!(
+            parse_err(&["register", "--github", "a", "--hotkey", "5G"]).contains("all of --hotkey")
+        );
+        assert!(parse_err(&[
+            "register",
+            "--github",
+            "a",
+            "--hotkey",
+            "5G",
+            "--signature",
+            "zz",
+            "--timestamp",
+            "1"
+        ])
+        .contains("64 bytes of hex"));
+        assert!(parse_err(&[
+            "register",
+            "--github",
+            "a",
+            "--mnemonic-file",
+            "k.txt",
+            "--hotkey",
+            "5G"
+        ])
+        .contains("not both"));
+        assert!(
+            parse_err(&["register", "--github", "a", "--mnemonic", "word"])
+                .contains("shell history")
+        );
+        assert!(parse_err(&["status", "5G", "--github", "a"]).contains("register command"));
+    }
+
+    #[test]
+    fn test_status_explain() {
+        match parse(&["status", "5G", "--explain"]).unwrap().command {
+            Some(Command::Status { explain, json, .. }) => assert!(explain && !json),
+            _ => panic!("expected a status command"),
+        }
+        assert!(parse_err(&["status", "5G", "--explain", "--json"]).contains("cannot be combined"));
+        assert!(parse_err(&["stats", "--explain"]).contains("status command"));
+    }
+
+    #[test]
+    fn test_proxy_flag() {
+        let opts = parse(&["--proxy=http://proxy.internal:3128", "stats"]).unwrap();
+        assert_eq!(opts.proxy.as_deref(), Some("http://proxy.internal:3128"));
+        assert!(parse(&["stats"]).unwrap().proxy.is_none());
+        assert!(parse_err(&["--proxy"]).contains("--proxy requires a value"));
+    }
+
+    #[test]
+    fn test_timezone_flags() {
+        let opts = parse(&["--absolute", "--timezone=+02:00", "stats"]).unwrap();
+        assert!(opts.absolute);
+        assert_eq!(opts.zone, Zone::parse("+02:00").unwrap());
+        assert_eq!(parse(&["--local"]).unwrap().zone, Zone::Local);
+
+        assert_eq!(
+            parse(&["--timezone", "Asia/Kolkata"]).unwrap().zone,
+            Zone::parse("Asia/Kolkata").unwrap()
+        );
+
+        let err = parse_err(&["--timezone", "Mars/Olympus"]);
+        assert!(err.contains("Unknown time zone 'Mars/Olympus'"), "{}", err);
+        assert!(parse_err(&["--timezone", "soon"]).contains("Invalid time zone"));
+    }
+
+    #[test]
+    fn test_leaderboard_diff() {
+        let opts = parse(&["leaderboard", "--diff", "snap.json", "--sort=net"]).unwrap();
+        match opts.command {
+            Some(Command::Leaderboard {
+                sort, json, diff, ..
+            }) => {
+                assert_eq!(sort.as_deref(), Some("net"));
+                assert!(!json);
+                assert_eq!(diff, Some(PathBuf::from("snap.json")));
+            }
+            _ => panic!("expected a leaderboard command"),
+        }
+        assert!(parse_err(&["leaderboard", "--json", "--diff", "s.json"]).contains("combined"));
+        assert!(parse_err(&["stats", "--diff", "s.json"]).contains("leaderboard command"));
+    }
+
+    #[test]
+    fn test_signed_snapshot() {
+        match parse(&[
+            "leaderboard",
+            "--json",
+            "--signed",
+            "--mnemonic-file",
+            "key.txt",
+        ])
+        .unwrap()
+        .command
+        {
+            Some(Command::Leaderboard { json, signed, .. }) => {
+                assert!(json);
+                assert_eq!(signed, Some(PathBuf::from("key.txt")));
+            }
+            _ => panic!("expected a leaderboard command"),
+        }
+        assert!(parse_err(&["leaderboard", "--signed"]).contains("requires --json"));
+        assert!(parse_err(&["leaderboard", "--json", "--signed"]).contains("--mnemonic-file"));
+        assert!(
+            parse_err(&["leaderboard", "--json", "--mnemonic-file", "key.txt"])
+                .contains("only valid with the register command")
+        );
+        assert!(parse_err(&["stats", "--signed"]).contains("leaderboard command"));
+
+        match parse(&["verify-snapshot", "snap.json"]).unwrap().command {
+            Some(Command::VerifySnapshot { file }) => assert_eq!(file, PathBuf::from("snap.json")),
+            _ => panic!("expected a verify-snapshot command"),
+        }
+        assert!(parse_err(&["verify-snapshot"]).contains("requires a <FILE>"));
+    }
+
+    #[test]
+    fn test_group_by_github() {
+        match parse(&["leaderboard", "--group-by=github", "--json"])
+            .unwrap()
+            .command
+        {
+            Some(Command::Leaderboard {
+                group_by_github,
+                json,
+                ..
+            }) => assert!(group_by_github && json),
+            _ => panic!("expected a leaderboard command"),
+        }
+        assert!(parse_err(&["leaderboard", "--group-by", "hotkey"]).contains("expected github"));
+        assert!(
+            parse_err(&["leaderboard", "--group-by", "github", "--top-movers"])
+                .contains("cannot be combined")
+        );
+        assert!(parse_err(&["stats", "--group-by", "github"]).contains("leaderboard command"));
+    }
+
+    #[test]
+    fn test_follow() {
+        match parse(&["leaderboard", "--follow", "--interval=10", "--json"])
+            .unwrap()
+            .command
+        {
+            Some(Command::Leaderboard { follow, json, .. }) => {
+                assert_eq!(follow, Some(10));
+                assert!(json);
+            }
+            _ => panic!("expected a leaderboard command"),
+        }
+        match parse(&["leaderboard", "--follow"]).unwrap().command {
+            Some(Command::Leaderboard { follow, .. }) => {
+                assert_eq!(follow, Some(DEFAULT_WATCH_INTERVAL_SECS))
+            }
+            _ => panic!("expected a leaderboard command"),
+        }
+        assert!(parse_err(&["leaderboard", "--interval", "3"]).contains("requires --follow"));
+        assert!(parse_err(&["leaderboard", "--follow", "--top-movers"]).contains("cannot be"));
+        assert!(parse_err(&["stats", "--follow"]).contains("leaderboard command"));
+    }
+
+    #[test]
+    fn test_no_pager() {
+        match parse(&["leaderboard", "--diff", "s.json", "--no-pager"])
+            .unwrap()
+            .command
+        {
+            Some(Command::Leaderboard { no_pager, .. }) => assert!(no_pager),
+            _ => panic!("expected a leaderboard command"),
+        }
+        assert!(parse_err(&["issues", "--no-pager"]).contains("leaderboard command"));
+    }
+
+    #[test]
+    fn test_leaderboard_file_outputs() {
+        let opts = parse(&[
+            "leaderboard",
+            "--sort",
+            "valid",
+            "--csv-out",
+            "board.csv",
+            "--json-out=board.json",
+        ])
+        .unwrap();
+        match opts.command {
+            Some(Command::Leaderboard {
+                sort,
+                csv_out,
+                json_out,
+                ..
+            }) => {
+                assert_eq!(sort.as_deref(), Some("valid"));
+                assert_eq!(csv_out, Some(PathBuf::from("board.csv")));
+                assert_eq!(json_out, Some(PathBuf::from("board.json")));
+            }
+            _ => panic!("expected a leaderboard command"),
+        }
+        assert!(
+            parse_err(&["leaderboard", "--csv-out", "b.csv", "--follow"])
+                .contains("cannot be combined")
+        );
+        assert!(parse_err(&["stats", "--json-out", "b.json"]).contains("leaderboard command"));
+    }
+
+    #[test]
+    fn test_export() {
+        let opts = parse(&[
+            "export",
+            "--format=csv",
+            "--output",
+            "dump.csv",
+            "--mnemonic-file",
+            "owner.txt",
+        ])
+        .unwrap();
+        match opts.command {
+            Some(Command::Export {
+                format,
+                output,
+                mnemonic_file,
+            }) => {
+                assert_eq!(format, ExportFormat::Csv);
+                assert_eq!(output, Some(PathBuf::from("dump.csv")));
+                assert_eq!(mnemonic_file, PathBuf::from("owner.txt"));
+            }
+            _ => panic!("expected an export command"),
+        }
+        assert!(parse_err(&["export"]).contains("--mnemonic-file"));
+        assert!(parse_err(&["export", "--format", "xml"]).contains("Unknown export format"));
+        assert!(parse_err(&["stats", "--format", "json"]).contains("export command"));
+    }
+
+    #[test]
+    fn test_import() {
+        let opts = parse(&["import", "dump.json", "--force", "--mnemonic-file=-"]).unwrap();
+        match opts.command {
+            Some(Command::Import {
+                file,
+                force,
+                mnemonic_file,
+            }) => {
+                assert_eq!(file, PathBuf::from("dump.json"));
+                assert!(force);
+                assert_eq!(mnemonic_file, PathBuf::from("-"));
+            }
+            _ => panic!("expected an import command"),
+        }
+        assert!(parse_err(&["import", "--mnemonic-file", "-"]).contains("<FILE>"));
+        assert!(parse_err(&["import", "dump.json"]).contains("--mnemonic-file"));
+        assert!(parse_err(&["stats", "--force"]).contains("import command"));
+        assert!(parse_err(&["import", "dump.json", "--scheme", "ed25519"]).contains("--scheme"));
+    }
+
+    #[test]
+    fn test_whoami() {
+        match parse(&["whoami"]).unwrap().command {
+            Some(Command::Whoami { mnemonic_file }) => assert_eq!(mnemonic_file, None),
+            _ => panic!("expected a whoami command"),
+        }
+        match parse(&["whoami", "--mnemonic-file=key.txt"])
+            .unwrap()
+            .command
+        {
+            Some(Command::Whoami { mnemonic_file }) => {
+                assert_eq!(mnemonic_file, Some(PathBuf::from("key.txt")));
+            }
+            _ => panic!("expected a whoami command"),
+        }
+        assert!(parse_err(&["whoami", "--mnemonic", "abandon"]).contains("shell history"));
+    }
+
+    #[test]
+    fn test_dry_run() {
+        assert!(parse(&["--dry-run", "stats"]).unwrap().dry_run);
+        assert!(!parse(&["stats"]).unwrap().dry_run);
+        assert!(parse_err(&["--dry-run"]).contains("requires a command"));
+    }
+
+    #[test]
+    fn test_ss58_format() {
+        let opts = parse(&["whoami", "--ss58-format", "0"]).unwrap();
+        assert_eq!(opts.ss58_format, Some(0));
+        assert_eq!(
+            parse(&["register", "--github", "a", "--mnemonic-file", "-"])
+                .unwrap()
+                .ss58_format,
+            None
+        );
+        assert!(parse_err(&["--ss58-format", "16384"]).contains("0 to 16383"));
+
+        // The flag wins over the environment.
+        assert_eq!(
+            resolve_ss58_format(Some(0), Some("7".to_string())).unwrap(),
+            0
+        );
+        assert_eq!(
+            resolve_ss58_format(None, Some(" 7\n".to_string())).unwrap(),
+            7
+        );
+        assert_eq!(
+            resolve_ss58_format(None, None).unwrap(),
+            DEFAULT_SS58_FORMAT
+        );
+        let err = resolve_ss58_format(None, Some("polkadot".to_string())).unwrap_err();
+        assert!(err.to_string().contains(SS58_FORMAT_ENV));
+    }
+}
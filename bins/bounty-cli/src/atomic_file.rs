@@ -0,0 +1,97 @@
+//! Whole-file writes that readers never observe half done: the data goes to
+//! a temporary file next to the target, which is then renamed over it.
+
+use std::fs::File;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// The temporary sibling of `path`; same directory, so the rename cannot
+/// cross filesystems.
+fn temp_path(path: &Path) -> PathBuf {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.with_file_name(format!(".{}.tmp-{}", name, std::process::id()))
+}
+
+/// Write `contents` to `path` atomically.
+pub fn write(path: &Path, contents: &[u8]) -> io::Result<()> {
+    write_with(path, |file| file.write_all(contents))
+}
+
+/// Create `path` from whatever `fill` writes. If `fill` or the flush fails,
+/// the temporary file is removed and `path` is left as it was.
+pub fn write_with(path: &Path, fill: impl FnOnce(&mut File) -> io::Result<()>) -> io::Result<()> {
+    let temp = temp_path(path);
+    let result = File::create(&temp).and_then(|mut file| {
+        fill(&mut file)?;
+        file.sync_all()?;
+        drop(file);
+        std::fs::rename(&temp, path)
+    });
+    if result.is_err() {
+        let _ = std::fs::remove_file(&temp);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("bounty-cli-atomic-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn entries(dir: &Path) -> Vec<String> {
+        let mut names: Vec<String> = std::fs::read_dir(dir)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn test_write_replaces_file() {
+        let dir = scratch_dir("replace");
+        let path = dir.join("leaderboard.csv");
+        std::fs::write(&path, "old").unwrap();
+
+        write(&path, b"new").unwrap();
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "new");
+        assert_eq!(entries(&dir), ["leaderboard.csv"]);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_failed_write_leaves_no_partial_file() {
+        let dir = scratch_dir("partial");
+        let path = dir.join("leaderboard.csv");
+
+        let err = write_with(&path, |file| {
+            file.write_all(b"rank,hotkey\n1,5Grw")?;
+            Err(io::Error::other("disk full"))
+        })
+        .unwrap_err();
+        assert_eq!(err.to_string(), "disk full");
+        assert!(entries(&dir).is_empty());
+
+        // An existing file survives a failed replacement untouched.
+        std::fs::write(&path, "rank,hotkey\n").unwrap();
+        assert!(write_with(&path, |file| {
+            file.write_all(b"partial")?;
+            Err(io::Error::other("disk full"))
+        })
+        .is_err());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "rank,hotkey\n");
+        assert_eq!(entries(&dir), ["leaderboard.csv"]);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
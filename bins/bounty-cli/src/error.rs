@@ -0,0 +1,118 @@
+use std::fmt;
+
+/// Process exit codes for non-interactive commands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExitCode {
+    Failure = 1,
+    Network = 2,
+    NotFound = 3,
+    InvalidInput = 4,
+    RpcError = 5,
+}
+
+pub const EXIT_CODES_HELP: &str = "\
+Exit codes:
+  0  success
+  1  unclassified failure
+  2  network error (validator unreachable or timed out)
+  3  not found (e.g. hotkey not registered)
+  4  invalid input
+  5  RPC error returned by the validator";
+
+/// An error tagged with the exit code it should produce.
+#[derive(Debug)]
+pub struct CliError {
+    pub code: ExitCode,
+    pub message: String,
+}
+
+impl CliError {
+    pub fn new(code: ExitCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for CliError {}
+
+/// Returned by the RPC layer once `--dry-run` has printed a request. It
+/// unwinds the command like an error, and `main` exits 0 on it.
+#[derive(Debug)]
+pub struct DryRunDone;
+
+impl fmt::Display for DryRunDone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("dry run: request printed, not sent")
+    }
+}
+
+impl std::error::Error for DryRunDone {}
+
+/// Whether `err` is the end of a `--dry-run` rather than a failure.
+pub fn is_dry_run(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| cause.is::<DryRunDone>())
+}
+
+/// Map an error to its exit code: tagged `CliError`s keep their code,
+/// transport failures from `reqwest` are network errors, anything else is 1.
+pub fn exit_code_for(err: &anyhow::Error) -> ExitCode {
+    for cause in err.chain() {
+        if let Some(cli) = cause.downcast_ref::<CliError>() {
+            return cli.code;
+        }
+        if let Some(req) = cause.downcast_ref::<reqwest::Error>() {
+            if req.is_connect() || req.is_timeout() || req.is_request() {
+                return ExitCode::Network;
+            }
+        }
+    }
+    ExitCode::Failure
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_code_for_tagged_errors() {
+        for code in [
+            ExitCode::Network,
+            ExitCode::NotFound,
+            ExitCode::InvalidInput,
+            ExitCode::RpcError,
+        ] {
+            let err = anyhow::Error::new(CliError::new(code, "boom"));
+            assert_eq!(exit_code_for(&err), code);
+        }
+    }
+
+    #[test]
+    fn test_exit_code_survives_context() {
+        let err = anyhow::Error::new(CliError::new(ExitCode::NotFound, "missing"))
+            .context("while fetching status");
+        assert_eq!(exit_code_for(&err), ExitCode::NotFound);
+    }
+
+    #[test]
+    fn test_exit_code_for_untagged_error() {
+        let err = anyhow::anyhow!("something else");
+        assert_eq!(exit_code_for(&err), ExitCode::Failure);
+        assert_eq!(ExitCode::Network as i32, 2);
+        assert_eq!(ExitCode::RpcError as i32, 5);
+    }
+
+    #[test]
+    fn test_dry_run_is_not_a_failure() {
+        let err = anyhow::Error::new(DryRunDone).context("while fetching stats");
+        assert!(is_dry_run(&err));
+        assert!(!is_dry_run(&anyhow::anyhow!("something else")));
+    }
+}
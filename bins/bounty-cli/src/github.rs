@@ -0,0 +1,196 @@
+use anyhow::{bail, Result};
+
+/// Repository that bare `#123` / `123` references point to.
+pub const DEFAULT_OWNER: &str = "PlatformNetwork";
+pub const DEFAULT_REPO: &str = "bounty-challenge";
+
+fn parse_number(input: &str, raw: &str) -> Result<u32> {
+    match raw.parse::<u32>() {
+        Ok(n) if n > 0 => Ok(n),
+        _ => bail!("Invalid issue number in '{}'", input),
+    }
+}
+
+fn is_name(part: &str) -> bool {
+    !part.is_empty()
+        && part
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+/// `{owner}/{repo}[.git]#{number}`, as copied from an SSH remote, after the
+/// `git@github.com:` prefix.
+fn parse_ssh_ref(input: &str, remote: &str) -> Result<(String, String, u32)> {
+    let Some((repo_path, number)) = remote.split_once('#') else {
+        bail!(
+            "{} names a repository, not an issue: append #NUMBER",
+            input.trim()
+        );
+    };
+    let repo_path = repo_path.strip_suffix(".git").unwrap_or(repo_path);
+    match repo_path.split_once('/') {
+        Some((owner, repo)) if is_name(owner) && is_name(repo) => Ok((
+            owner.to_string(),
+            repo.to_string(),
+            parse_number(input, number)?,
+        )),
+        _ => bail!("Not a GitHub SSH remote: {}", input.trim()),
+    }
+}
+
+/// Parse an issue reference into `(owner, repo, number)`. Accepts
+/// `https://github.com/{owner}/{repo}/issues/{number}` (trailing slashes,
+/// query strings and fragments are ignored), `{owner}/{repo}#{number}`,
+/// `git@github.com:{owner}/{repo}.git#{number}` (`.git` optional), and bare
+/// `#{number}` or `{number}` for the bounty-challenge repository.
+/// Pull-request URLs are rejected.
+pub fn parse_issue_ref(input: &str) -> Result<(String, String, u32)> {
+    let trimmed = input.trim();
+    if trimmed.is_empty() {
+        bail!("Empty issue reference");
+    }
+
+    let bare = trimmed.strip_prefix('#').unwrap_or(trimmed);
+    if bare.chars().all(|c| c.is_ascii_digit()) {
+        let number = parse_number(input, bare)?;
+        return Ok((DEFAULT_OWNER.to_string(), DEFAULT_REPO.to_string(), number));
+    }
+
+    if let Some(remote) = trimmed.strip_prefix("git@github.com:") {
+        return parse_ssh_ref(input, remote);
+    }
+
+    if !trimmed.contains("://") && !trimmed.starts_with("github.com/") {
+        if let Some((repo_path, number)) = trimmed.split_once('#') {
+            if let Some((owner, repo)) = repo_path.split_once('/') {
+                if is_name(owner) && is_name(repo) {
+                    let number = parse_number(input, number)?;
+                    return Ok((owner.to_string(), repo.to_string(), number));
+                }
+            }
+        }
+        bail!("Not a GitHub issue reference: {}", trimmed);
+    }
+
+    let url = trimmed.split(['#', '?']).next().unwrap_or(trimmed);
+    let url = url.trim_end_matches('/');
+    let path = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
+    let path = path.strip_prefix("www.").unwrap_or(path);
+
+    let parts: Vec<&str> = path.split('/').collect();
+    match parts.as_slice() {
+        ["github.com", owner, repo, "issues", number] if is_name(owner) && is_name(repo) => Ok((
+            owner.to_string(),
+            repo.to_string(),
+            parse_number(input, number)?,
+        )),
+        ["github.com", _, _, "pull", _] => {
+            bail!("{} is a pull request, not an issue", trimmed)
+        }
+        _ => bail!("Not a GitHub issue URL: {}", trimmed),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parsed(input: &str) -> (String, String, u32) {
+        parse_issue_ref(input).unwrap()
+    }
+
+    #[test]
+    fn test_full_url() {
+        assert_eq!(
+            parsed("https://github.com/PlatformNetwork/bounty-challenge/issues/42"),
+            (
+                "PlatformNetwork".to_string(),
+                "bounty-challenge".to_string(),
+                42
+            )
+        );
+    }
+
+    #[test]
+    fn test_url_trailing_slash_and_fragment() {
+        let base = "https://github.com/PlatformNetwork/bounty-challenge/issues/42";
+        for url in [
+            format!("{}/", base),
+            format!("{}#issuecomment-123", base),
+            format!("{}/#top", base),
+            format!("{}?q=1", base),
+            format!("  {}  ", base),
+        ] {
+            assert_eq!(parsed(&url).2, 42, "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_shorthand_and_bare_numbers() {
+        assert_eq!(
+            parsed("cortexlm/vgrep#7"),
+            ("cortexlm".to_string(), "vgrep".to_string(), 7)
+        );
+        for input in ["#123", "123", " 123 "] {
+            assert_eq!(
+                parsed(input),
+                (DEFAULT_OWNER.to_string(), DEFAULT_REPO.to_string(), 123),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_ssh_remote_refs() {
+        let expected = (
+            "PlatformNetwork".to_string(),
+            "bounty-challenge".to_string(),
+            42,
+        );
+        assert_eq!(
+            parsed("git@github.com:PlatformNetwork/bounty-challenge.git#42"),
+            expected
+        );
+        assert_eq!(
+            parsed(" git@github.com:PlatformNetwork/bounty-challenge#42 "),
+            expected
+        );
+
+        let err =
+            parse_issue_ref("git@github.com:PlatformNetwork/bounty-challenge.git").unwrap_err();
+        assert!(err.to_string().contains("append #NUMBER"), "{}", err);
+        for input in [
+            "git@github.com:bounty-challenge.git#42",
+            "git@github.com:o/r/extra.git#42",
+            "git@github.com:o/r.git#x",
+            "git@gitlab.com:o/r.git#42",
+        ] {
+            assert!(parse_issue_ref(input).is_err(), "{}", input);
+        }
+    }
+
+    #[test]
+    fn test_rejects_pull_requests_and_malformed_input() {
+        let err = parse_issue_ref("https://github.com/PlatformNetwork/bounty-challenge/pull/42")
+            .unwrap_err();
+        assert!(err.to_string().contains("pull request"));
+
+        for input in [
+            "",
+            "#",
+            "#0",
+            "abc",
+            "o/r#x",
+            "o#1",
+            "https://github.com/PlatformNetwork/bounty-challenge/issues/",
+            "https://gitlab.com/o/r/issues/1",
+            "https://github.com/o/r/issues/1/comments",
+        ] {
+            assert!(parse_issue_ref(input).is_err(), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use sp_core::crypto::{Pair as PairTrait, SecretStringError, Ss58AddressFormat, Ss58Codec};
+use sp_core::sr25519;
+use std::sync::atomic::{AtomicU16, Ordering};
+
+/// The generic Substrate prefix.
+pub const DEFAULT_SS58_FORMAT: u16 = 42;
+
+static SS58_FORMAT: AtomicU16 = AtomicU16::new(DEFAULT_SS58_FORMAT);
+
+/// Set the network prefix of displayed hotkey addresses for the whole process.
+pub fn set_ss58_format(format: u16) {
+    SS58_FORMAT.store(format, Ordering::Relaxed);
+}
+
+pub fn ss58_format() -> u16 {
+    SS58_FORMAT.load(Ordering::Relaxed)
+}
+
+/// An sr25519 hotkey pair derived from a mnemonic. sr25519 is the only
+/// scheme the validators verify request signatures under.
+pub struct Keypair(sr25519::Pair);
+
+impl Keypair {
+    pub fn from_phrase(mnemonic: &str) -> Result<Self, SecretStringError> {
+        Ok(Self(sr25519::Pair::from_phrase(mnemonic, None)?.0))
+    }
+
+    pub fn public_bytes(&self) -> [u8; 32] {
+        self.0.public().0
+    }
+
+    /// SS58 address under the prefix set with [`set_ss58_format`].
+    pub fn to_ss58(&self) -> String {
+        self.to_ss58_with_format(ss58_format())
+    }
+
+    pub fn to_ss58_with_format(&self, format: u16) -> String {
+        self.0
+            .public()
+            .to_ss58check_with_version(Ss58AddressFormat::custom(format))
+    }
+
+    pub fn sign(&self, message: &[u8]) -> [u8; 64] {
+        self.0.sign(message).0
+    }
+}
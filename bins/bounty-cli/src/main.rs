@@ -1,5 +1,14 @@
+mod args;
+mod atomic_file;
+mod error;
+mod github;
+mod keys;
+mod pager;
 mod rpc;
+mod theme;
+mod timestamps;
 mod tui;
+mod version;
 mod views;
 
 use anyhow::Result;
@@ -24,15 +33,154 @@ const MENU_ITEMS: &[&str] = &[
 fn print_header(rpc_url: &str) {
     println!();
     println!("  {}", style("bounty-challenge").cyan().bold());
-    println!("  {} {}", style("RPC:").dim(), style(rpc_url).green());
+    println!("  {} {}", style("RPC:").dim(), theme::good(rpc_url));
     println!();
 }
 
 #[tokio::main]
-async fn main() -> Result<()> {
-    let mut rpc_url =
-        std::env::var("BOUNTY_RPC_URL").unwrap_or_else(|_| DEFAULT_RPC_URL.to_string());
+async fn main() {
+    let opts = match args::parse_args(std::env::args().skip(1)) {
+        Ok(o) => o,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(error::exit_code_for(&e) as i32);
+        }
+    };
+    theme::init(opts.no_color, opts.theme);
+    theme::set_ascii(opts.ascii || theme::detect_ascii());
+    timestamps::init(timestamps::TimeDisplay {
+        zone: opts.zone,
+        absolute: opts.absolute,
+    });
+    tui::set_refresh_secs(opts.refresh_secs);
+    match args::resolve_ss58_format(opts.ss58_format, std::env::var(args::SS58_FORMAT_ENV).ok()) {
+        Ok(format) => keys::set_ss58_format(format),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(error::exit_code_for(&e) as i32);
+        }
+    }
+
+    if opts.insecure {
+        eprintln!(
+            "{} TLS certificate verification is disabled (--insecure). \
+             Only use this with a validator you trust.",
+            theme::bad("WARNING:").bold()
+        );
+    }
+    match rpc::build_client(
+        opts.insecure,
+        opts.ca_cert.as_deref(),
+        opts.proxy.as_deref(),
+    ) {
+        Ok(client) => rpc::init_client(client),
+        Err(e) => {
+            eprintln!("{} {}", theme::bad("Error:").bold(), e);
+            std::process::exit(error::exit_code_for(&e) as i32);
+        }
+    }
+
+    let rpc_url = std::env::var("BOUNTY_RPC_URL").unwrap_or_else(|_| DEFAULT_RPC_URL.to_string());
+
+    rpc::set_dry_run(opts.dry_run);
+
+    // The interactive menu is long-running, so it always checks.
+    if !opts.dry_run && (opts.check_version || opts.command.is_none()) {
+        version::check(&rpc_url).await;
+    }
+
+    let result = match opts.command {
+        Some(args::Command::Status {
+            hotkey,
+            json,
+            explain,
+            fail_if_unregistered,
+        }) => {
+            if json {
+                views::status::run_hotkey_json(&rpc_url, &hotkey, fail_if_unregistered).await
+            } else {
+                views::status::run_hotkey(&rpc_url, &hotkey, explain).await
+            }
+        }
+        Some(args::Command::Leaderboard {
+            sort,
+            json,
+            diff,
+            top_movers,
+            layout,
+            signed,
+            group_by_github,
+            no_pager,
+            follow,
+            csv_out,
+            json_out,
+        }) => {
+            let sort = sort.as_deref();
+            if csv_out.is_some() || json_out.is_some() {
+                views::leaderboard::run_save(
+                    &rpc_url,
+                    sort,
+                    csv_out.as_deref(),
+                    json_out.as_deref(),
+                )
+                .await
+            } else if group_by_github {
+                views::leaderboard::run_grouped(&rpc_url, sort, json, no_pager).await
+            } else if let Some(window) = top_movers {
+                views::leaderboard::run_movers(&rpc_url, &window, json).await
+            } else if let Some(snapshot) = diff {
+                views::leaderboard::run_diff(&rpc_url, sort, &snapshot, no_pager).await
+            } else if let Some(interval_secs) = follow {
+                views::leaderboard::run_follow(&rpc_url, sort, interval_secs, json).await
+            } else if json || opts.dry_run {
+                // The live table fetches the same snapshot; a dry run must
+                // not leave the terminal in full-screen mode.
+                views::leaderboard::run_json(&rpc_url, sort, signed.as_deref()).await
+            } else {
+                tui::leaderboard::run(&rpc_url, sort, layout).await
+            }
+        }
+        Some(args::Command::Stats {
+            watch,
+            interval_secs,
+            json,
+            detailed,
+        }) => views::stats::run(&rpc_url, watch, interval_secs, json, detailed).await,
+        Some(args::Command::Issues {
+            state,
+            limit,
+            offset,
+        }) => views::issues::run_filtered(&rpc_url, state.as_deref(), limit, offset).await,
+        Some(args::Command::Export {
+            format,
+            output,
+            mnemonic_file,
+        }) => views::export::run(&rpc_url, format, output.as_deref(), &mnemonic_file).await,
+        Some(args::Command::Import {
+            file,
+            force,
+            mnemonic_file,
+        }) => views::import::run(&rpc_url, &file, force, &mnemonic_file).await,
+        Some(args::Command::VerifySnapshot { file }) => views::snapshot::run(&file),
+        Some(args::Command::Whoami { mnemonic_file }) => {
+            views::whoami::run(mnemonic_file.as_deref())
+        }
+        Some(args::Command::Register(register)) => {
+            views::register::run_non_interactive(&rpc_url, register).await
+        }
+        None => run_interactive(rpc_url).await,
+    };
+
+    if let Err(e) = result {
+        if error::is_dry_run(&e) {
+            return;
+        }
+        eprintln!("{} {}", theme::bad("Error:").bold(), e);
+        std::process::exit(error::exit_code_for(&e) as i32);
+    }
+}
 
+async fn run_interactive(mut rpc_url: String) -> Result<()> {
     loop {
         print_header(&rpc_url);
 
@@ -48,7 +196,7 @@ async fn main() -> Result<()> {
         };
 
         let result = match selection {
-            0 => tui::leaderboard::run(&rpc_url).await,
+            0 => tui::leaderboard::run(&rpc_url, None, None).await,
             1 => tui::stats::run(&rpc_url).await,
             2 => tui::weights::run(&rpc_url).await,
             3 => views::status::run(&rpc_url).await,
@@ -65,7 +213,7 @@ async fn main() -> Result<()> {
                 println!(
                     "  {} {}",
                     style("RPC updated:").dim(),
-                    style(&rpc_url).green()
+                    theme::good(&rpc_url)
                 );
                 Ok(())
             }
@@ -74,7 +222,7 @@ async fn main() -> Result<()> {
         };
 
         if let Err(e) = result {
-            println!("\n  {} {}\n", style("Error:").red().bold(), e);
+            println!("\n  {} {}\n", theme::bad("Error:").bold(), e);
         }
 
         println!("{}", style("Press Enter to continue...").dim());
@@ -0,0 +1,70 @@
+//! A minimal `more`-style pager for long table output on a terminal.
+
+use console::{style, Key, Term};
+use std::io;
+
+/// Whether `rows` lines of output should be paged: only on a terminal, only
+/// when they do not fit on one screen, and never with `--no-pager`.
+pub fn should_page(is_tty: bool, no_pager: bool, rows: usize, term_rows: usize) -> bool {
+    is_tty && !no_pager && rows > term_rows
+}
+
+/// Print `lines`, one screen at a time when [`should_page`] says so. Space
+/// shows the next screen, Enter the next line, and q or Esc stops.
+pub fn print(lines: &[String], no_pager: bool) -> io::Result<()> {
+    let term = Term::stdout();
+    let (term_rows, _) = term.size();
+    let term_rows = usize::from(term_rows);
+    if !should_page(term.is_term(), no_pager, lines.len(), term_rows) {
+        for line in lines {
+            println!("{}", line);
+        }
+        return Ok(());
+    }
+
+    // The last row holds the prompt.
+    let screen = term_rows.saturating_sub(1).max(1);
+    let mut shown = 0;
+    let mut until = screen;
+    loop {
+        while shown < until.min(lines.len()) {
+            term.write_line(&lines[shown])?;
+            shown += 1;
+        }
+        if shown >= lines.len() {
+            return Ok(());
+        }
+        term.write_str(
+            &style(format!(
+                "-- {}/{} -- space: next page, enter: next line, q: quit",
+                shown,
+                lines.len()
+            ))
+            .reverse()
+            .to_string(),
+        )?;
+        let key = term.read_key()?;
+        term.clear_line()?;
+        until = match key {
+            Key::Char(' ') | Key::PageDown => shown + screen,
+            Key::Enter | Key::ArrowDown => shown + 1,
+            Key::Char('q') | Key::Escape => return Ok(()),
+            _ => shown,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_page() {
+        assert!(should_page(true, false, 120, 40));
+        // Fits on one screen.
+        assert!(!should_page(true, false, 40, 40));
+        // Redirected or piped output.
+        assert!(!should_page(false, false, 120, 40));
+        assert!(!should_page(true, true, 120, 40));
+    }
+}
@@ -1,12 +1,100 @@
 use anyhow::{Context, Result};
 use serde_json::Value;
 use sha2::{Digest, Sha256};
-use sp_core::{crypto::Pair as PairTrait, sr25519::Pair};
 use std::collections::HashMap;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use crate::error::{CliError, DryRunDone, ExitCode};
+use crate::keys::Keypair;
 
 const CHALLENGE_ID: &str = "bounty-challenge";
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+const POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(90);
+/// How much of a non-JSON response body is quoted in the error.
+const BODY_SNIPPET_CHARS: usize = 200;
+
+static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+static DRY_RUN: AtomicBool = AtomicBool::new(false);
+
+/// With `--dry-run`, the first RPC call prints its request instead of
+/// sending it and fails with [`DryRunDone`], which ends the command.
+pub fn set_dry_run(enabled: bool) {
+    DRY_RUN.store(enabled, Ordering::Relaxed);
+}
 
-fn canonicalize_json(value: &Value) -> String {
+fn client_builder() -> reqwest::ClientBuilder {
+    reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .connect_timeout(CONNECT_TIMEOUT)
+        .pool_idle_timeout(POOL_IDLE_TIMEOUT)
+        .tcp_keepalive(POOL_IDLE_TIMEOUT)
+}
+
+/// Build the HTTP client used for every RPC call.
+///
+/// `insecure` disables certificate verification; `ca_cert` adds a PEM root
+/// certificate for validators using a self-signed or private CA. `proxy`
+/// routes all requests through the given URL; without it, `HTTP_PROXY`,
+/// `HTTPS_PROXY` and `NO_PROXY` are honored.
+pub fn build_client(
+    insecure: bool,
+    ca_cert: Option<&Path>,
+    proxy: Option<&str>,
+) -> Result<reqwest::Client> {
+    let mut builder = client_builder();
+
+    if let Some(url) = proxy {
+        let proxy = reqwest::Proxy::all(url).map_err(|e| {
+            CliError::new(
+                ExitCode::InvalidInput,
+                format!("Invalid proxy URL '{}': {}", url, e),
+            )
+        })?;
+        builder = builder.proxy(proxy);
+    }
+
+    if let Some(path) = ca_cert {
+        let pem = std::fs::read(path).map_err(|e| {
+            CliError::new(
+                ExitCode::InvalidInput,
+                format!("Failed to read CA certificate '{}': {}", path.display(), e),
+            )
+        })?;
+        let cert = reqwest::Certificate::from_pem(&pem).map_err(|e| {
+            CliError::new(
+                ExitCode::InvalidInput,
+                format!("Invalid CA certificate '{}': {}", path.display(), e),
+            )
+        })?;
+        builder = builder.add_root_certificate(cert);
+    }
+
+    if insecure {
+        builder = builder.danger_accept_invalid_certs(true);
+    }
+
+    builder.build().context("Failed to build HTTP client")
+}
+
+/// Install the client for the rest of the process. Must be called before the
+/// first RPC call; later calls are ignored.
+pub fn init_client(client: reqwest::Client) {
+    let _ = CLIENT.set(client);
+}
+
+/// The process-wide client. Reusing it keeps connections and TLS sessions
+/// alive across the TUI's polling requests.
+fn client() -> &'static reqwest::Client {
+    CLIENT.get_or_init(|| client_builder().build().unwrap_or_default())
+}
+
+/// Compact JSON with object keys sorted, so equal values always give the
+/// same bytes to sign.
+pub(crate) fn canonicalize_json(value: &Value) -> String {
     match value {
         Value::Object(map) => {
             let mut pairs: Vec<_> = map.iter().collect();
@@ -31,6 +119,19 @@ fn canonicalize_json(value: &Value) -> String {
     }
 }
 
+/// The message signed for an authenticated request, with the body hashed
+/// in canonical (sorted-key) JSON form.
+fn auth_message(method: &str, path: &str, body: Option<&Value>, nonce: &str) -> String {
+    let body_bytes = body
+        .map(|b| canonicalize_json(b).into_bytes())
+        .unwrap_or_default();
+    let body_hash = hex::encode(Sha256::digest(&body_bytes));
+    format!(
+        "challenge:{}:{}:{}:{}:{}",
+        CHALLENGE_ID, method, path, body_hash, nonce
+    )
+}
+
 /// RPC call without authentication
 pub async fn rpc_call(
     rpc_url: &str,
@@ -51,13 +152,8 @@ pub async fn rpc_call_auth(
     method: &str,
     path: &str,
     body: Option<Value>,
-    keypair: &Pair,
+    keypair: &Keypair,
 ) -> Result<Value> {
-    let body_bytes = body
-        .as_ref()
-        .map(|b| canonicalize_json(b).into_bytes())
-        .unwrap_or_default();
-
     // Create nonce: {timestamp}:{random}
     let timestamp = std::time::SystemTime::now()
         .duration_since(std::time::UNIX_EPOCH)?
@@ -65,28 +161,32 @@ pub async fn rpc_call_auth(
     let random: u64 = rand::random();
     let nonce = format!("{}:{:016x}", timestamp, random);
 
-    // Hash the canonicalized body
-    let body_hash = hex::encode(Sha256::digest(&body_bytes));
+    let headers = auth_headers(method, path, body.as_ref(), &nonce, keypair);
+    rpc_call_internal(rpc_url, method, path, body, Some(headers)).await
+}
 
-    // Create the signed message
-    let message = format!(
-        "challenge:{}:{}:{}:{}:{}",
-        CHALLENGE_ID, method, path, body_hash, nonce
-    );
+/// The signature headers of an authenticated request.
+fn auth_headers(
+    method: &str,
+    path: &str,
+    body: Option<&Value>,
+    nonce: &str,
+    keypair: &Keypair,
+) -> HashMap<String, String> {
+    let message = auth_message(method, path, body, nonce);
 
     // Sign
     let signature = keypair.sign(message.as_bytes());
-    let sig_hex = hex::encode(signature.0);
+    let sig_hex = hex::encode(signature);
 
     // Get hotkey as hex (not SS58)
-    let hotkey_hex = hex::encode(keypair.public().0);
+    let hotkey_hex = hex::encode(keypair.public_bytes());
 
     let mut headers = HashMap::new();
     headers.insert("X-Hotkey".to_string(), hotkey_hex);
     headers.insert("X-Signature".to_string(), sig_hex);
-    headers.insert("X-Nonce".to_string(), nonce);
-
-    rpc_call_internal(rpc_url, method, path, body, Some(headers)).await
+    headers.insert("X-Nonce".to_string(), nonce.to_string());
+    headers
 }
 
 async fn rpc_call_internal(
@@ -96,8 +196,20 @@ async fn rpc_call_internal(
     body: Option<Value>,
     headers: Option<HashMap<String, String>>,
 ) -> Result<Value> {
-    let client = reqwest::Client::new();
+    let request = request_envelope(method, path, body, headers)?;
+    match send(client(), rpc_url, &request, DRY_RUN.load(Ordering::Relaxed)).await? {
+        Some(result) => Ok(result),
+        None => Err(DryRunDone.into()),
+    }
+}
 
+/// The JSON-RPC `challenge_call` request for one challenge route.
+fn request_envelope(
+    method: &str,
+    path: &str,
+    body: Option<Value>,
+    headers: Option<HashMap<String, String>>,
+) -> Result<Value> {
     let mut params = serde_json::json!({
         "challengeId": CHALLENGE_ID,
         "method": method,
@@ -112,34 +224,316 @@ async fn rpc_call_internal(
         params["headers"] = serde_json::to_value(h)?;
     }
 
-    let request = serde_json::json!({
+    Ok(serde_json::json!({
         "jsonrpc": "2.0",
         "method": "challenge_call",
         "params": params,
         "id": 1,
-    });
+    }))
+}
+
+/// What `--dry-run` prints: the HTTP request line and the JSON body.
+fn dry_run_text(rpc_url: &str, request: &Value) -> String {
+    format!(
+        "POST {}/rpc\n{}",
+        rpc_url,
+        serde_json::to_string_pretty(request).unwrap_or_default()
+    )
+}
+
+/// `err` and its sources, outermost first.
+fn error_chain(err: &dyn std::error::Error) -> String {
+    let mut text = err.to_string();
+    let mut source = err.source();
+    while let Some(cause) = source {
+        text.push_str(": ");
+        text.push_str(&cause.to_string());
+        source = cause.source();
+    }
+    text
+}
+
+/// Name what went wrong on the way to the validator. `reqwest` has no DNS or
+/// TLS predicates, so those are told apart by the underlying error text.
+fn transport_error(rpc_url: &str, err: &reqwest::Error) -> CliError {
+    let detail = error_chain(err);
+    // The sources only: the outer message quotes the URL.
+    let lower = std::error::Error::source(err)
+        .map(error_chain)
+        .unwrap_or_default()
+        .to_lowercase();
+    let summary = if err.is_timeout() {
+        format!("Timed out waiting for the validator RPC at {}", rpc_url)
+    } else if lower.contains("dns error") || lower.contains("failed to lookup address") {
+        format!("Could not resolve the validator host of {}", rpc_url)
+    } else if lower.contains("certificate") || lower.contains("tls") || lower.contains("ssl") {
+        format!(
+            "TLS handshake with the validator RPC at {} failed (see --ca-cert)",
+            rpc_url
+        )
+    } else if err.is_connect() {
+        format!(
+            "Could not connect to the validator RPC at {} (refused or unreachable)",
+            rpc_url
+        )
+    } else {
+        format!("Request to the validator RPC at {} failed", rpc_url)
+    };
+    CliError::new(ExitCode::Network, format!("{}: {}", summary, detail))
+}
+
+/// A response that is not JSON (a proxy error page, say), quoted briefly.
+fn non_json_error(status: reqwest::StatusCode, body: &[u8]) -> CliError {
+    let text = String::from_utf8_lossy(body);
+    let mut snippet: String = text
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .take(BODY_SNIPPET_CHARS)
+        .collect();
+    if snippet.is_empty() {
+        snippet = "(empty body)".to_string();
+    }
+    CliError::new(
+        ExitCode::RpcError,
+        format!(
+            "Validator RPC returned HTTP {} with a non-JSON body: {}",
+            status, snippet
+        ),
+    )
+}
+
+/// Send `request` and return its `result`, or with `dry_run` print it and
+/// return `None` without touching the network.
+async fn send(
+    client: &reqwest::Client,
+    rpc_url: &str,
+    request: &Value,
+    dry_run: bool,
+) -> Result<Option<Value>> {
+    if dry_run {
+        println!("{}", dry_run_text(rpc_url, request));
+        return Ok(None);
+    }
 
     let response = client
         .post(format!("{}/rpc", rpc_url))
-        .json(&request)
+        .json(request)
         .send()
         .await
-        .context("Failed to connect to validator RPC")?;
+        .map_err(|e| transport_error(rpc_url, &e))?;
 
     let status = response.status();
-    let json: Value = response
-        .json()
+    let bytes = response
+        .bytes()
         .await
-        .context("Failed to parse RPC response")?;
+        .map_err(|e| transport_error(rpc_url, &e))?;
+    let json: Value = serde_json::from_slice(&bytes).map_err(|_| non_json_error(status, &bytes))?;
 
     if let Some(error) = json.get("error") {
         let msg = error
             .get("message")
             .and_then(|m| m.as_str())
             .unwrap_or("Unknown RPC error");
-        anyhow::bail!("RPC error (HTTP {}): {}", status, msg);
+        return Err(CliError::new(
+            ExitCode::RpcError,
+            format!("RPC error (HTTP {}): {}", status, msg),
+        )
+        .into());
     }
 
     let result = json.get("result").cloned().unwrap_or(Value::Null);
-    Ok(result)
+    Ok(Some(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::exit_code_for;
+
+    #[test]
+    fn test_build_client_missing_ca_cert() {
+        let path = Path::new("/nonexistent/bounty-cli-test-ca.pem");
+        let err = build_client(false, Some(path), None).unwrap_err();
+
+        assert_eq!(exit_code_for(&err), ExitCode::InvalidInput);
+        let msg = err.to_string();
+        assert!(msg.contains("Failed to read CA certificate"));
+        assert!(msg.contains("bounty-cli-test-ca.pem"));
+    }
+
+    #[test]
+    fn test_build_client_invalid_proxy() {
+        let err = build_client(false, None, Some("http://[::1")).unwrap_err();
+
+        assert_eq!(exit_code_for(&err), ExitCode::InvalidInput);
+        assert!(err.to_string().contains("Invalid proxy URL 'http://[::1'"));
+
+        assert!(build_client(false, None, Some("http://proxy.internal:3128")).is_ok());
+    }
+
+    #[test]
+    fn test_auth_message_hashes_canonical_body() {
+        let body = serde_json::json!({ "issue_urls": ["u"], "github_username": "alice" });
+        let message = auth_message("POST", "/register", Some(&body), "1705590000:00ff");
+
+        let canonical = r#"{"github_username":"alice","issue_urls":["u"]}"#;
+        assert_eq!(
+            message,
+            format!(
+                "challenge:bounty-challenge:POST:/register:{}:1705590000:00ff",
+                hex::encode(Sha256::digest(canonical.as_bytes()))
+            )
+        );
+
+        let empty = auth_message("GET", "/status", None, "n");
+        assert!(empty.ends_with(&format!("{}:n", hex::encode(Sha256::digest(b"")))));
+    }
+
+    #[test]
+    fn test_signed_request_verifies_the_way_the_validator_checks_it() {
+        use sp_core::crypto::{Pair as _, Ss58Codec};
+        use sp_core::sr25519;
+
+        let pair = Keypair::from_phrase(
+            "bottom drive obey lake curtain smoke basket hold race lonely fit walk",
+        )
+        .unwrap();
+        let body = serde_json::json!({ "issue_urls": ["u"], "idempotency_key": "k" });
+        let headers = auth_headers("POST", "/claim", Some(&body), "1705590000:00ff", &pair);
+        let envelope = request_envelope("POST", "/claim", Some(body), Some(headers)).unwrap();
+        let params = &envelope["params"];
+
+        // The validator hashes the body as received, rebuilds the message
+        // from the headers and verifies X-Signature as sr25519 under X-Hotkey.
+        let header = |name: &str| hex::decode(params["headers"][name].as_str().unwrap()).unwrap();
+        let public = sr25519::Public::from_raw(header("X-Hotkey").try_into().unwrap());
+        let signature = sr25519::Signature::from_raw(header("X-Signature").try_into().unwrap());
+        let body_hash = hex::encode(Sha256::digest(serde_json::to_vec(&params["body"]).unwrap()));
+        let message = format!(
+            "challenge:{}:{}:{}:{}:{}",
+            CHALLENGE_ID,
+            params["method"].as_str().unwrap(),
+            params["path"].as_str().unwrap(),
+            body_hash,
+            params["headers"]["X-Nonce"].as_str().unwrap()
+        );
+        assert!(sr25519::Pair::verify(
+            &signature,
+            message.as_bytes(),
+            &public
+        ));
+        assert!(params["body"].get("scheme").is_none());
+
+        // The challenge stores the hotkey under the generic prefix.
+        assert_eq!(public.to_ss58check(), pair.to_ss58_with_format(42));
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_prints_envelope_without_sending() {
+        let headers = HashMap::from([("X-Signature".to_string(), "ab".repeat(64))]);
+        let request = request_envelope(
+            "POST",
+            "/register",
+            Some(serde_json::json!({ "github_username": "alice" })),
+            Some(headers),
+        )
+        .unwrap();
+        assert_eq!(
+            request,
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": "challenge_call",
+                "params": {
+                    "challengeId": "bounty-challenge",
+                    "method": "POST",
+                    "path": "/register",
+                    "body": { "github_username": "alice" },
+                    "headers": { "X-Signature": "ab".repeat(64) },
+                },
+                "id": 1,
+            })
+        );
+
+        let text = dry_run_text("http://127.0.0.1:9", &request);
+        assert!(text.starts_with("POST http://127.0.0.1:9/rpc\n{"));
+        let printed: Value = serde_json::from_str(text.split_once('\n').unwrap().1).unwrap();
+        assert_eq!(printed, request);
+
+        // Nothing listens on the discard port: sending would fail to connect.
+        let sent = send(client(), "http://127.0.0.1:9", &request, true)
+            .await
+            .unwrap();
+        assert!(sent.is_none());
+    }
+
+    /// A local server that reads one request and answers with `response`,
+    /// or never answers when it is `None`. Returns its base URL.
+    fn mock_server(response: Option<&'static str>) -> String {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0u8; 4096];
+            let _ = stream.read(&mut buf);
+            match response {
+                Some(response) => {
+                    let _ = stream.write_all(response.as_bytes());
+                }
+                None => std::thread::sleep(Duration::from_secs(5)),
+            }
+        });
+        url
+    }
+
+    async fn send_error(client: &reqwest::Client, rpc_url: &str) -> anyhow::Error {
+        let request = request_envelope("GET", "/stats", None, None).unwrap();
+        send(client, rpc_url, &request, false).await.unwrap_err()
+    }
+
+    #[tokio::test]
+    async fn test_timeout_is_reported_as_timeout() {
+        let url = mock_server(None);
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_millis(200))
+            .build()
+            .unwrap();
+
+        let err = send_error(&client, &url).await;
+        assert_eq!(exit_code_for(&err), ExitCode::Network);
+        assert!(err.to_string().starts_with("Timed out waiting"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_html_error_page_quotes_status_and_body() {
+        let url = mock_server(Some(
+            "HTTP/1.1 500 Internal Server Error\r\nContent-Type: text/html\r\n\
+             Content-Length: 51\r\nConnection: close\r\n\r\n\
+             <html>\n<body>Upstream   exploded</body>\n</html>\n\n\n\n",
+        ));
+
+        let err = send_error(&reqwest::Client::new(), &url).await;
+        assert_eq!(exit_code_for(&err), ExitCode::RpcError);
+        let msg = err.to_string();
+        assert!(msg.contains("HTTP 500 Internal Server Error"), "{}", msg);
+        assert!(msg.contains("<body>Upstream exploded</body>"), "{}", msg);
+    }
+
+    #[tokio::test]
+    async fn test_connection_refused_is_reported_as_connect_failure() {
+        // Bind and release a port so nothing is listening on it.
+        let addr = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+
+        let err = send_error(&reqwest::Client::new(), &format!("http://{}", addr)).await;
+        assert_eq!(exit_code_for(&err), ExitCode::Network);
+        let msg = err.to_string();
+        assert!(msg.starts_with("Could not connect"), "{}", msg);
+        assert!(!msg.contains("Timed out"));
+    }
 }
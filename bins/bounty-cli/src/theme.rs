@@ -0,0 +1,237 @@
+use console::{style, StyledObject};
+use ratatui::style::{Color, Style};
+use ratatui::symbols::border;
+use ratatui::widgets::{Block, Borders};
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Theme {
+    Default,
+    /// Swaps red/green status indicators for orange/blue.
+    Colorblind,
+}
+
+impl Theme {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Self::Default),
+            "colorblind" => Some(Self::Colorblind),
+            _ => None,
+        }
+    }
+}
+
+static COLORS_ENABLED: AtomicBool = AtomicBool::new(true);
+static ASCII_MODE: AtomicBool = AtomicBool::new(false);
+static THEME: AtomicU8 = AtomicU8::new(0);
+
+/// Apply the color settings for the whole process. Colors are disabled when
+/// `no_color` is set or the `NO_COLOR` env var is present and non-empty.
+pub fn init(no_color: bool, theme: Theme) {
+    let env_no_color = std::env::var("NO_COLOR")
+        .map(|v| !v.is_empty())
+        .unwrap_or(false);
+    let enabled = !(no_color || env_no_color);
+
+    COLORS_ENABLED.store(enabled, Ordering::Relaxed);
+    THEME.store(theme as u8, Ordering::Relaxed);
+    if !enabled {
+        console::set_colors_enabled(false);
+        console::set_colors_enabled_stderr(false);
+    }
+}
+
+pub fn colors_enabled() -> bool {
+    COLORS_ENABLED.load(Ordering::Relaxed)
+}
+
+fn theme() -> Theme {
+    match THEME.load(Ordering::Relaxed) {
+        1 => Theme::Colorblind,
+        _ => Theme::Default,
+    }
+}
+
+fn good_color() -> console::Color {
+    match theme() {
+        Theme::Default => console::Color::Green,
+        Theme::Colorblind => console::Color::Blue,
+    }
+}
+
+fn bad_color() -> console::Color {
+    match theme() {
+        Theme::Default => console::Color::Red,
+        Theme::Colorblind => console::Color::Color256(208),
+    }
+}
+
+/// Style a positive status indicator (valid, claimed, success).
+pub fn good<D>(val: D) -> StyledObject<D> {
+    style(val).fg(good_color())
+}
+
+/// Style a negative status indicator (invalid, rejected, failure).
+pub fn bad<D>(val: D) -> StyledObject<D> {
+    style(val).fg(bad_color())
+}
+
+/// Foreground style for the TUI views; a no-op when colors are disabled.
+pub fn fg(color: Color) -> Style {
+    if !colors_enabled() {
+        return Style::default();
+    }
+    let color = match (theme(), color) {
+        (Theme::Colorblind, Color::Green) => Color::Blue,
+        (Theme::Colorblind, Color::Red) => Color::Indexed(208),
+        (_, c) => c,
+    };
+    Style::default().fg(color)
+}
+
+/// Background style for the TUI views; a no-op when colors are disabled.
+pub fn bg(color: Color) -> Style {
+    if !colors_enabled() {
+        return Style::default();
+    }
+    Style::default().bg(color)
+}
+
+/// Characters used for rules, bars, rank movement and TUI borders.
+pub struct Glyphs {
+    pub rule: &'static str,
+    pub bar: &'static str,
+    pub dash: &'static str,
+    pub up: &'static str,
+    pub down: &'static str,
+    pub scroll: &'static str,
+    pub delta: &'static str,
+    pub border: border::Set,
+}
+
+pub const UNICODE: Glyphs = Glyphs {
+    rule: "─",
+    bar: "█",
+    dash: "—",
+    up: "▲",
+    down: "▼",
+    scroll: "↑/↓",
+    delta: "Δ",
+    border: border::PLAIN,
+};
+
+/// Fallback for consoles that cannot render Unicode (e.g. legacy Windows
+/// code pages).
+pub const ASCII: Glyphs = Glyphs {
+    rule: "-",
+    bar: "#",
+    dash: "-",
+    up: "+",
+    down: "-",
+    scroll: "Up/Down",
+    delta: "d",
+    border: border::Set {
+        top_left: "+",
+        top_right: "+",
+        bottom_left: "+",
+        bottom_right: "+",
+        vertical_left: "|",
+        vertical_right: "|",
+        horizontal_top: "-",
+        horizontal_bottom: "-",
+    },
+};
+
+/// Whether a locale string (`LC_ALL`/`LC_CTYPE`/`LANG`) names a UTF-8
+/// encoding, e.g. `en_US.UTF-8` or `C.utf8`.
+fn is_utf8_locale(locale: &str) -> bool {
+    let lower = locale.to_ascii_lowercase();
+    lower.contains("utf-8") || lower.contains("utf8")
+}
+
+/// Pick ASCII output when the effective locale is set but not UTF-8, or on
+/// Windows consoles outside Windows Terminal when no locale is set.
+fn wants_ascii(locale: Option<&str>, windows: bool, windows_terminal: bool) -> bool {
+    match locale {
+        Some(l) => !is_utf8_locale(l),
+        None => windows && !windows_terminal,
+    }
+}
+
+/// Auto-detect from the environment whether the terminal needs `--ascii`.
+pub fn detect_ascii() -> bool {
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|v| !v.is_empty());
+    wants_ascii(
+        locale.as_deref(),
+        cfg!(windows),
+        std::env::var_os("WT_SESSION").is_some(),
+    )
+}
+
+pub fn set_ascii(ascii: bool) {
+    ASCII_MODE.store(ascii, Ordering::Relaxed);
+}
+
+/// The glyph set for this process.
+pub fn glyphs() -> &'static Glyphs {
+    if ASCII_MODE.load(Ordering::Relaxed) {
+        &ASCII
+    } else {
+        &UNICODE
+    }
+}
+
+/// A horizontal rule `width` characters wide.
+pub fn rule(width: usize) -> String {
+    glyphs().rule.repeat(width)
+}
+
+/// A bordered TUI block drawn with the current glyph set.
+pub fn block() -> Block<'static> {
+    Block::default()
+        .borders(Borders::ALL)
+        .border_set(glyphs().border)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_glyphs_are_ascii() {
+        let b = &ASCII.border;
+        for glyph in [
+            ASCII.rule,
+            ASCII.bar,
+            ASCII.dash,
+            ASCII.up,
+            ASCII.down,
+            ASCII.scroll,
+            ASCII.delta,
+            b.top_left,
+            b.top_right,
+            b.bottom_left,
+            b.bottom_right,
+            b.vertical_left,
+            b.vertical_right,
+            b.horizontal_top,
+            b.horizontal_bottom,
+        ] {
+            assert!(glyph.is_ascii(), "{:?}", glyph);
+        }
+    }
+
+    #[test]
+    fn test_wants_ascii() {
+        assert!(!wants_ascii(Some("en_US.UTF-8"), false, false));
+        assert!(!wants_ascii(Some("C.utf8"), true, false));
+        assert!(wants_ascii(Some("C"), false, false));
+        assert!(wants_ascii(Some("en_US.ISO-8859-1"), false, false));
+        assert!(!wants_ascii(None, false, false));
+        assert!(wants_ascii(None, true, false));
+        assert!(!wants_ascii(None, true, true));
+    }
+}
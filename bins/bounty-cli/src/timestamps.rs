@@ -0,0 +1,220 @@
+use chrono::{DateTime, FixedOffset, Local, Utc};
+use chrono_tz::Tz;
+use std::sync::OnceLock;
+
+/// Time zone used for absolute timestamps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Zone {
+    Utc,
+    Local,
+    Fixed(FixedOffset),
+    /// An IANA zone such as `Europe/Berlin`, with its daylight saving rules.
+    Named(Tz),
+}
+
+impl Zone {
+    /// Parse `--timezone`: `UTC`, `local`, a fixed offset such as `+02:00`
+    /// or `-0530`, or an IANA name such as `Europe/Berlin`.
+    pub fn parse(name: &str) -> Result<Self, String> {
+        let trimmed = name.trim();
+        match trimmed.to_ascii_lowercase().as_str() {
+            "utc" | "z" => return Ok(Self::Utc),
+            "local" => return Ok(Self::Local),
+            _ => {}
+        }
+        if let Some(offset) = parse_offset(trimmed) {
+            return Ok(Self::Fixed(offset));
+        }
+        if let Ok(tz) = trimmed.parse::<Tz>() {
+            return Ok(Self::Named(tz));
+        }
+        if trimmed.contains('/') {
+            return Err(format!(
+                "Unknown time zone '{}': expected an IANA name such as Europe/Berlin",
+                trimmed
+            ));
+        }
+        Err(format!(
+            "Invalid time zone '{}': expected UTC, local, an offset such as +02:00 \
+             or an IANA name such as Europe/Berlin",
+            trimmed
+        ))
+    }
+}
+
+/// `+HH:MM`, `-HH:MM`, `+HHMM` or `+HH`, within ±23:59.
+fn parse_offset(s: &str) -> Option<FixedOffset> {
+    let (sign, rest) = match s.as_bytes().first()? {
+        b'+' => (1, &s[1..]),
+        b'-' => (-1, &s[1..]),
+        _ => return None,
+    };
+    let digits: String = rest.chars().filter(|c| *c != ':').collect();
+    if !digits.chars().all(|c| c.is_ascii_digit()) || rest.matches(':').count() > 1 {
+        return None;
+    }
+    let (hours, minutes) = match digits.len() {
+        2 => (digits.parse::<i32>().ok()?, 0),
+        4 => (
+            digits[..2].parse::<i32>().ok()?,
+            digits[2..].parse::<i32>().ok()?,
+        ),
+        _ => return None,
+    };
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct TimeDisplay {
+    pub zone: Zone,
+    /// Full date and time instead of "3 hours ago".
+    pub absolute: bool,
+}
+
+static DISPLAY: OnceLock<TimeDisplay> = OnceLock::new();
+
+/// Apply the timestamp settings for the whole process.
+pub fn init(display: TimeDisplay) {
+    let _ = DISPLAY.set(display);
+}
+
+fn display() -> TimeDisplay {
+    DISPLAY.get().copied().unwrap_or(TimeDisplay {
+        zone: Zone::Utc,
+        absolute: false,
+    })
+}
+
+fn unit(count: i64, name: &str) -> String {
+    if count == 1 {
+        format!("1 {}", name)
+    } else {
+        format!("{} {}s", count, name)
+    }
+}
+
+/// "3 hours ago" for a timestamp `delta_secs` in the past ("in 3 hours" for
+/// a negative delta). Each value is rounded down to its largest unit.
+pub fn relative(delta_secs: i64) -> String {
+    let secs = delta_secs.unsigned_abs() as i64;
+    if secs < 60 {
+        return "just now".to_string();
+    }
+    let amount = match secs {
+        s if s < 3600 => unit(s / 60, "minute"),
+        s if s < 86_400 => unit(s / 3600, "hour"),
+        s if s < 30 * 86_400 => unit(s / 86_400, "day"),
+        s if s < 365 * 86_400 => unit(s / (30 * 86_400), "month"),
+        s => unit(s / (365 * 86_400), "year"),
+    };
+    if delta_secs < 0 {
+        format!("in {}", amount)
+    } else {
+        format!("{} ago", amount)
+    }
+}
+
+fn absolute(at: DateTime<Utc>, zone: Zone) -> String {
+    const WITH_OFFSET: &str = "%Y-%m-%d %H:%M:%S %:z";
+    match zone {
+        Zone::Utc => at.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+        Zone::Local => at.with_timezone(&Local).format(WITH_OFFSET).to_string(),
+        Zone::Fixed(offset) => at.with_timezone(&offset).format(WITH_OFFSET).to_string(),
+        Zone::Named(tz) => at.with_timezone(&tz).format(WITH_OFFSET).to_string(),
+    }
+}
+
+fn format_at(ms: i64, now_ms: i64, display: TimeDisplay) -> String {
+    if ms <= 0 {
+        return "never".to_string();
+    }
+    match DateTime::<Utc>::from_timestamp_millis(ms) {
+        Some(at) if display.absolute => absolute(at, display.zone),
+        Some(_) => relative((now_ms - ms) / 1000),
+        None => ms.to_string(),
+    }
+}
+
+/// Format a Unix timestamp in milliseconds per `--absolute`/`--timezone`.
+/// Zero (the server's "never") prints as "never".
+pub fn format_ms(ms: i64) -> String {
+    format_at(ms, Utc::now().timestamp_millis(), display())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative() {
+        assert_eq!(relative(0), "just now");
+        assert_eq!(relative(59), "just now");
+        assert_eq!(relative(60), "1 minute ago");
+        assert_eq!(relative(45 * 60), "45 minutes ago");
+        assert_eq!(relative(3 * 3600 + 1200), "3 hours ago");
+        assert_eq!(relative(86_400), "1 day ago");
+        assert_eq!(relative(75 * 86_400), "2 months ago");
+        assert_eq!(relative(800 * 86_400), "2 years ago");
+        assert_eq!(relative(-2 * 3600), "in 2 hours");
+    }
+
+    #[test]
+    fn test_absolute_in_zone() {
+        // 2024-01-18 15:00:00 UTC
+        let ms = 1_705_590_000_000;
+        let utc = TimeDisplay {
+            zone: Zone::Utc,
+            absolute: true,
+        };
+        assert_eq!(format_at(ms, 0, utc), "2024-01-18 15:00:00 UTC");
+
+        let plus_one = TimeDisplay {
+            zone: Zone::parse("+01:00").unwrap(),
+            absolute: true,
+        };
+        assert_eq!(format_at(ms, 0, plus_one), "2024-01-18 16:00:00 +01:00");
+
+        // Named zones follow daylight saving time: +01:00 in January,
+        // +02:00 in July.
+        let berlin = TimeDisplay {
+            zone: Zone::parse("Europe/Berlin").unwrap(),
+            absolute: true,
+        };
+        assert_eq!(format_at(ms, 0, berlin), "2024-01-18 16:00:00 +01:00");
+        let july = ms + 182 * 86_400_000;
+        assert_eq!(format_at(july, 0, berlin), "2024-07-18 17:00:00 +02:00");
+
+        let ago = TimeDisplay {
+            zone: Zone::Utc,
+            absolute: false,
+        };
+        assert_eq!(format_at(ms, ms + 7_200_000, ago), "2 hours ago");
+        assert_eq!(format_at(0, ms, ago), "never");
+    }
+
+    #[test]
+    fn test_parse_zone() {
+        assert_eq!(Zone::parse("UTC"), Ok(Zone::Utc));
+        assert_eq!(Zone::parse("local"), Ok(Zone::Local));
+        assert_eq!(
+            Zone::parse("-0530"),
+            Ok(Zone::Fixed(
+                FixedOffset::west_opt(5 * 3600 + 30 * 60).unwrap()
+            ))
+        );
+
+        assert_eq!(
+            Zone::parse("America/New_York"),
+            Ok(Zone::Named(chrono_tz::America::New_York))
+        );
+
+        let err = Zone::parse("Mars/Olympus").unwrap_err();
+        assert!(err.contains("Unknown time zone"), "{}", err);
+        for bad in ["", "+24:00", "+1:30", "+01:60", "soon", "+01:00:00"] {
+            assert!(Zone::parse(bad).is_err(), "{}", bad);
+        }
+    }
+}
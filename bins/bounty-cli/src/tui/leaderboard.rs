@@ -2,35 +2,169 @@ use anyhow::Result;
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
 use ratatui::{prelude::*, widgets::*};
 use serde_json::Value;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 use crate::rpc::rpc_call;
+use crate::theme;
 
 struct LeaderboardEntry {
     rank: u64,
     hotkey: String,
+    hotkey_full: String,
     github: String,
     net_points: f64,
     valid: u64,
     invalid: u64,
     stars: u64,
     weight: f64,
+    last_epoch: u64,
+}
+
+/// Terminals narrower than this get the compact layout when none is chosen.
+const COMPACT_BELOW_COLUMNS: u16 = 80;
+
+/// Which leaderboard columns to show.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TableLayout {
+    /// Drops the Invalid and Stars columns.
+    Compact,
+    Normal,
+    /// Shows the full hotkey and the epoch of the last update.
+    Wide,
+}
+
+impl TableLayout {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "compact" => Some(Self::Compact),
+            "normal" => Some(Self::Normal),
+            "wide" => Some(Self::Wide),
+            _ => None,
+        }
+    }
+
+    fn for_width(columns: u16) -> Self {
+        if columns < COMPACT_BELOW_COLUMNS {
+            Self::Compact
+        } else {
+            Self::Normal
+        }
+    }
+
+    fn columns(self) -> &'static [Column] {
+        use Column::*;
+        match self {
+            Self::Compact => &[Rank, Hotkey, GitHub, NetPoints, Valid, Weight],
+            Self::Normal => &[
+                Rank, Hotkey, GitHub, NetPoints, Valid, Invalid, Stars, Weight,
+            ],
+            Self::Wide => &[
+                Rank, HotkeyFull, GitHub, NetPoints, Valid, Invalid, Stars, Weight, LastEpoch,
+            ],
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Column {
+    Rank,
+    Hotkey,
+    HotkeyFull,
+    GitHub,
+    NetPoints,
+    Valid,
+    Invalid,
+    Stars,
+    Weight,
+    LastEpoch,
+}
+
+impl Column {
+    fn header(self) -> &'static str {
+        match self {
+            Self::Rank => "Rank",
+            Self::Hotkey | Self::HotkeyFull => "Hotkey",
+            Self::GitHub => "GitHub",
+            Self::NetPoints => "Net Pts",
+            Self::Valid => "Valid",
+            Self::Invalid => "Invalid",
+            Self::Stars => "Stars",
+            Self::Weight => "Weight",
+            Self::LastEpoch => "Updated (epoch)",
+        }
+    }
+
+    fn cell(self, e: &LeaderboardEntry) -> String {
+        match self {
+            Self::Rank => e.rank.to_string(),
+            Self::Hotkey => e.hotkey.clone(),
+            Self::HotkeyFull => e.hotkey_full.clone(),
+            Self::GitHub => e.github.clone(),
+            Self::NetPoints => format!("{:.2}", e.net_points),
+            Self::Valid => e.valid.to_string(),
+            Self::Invalid => e.invalid.to_string(),
+            Self::Stars => e.stars.to_string(),
+            Self::Weight => format!("{:.4}", e.weight),
+            Self::LastEpoch => e.last_epoch.to_string(),
+        }
+    }
+}
+
+/// Width of each column: the longest of its header and cells.
+fn column_widths(columns: &[Column], entries: &[&LeaderboardEntry]) -> Vec<u16> {
+    columns
+        .iter()
+        .map(|c| {
+            entries
+                .iter()
+                .map(|e| c.cell(e).chars().count())
+                .chain(std::iter::once(c.header().chars().count()))
+                .max()
+                .unwrap_or(0) as u16
+        })
+        .collect()
 }
 
 struct App {
     entries: Vec<LeaderboardEntry>,
     scroll_offset: usize,
     error: Option<String>,
+    /// Active search filter (case-insensitive substring).
+    filter: String,
+    /// Whether keystrokes are currently edited into `filter`.
+    searching: bool,
+    /// Chosen layout; `None` picks one from the terminal width.
+    layout: Option<TableLayout>,
 }
 
-fn parse_entries(data: &Value) -> Vec<LeaderboardEntry> {
-    let body = data.get("body").unwrap_or(data);
-    let arr = match body.as_array() {
-        Some(a) => a,
-        None => return vec![],
-    };
+impl App {
+    /// Entries matching the active filter, checked against the full hotkey
+    /// and GitHub username rather than the truncated display strings.
+    fn visible(&self) -> Vec<&LeaderboardEntry> {
+        if self.filter.is_empty() {
+            return self.entries.iter().collect();
+        }
+        let needle = self.filter.to_lowercase();
+        self.entries
+            .iter()
+            .filter(|e| {
+                e.hotkey_full.to_lowercase().contains(&needle)
+                    || e.github.to_lowercase().contains(&needle)
+            })
+            .collect()
+    }
 
-    arr.iter()
+    fn clamp_scroll(&mut self) {
+        let len = self.visible().len();
+        if self.scroll_offset >= len {
+            self.scroll_offset = len.saturating_sub(1);
+        }
+    }
+}
+
+fn parse_entries(data: &Value) -> Vec<LeaderboardEntry> {
+    crate::views::leaderboard::rows(data)
+        .iter()
         .map(|e| {
             let hotkey = e.get("hotkey").and_then(|v| v.as_str()).unwrap_or("?");
             let hotkey_short = if hotkey.len() > 14 {
@@ -41,6 +175,7 @@ fn parse_entries(data: &Value) -> Vec<LeaderboardEntry> {
             LeaderboardEntry {
                 rank: e.get("rank").and_then(|v| v.as_u64()).unwrap_or(0),
                 hotkey: hotkey_short,
+                hotkey_full: hotkey.to_string(),
                 github: e
                     .get("github_username")
                     .and_then(|v| v.as_str())
@@ -54,118 +189,158 @@ fn parse_entries(data: &Value) -> Vec<LeaderboardEntry> {
                     .unwrap_or(0),
                 stars: e.get("star_count").and_then(|v| v.as_u64()).unwrap_or(0),
                 weight: e.get("score").and_then(|v| v.as_f64()).unwrap_or(0.0),
+                last_epoch: e.get("last_epoch").and_then(|v| v.as_u64()).unwrap_or(0),
             }
         })
         .collect()
 }
 
-fn ui(frame: &mut Frame, app: &App) {
+fn ui(frame: &mut Frame, app: &App, freshness: Span<'static>) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Min(3), Constraint::Length(3)])
         .split(frame.area());
 
-    let header = Row::new(vec![
-        Cell::from("Rank"),
-        Cell::from("Hotkey"),
-        Cell::from("GitHub"),
-        Cell::from("Net Pts"),
-        Cell::from("Valid"),
-        Cell::from("Invalid"),
-        Cell::from("Stars"),
-        Cell::from("Weight"),
-    ])
-    .style(Style::default().fg(Color::Yellow).bold())
-    .height(1);
-
-    let rows: Vec<Row> = app
-        .entries
+    let layout = app
+        .layout
+        .unwrap_or_else(|| TableLayout::for_width(frame.area().width));
+    let columns = layout.columns();
+
+    let header = Row::new(columns.iter().map(|c| Cell::from(c.header())))
+        .style(theme::fg(Color::Yellow).bold())
+        .height(1);
+
+    let visible = app.visible();
+    let rows: Vec<Row> = visible
         .iter()
         .skip(app.scroll_offset)
-        .map(|e| {
-            Row::new(vec![
-                Cell::from(e.rank.to_string()),
-                Cell::from(e.hotkey.clone()),
-                Cell::from(e.github.clone()),
-                Cell::from(format!("{:.2}", e.net_points)),
-                Cell::from(e.valid.to_string()),
-                Cell::from(e.invalid.to_string()),
-                Cell::from(e.stars.to_string()),
-                Cell::from(format!("{:.4}", e.weight)),
-            ])
-        })
+        .map(|e| Row::new(columns.iter().map(|c| Cell::from(c.cell(e)))))
         .collect();
 
-    let widths = [
-        Constraint::Length(6),
-        Constraint::Length(17),
-        Constraint::Length(18),
-        Constraint::Length(10),
-        Constraint::Length(7),
-        Constraint::Length(9),
-        Constraint::Length(7),
-        Constraint::Length(10),
-    ];
+    let widths: Vec<Constraint> = column_widths(columns, &visible)
+        .into_iter()
+        .map(Constraint::Length)
+        .collect();
 
     let title = if let Some(ref err) = app.error {
-        format!(" Leaderboard — ERROR: {} ", err)
+        format!(" Leaderboard {} ERROR: {} ", theme::glyphs().dash, err)
+    } else if !app.filter.is_empty() {
+        format!(
+            " Leaderboard {} {} of {} miners (filtered) ",
+            theme::glyphs().dash,
+            visible.len(),
+            app.entries.len()
+        )
     } else {
-        format!(" Leaderboard — {} miners ", app.entries.len())
+        format!(
+            " Leaderboard {} {} miners ",
+            theme::glyphs().dash,
+            app.entries.len()
+        )
     };
 
     let table = Table::new(rows, widths)
         .header(header)
         .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan))
+            theme::block()
+                .border_style(theme::fg(Color::Cyan))
                 .title(title),
         )
-        .row_highlight_style(Style::default().bg(Color::DarkGray));
+        .row_highlight_style(theme::bg(Color::DarkGray));
 
     frame.render_widget(table, chunks[0]);
 
-    let help = Paragraph::new(" ↑/↓ scroll  |  q/Esc quit  |  auto-refresh 5s")
-        .style(Style::default().fg(Color::DarkGray))
-        .block(Block::default().borders(Borders::ALL));
+    let help_text = if app.searching {
+        format!(
+            " Search: {}_  |  Enter apply  |  Esc clear  |  ",
+            app.filter
+        )
+    } else if !app.filter.is_empty() {
+        format!(
+            " Filter: {}  |  / edit  |  Esc clear  |  {} scroll  |  q quit  |  ",
+            app.filter,
+            theme::glyphs().scroll
+        )
+    } else {
+        format!(
+            " {} scroll  |  / search  |  q/Esc quit  |  {}  |  ",
+            theme::glyphs().scroll,
+            super::refresh_label()
+        )
+    };
+    let help = Paragraph::new(Line::from(vec![Span::raw(help_text), freshness]))
+        .style(theme::fg(Color::DarkGray))
+        .block(theme::block());
     frame.render_widget(help, chunks[1]);
 }
 
-pub async fn run(rpc_url: &str) -> Result<()> {
+/// Run the live leaderboard. `sort` is passed through to the server's
+/// `/leaderboard?sort=` parameter; `None` keeps the default score order.
+/// Without a `layout`, terminals under 80 columns get the compact one.
+pub async fn run(rpc_url: &str, sort: Option<&str>, layout: Option<TableLayout>) -> Result<()> {
+    let path = match sort {
+        Some(field) => format!("/leaderboard?sort={}", field),
+        None => "/leaderboard".to_string(),
+    };
     let mut terminal = super::setup_terminal()?;
     let mut app = App {
         entries: vec![],
         scroll_offset: 0,
         error: None,
+        filter: String::new(),
+        searching: false,
+        layout,
     };
 
-    let mut last_fetch = Instant::now() - Duration::from_secs(10);
-    let refresh_interval = Duration::from_secs(5);
+    let mut refresh = super::RefreshTimer::new();
 
     loop {
-        if last_fetch.elapsed() >= refresh_interval {
-            match rpc_call(rpc_url, "GET", "/leaderboard", None).await {
+        if refresh.due() {
+            match rpc_call(rpc_url, "GET", &path, None).await {
                 Ok(data) => {
                     app.entries = parse_entries(&data);
                     app.error = None;
+                    app.clamp_scroll();
+                    refresh.record_success();
                 }
                 Err(e) => app.error = Some(e.to_string()),
             }
-            last_fetch = Instant::now();
         }
 
-        terminal.draw(|f| ui(f, &app))?;
+        let freshness = refresh.freshness();
+        terminal.draw(|f| ui(f, &app, freshness))?;
 
         if event::poll(Duration::from_millis(100))? {
             if let Event::Key(key) = event::read()? {
                 if key.kind == KeyEventKind::Press {
+                    if app.searching {
+                        match key.code {
+                            KeyCode::Enter => app.searching = false,
+                            KeyCode::Esc => {
+                                app.searching = false;
+                                app.filter.clear();
+                            }
+                            KeyCode::Backspace => {
+                                app.filter.pop();
+                            }
+                            KeyCode::Char(c) => app.filter.push(c),
+                            _ => {}
+                        }
+                        app.scroll_offset = 0;
+                        continue;
+                    }
                     match key.code {
+                        KeyCode::Char('/') => app.searching = true,
+                        KeyCode::Esc if !app.filter.is_empty() => {
+                            app.filter.clear();
+                            app.scroll_offset = 0;
+                        }
                         KeyCode::Char('q') | KeyCode::Esc => break,
                         KeyCode::Up | KeyCode::Char('k') => {
                             app.scroll_offset = app.scroll_offset.saturating_sub(1);
                         }
                         KeyCode::Down | KeyCode::Char('j') => {
-                            if app.scroll_offset + 1 < app.entries.len() {
+                            if app.scroll_offset + 1 < app.visible().len() {
                                 app.scroll_offset += 1;
                             }
                         }
@@ -179,3 +354,46 @@ pub async fn run(rpc_url: &str) -> Result<()> {
     super::restore_terminal(&mut terminal)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(hotkey: &str, github: &str) -> LeaderboardEntry {
+        LeaderboardEntry {
+            rank: 1,
+            hotkey: hotkey.to_string(),
+            hotkey_full: hotkey.to_string(),
+            github: github.to_string(),
+            net_points: 12.5,
+            valid: 3,
+            invalid: 0,
+            stars: 2,
+            weight: 0.25,
+            last_epoch: 42,
+        }
+    }
+
+    #[test]
+    fn test_layout_for_terminal_width() {
+        assert_eq!(TableLayout::for_width(60), TableLayout::Compact);
+        assert_eq!(TableLayout::for_width(79), TableLayout::Compact);
+        assert_eq!(TableLayout::for_width(80), TableLayout::Normal);
+        assert_eq!(TableLayout::for_width(200), TableLayout::Normal);
+
+        let compact = TableLayout::Compact.columns();
+        assert!(!compact.contains(&Column::Stars));
+        assert!(!compact.contains(&Column::Invalid));
+        let wide = TableLayout::Wide.columns();
+        assert!(wide.contains(&Column::HotkeyFull));
+        assert!(wide.contains(&Column::LastEpoch));
+    }
+
+    #[test]
+    fn test_column_widths_follow_content() {
+        let a = entry("5Grwva", "a-very-long-github-name");
+        let b = entry("5F", "bob");
+        let widths = column_widths(&[Column::Rank, Column::GitHub, Column::Hotkey], &[&a, &b]);
+        assert_eq!(widths, [4, 23, 6]);
+    }
+}
@@ -7,8 +7,116 @@ use crossterm::{
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use rand::Rng;
 use ratatui::prelude::*;
 use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::theme;
+
+pub const DEFAULT_REFRESH_SECS: u64 = 5;
+/// Maximum deviation from the refresh interval, as a fraction of it.
+const REFRESH_JITTER: f64 = 0.1;
+
+static REFRESH_SECS: AtomicU64 = AtomicU64::new(DEFAULT_REFRESH_SECS);
+
+/// Set the auto-refresh interval of the live views for the whole process.
+pub fn set_refresh_secs(secs: u64) {
+    REFRESH_SECS.store(secs.max(1), Ordering::Relaxed);
+}
+
+fn refresh_secs() -> u64 {
+    REFRESH_SECS.load(Ordering::Relaxed)
+}
+
+/// Help-bar text describing the refresh interval.
+pub fn refresh_label() -> String {
+    format!("auto-refresh {}s", refresh_secs())
+}
+
+/// Scale `interval` by `1 + REFRESH_JITTER * factor`, with `factor` clamped
+/// to [-1, 1].
+fn jittered(interval: Duration, factor: f64) -> Duration {
+    interval.mul_f64(1.0 + REFRESH_JITTER * factor.clamp(-1.0, 1.0))
+}
+
+/// "45s" under a minute, then "3m".
+fn format_age(age: Duration) -> String {
+    let secs = age.as_secs();
+    if secs < 60 {
+        format!("{}s", secs)
+    } else {
+        format!("{}m", secs / 60)
+    }
+}
+
+/// Color of the freshness indicator. Data is normally up to one (jittered)
+/// interval old, so it stays gray until two intervals have passed without
+/// a successful fetch, turns yellow, and red after five.
+fn staleness_color(age: Option<Duration>, interval: Duration) -> Color {
+    match age {
+        Some(age) if age < interval * 2 => Color::DarkGray,
+        Some(age) if age < interval * 5 => Color::Yellow,
+        _ => Color::Red,
+    }
+}
+
+/// Polling timer shared by the live views. Due immediately, then every
+/// refresh interval ±10% so many open dashboards don't poll in lockstep.
+pub struct RefreshTimer {
+    interval: Duration,
+    next: Instant,
+    /// When a fetch last succeeded; `next` advances on errors too.
+    last_success: Option<Instant>,
+}
+
+impl RefreshTimer {
+    pub fn new() -> Self {
+        Self {
+            interval: Duration::from_secs(refresh_secs()),
+            next: Instant::now(),
+            last_success: None,
+        }
+    }
+
+    /// Note that the data on screen was just fetched.
+    pub fn record_success(&mut self) {
+        self.last_success = Some(Instant::now());
+    }
+
+    /// Footer span such as "last updated: 12s ago", colored by staleness.
+    pub fn freshness(&self) -> Span<'static> {
+        let Some(at) = self.last_success else {
+            return Span::styled(
+                "last updated: never",
+                theme::fg(staleness_color(None, self.interval)),
+            );
+        };
+        let age = at.elapsed();
+        Span::styled(
+            format!("last updated: {} ago", format_age(age)),
+            theme::fg(staleness_color(Some(age), self.interval)),
+        )
+    }
+
+    /// Whether a fetch is due; if so, schedules the next one.
+    pub fn due(&mut self) -> bool {
+        let now = Instant::now();
+        if now < self.next {
+            return false;
+        }
+        let factor = rand::thread_rng().gen_range(-1.0..=1.0);
+        self.next = now + jittered(self.interval, factor);
+        true
+    }
+}
+
+impl Default for RefreshTimer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 pub fn setup_terminal() -> Result<Terminal<CrosstermBackend<io::Stdout>>> {
     enable_raw_mode()?;
@@ -25,3 +133,34 @@ pub fn restore_terminal(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -
     terminal.show_cursor()?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jitter_stays_within_ten_percent() {
+        let interval = Duration::from_secs(5);
+        for factor in [-5.0, -1.0, -0.3, 0.0, 0.7, 1.0, 5.0] {
+            let d = jittered(interval, factor);
+            assert!(d >= Duration::from_millis(4500), "{:?}", d);
+            assert!(d <= Duration::from_millis(5500), "{:?}", d);
+        }
+        assert_eq!(jittered(interval, 0.0), interval);
+    }
+
+    #[test]
+    fn test_staleness_color() {
+        let interval = Duration::from_secs(5);
+        let color = |secs| staleness_color(Some(Duration::from_secs(secs)), interval);
+        assert_eq!(color(0), Color::DarkGray);
+        assert_eq!(color(6), Color::DarkGray);
+        assert_eq!(color(10), Color::Yellow);
+        assert_eq!(color(24), Color::Yellow);
+        assert_eq!(color(25), Color::Red);
+        assert_eq!(staleness_color(None, interval), Color::Red);
+
+        assert_eq!(format_age(Duration::from_secs(42)), "42s");
+        assert_eq!(format_age(Duration::from_secs(185)), "3m");
+    }
+}
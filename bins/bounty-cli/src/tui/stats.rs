@@ -2,9 +2,10 @@ use anyhow::Result;
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
 use ratatui::{prelude::*, widgets::*};
 use serde_json::Value;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 use crate::rpc::rpc_call;
+use crate::theme;
 
 struct StatsData {
     total_bounties: u64,
@@ -48,24 +49,16 @@ fn parse_stats(data: &Value) -> StatsData {
 
 fn stat_block<'a>(label: &'a str, value: u64, color: Color) -> Paragraph<'a> {
     let text = vec![
-        Line::from(Span::styled(
-            label,
-            Style::default().fg(Color::DarkGray).bold(),
-        )),
+        Line::from(Span::styled(label, theme::fg(Color::DarkGray).bold())),
         Line::from(""),
-        Line::from(Span::styled(
-            value.to_string(),
-            Style::default().fg(color).bold(),
-        )),
+        Line::from(Span::styled(value.to_string(), theme::fg(color).bold())),
     ];
-    Paragraph::new(text).alignment(Alignment::Center).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(color)),
-    )
+    Paragraph::new(text)
+        .alignment(Alignment::Center)
+        .block(theme::block().border_style(theme::fg(color)))
 }
 
-fn ui(frame: &mut Frame, stats: &StatsData, error: &Option<String>) {
+fn ui(frame: &mut Frame, stats: &StatsData, error: &Option<String>, freshness: Span<'static>) {
     let outer = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -76,14 +69,14 @@ fn ui(frame: &mut Frame, stats: &StatsData, error: &Option<String>) {
         .split(frame.area());
 
     let title_text = if let Some(ref err) = error {
-        format!(" Challenge Stats — ERROR: {} ", err)
+        format!(" Challenge Stats {} ERROR: {} ", theme::glyphs().dash, err)
     } else {
         " Challenge Stats ".to_string()
     };
     let title = Paragraph::new(title_text)
         .alignment(Alignment::Center)
-        .style(Style::default().fg(Color::Cyan).bold())
-        .block(Block::default().borders(Borders::ALL));
+        .style(theme::fg(Color::Cyan).bold())
+        .block(theme::block());
     frame.render_widget(title, outer[0]);
 
     let grid = Layout::default()
@@ -113,9 +106,12 @@ fn ui(frame: &mut Frame, stats: &StatsData, error: &Option<String>) {
         grid[3],
     );
 
-    let help = Paragraph::new(" q/Esc quit  |  auto-refresh 5s")
-        .style(Style::default().fg(Color::DarkGray))
-        .block(Block::default().borders(Borders::ALL));
+    let help = Paragraph::new(Line::from(vec![
+        Span::raw(format!(" q/Esc quit  |  {}  |  ", super::refresh_label())),
+        freshness,
+    ]))
+    .style(theme::fg(Color::DarkGray))
+    .block(theme::block());
     frame.render_widget(help, outer[2]);
 }
 
@@ -123,21 +119,22 @@ pub async fn run(rpc_url: &str) -> Result<()> {
     let mut terminal = super::setup_terminal()?;
     let mut stats = StatsData::default();
     let mut error: Option<String> = None;
-    let mut last_fetch = Instant::now() - Duration::from_secs(10);
+    let mut refresh = super::RefreshTimer::new();
 
     loop {
-        if last_fetch.elapsed() >= Duration::from_secs(5) {
+        if refresh.due() {
             match rpc_call(rpc_url, "GET", "/stats", None).await {
                 Ok(data) => {
                     stats = parse_stats(&data);
                     error = None;
+                    refresh.record_success();
                 }
                 Err(e) => error = Some(e.to_string()),
             }
-            last_fetch = Instant::now();
         }
 
-        terminal.draw(|f| ui(f, &stats, &error))?;
+        let freshness = refresh.freshness();
+        terminal.draw(|f| ui(f, &stats, &error, freshness))?;
 
         if event::poll(Duration::from_millis(100))? {
             if let Event::Key(key) = event::read()? {
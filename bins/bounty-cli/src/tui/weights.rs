@@ -2,72 +2,96 @@ use anyhow::Result;
 use crossterm::event::{self, Event, KeyCode, KeyEventKind};
 use ratatui::{prelude::*, widgets::*};
 use serde_json::Value;
-use std::time::{Duration, Instant};
+use std::time::Duration;
 
 use crate::rpc::rpc_call;
+use crate::theme;
 
 struct WeightEntry {
     hotkey: String,
     weight: f64,
 }
 
+fn short_hotkey(hotkey: &str) -> String {
+    if hotkey.len() > 14 {
+        format!("{}...", &hotkey[..14])
+    } else {
+        hotkey.to_string()
+    }
+}
+
+fn sort_by_weight(entries: &mut [WeightEntry]) {
+    entries.sort_by(|a, b| {
+        b.weight
+            .partial_cmp(&a.weight)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+}
+
+fn parse_weight_array(arr: &[Value]) -> Vec<WeightEntry> {
+    let mut entries: Vec<WeightEntry> = arr
+        .iter()
+        .filter_map(|e| {
+            let hotkey = e.get("hotkey").and_then(|v| v.as_str())?;
+            let weight = e.get("weight").and_then(|v| v.as_f64())?;
+            Some(WeightEntry {
+                hotkey: short_hotkey(hotkey),
+                weight,
+            })
+        })
+        .collect();
+    sort_by_weight(&mut entries);
+    entries
+}
+
+/// Parse `/get_weights`. The canonical shape is
+/// `{ "epoch": N, "weights": [{ "hotkey", "weight" }] }`; a bare array of
+/// entries and a `{ hotkey: weight }` map are still accepted.
 fn parse_weights(data: &Value) -> Vec<WeightEntry> {
     let body = data.get("body").unwrap_or(data);
 
-    if let Some(obj) = body.as_object() {
-        let mut entries: Vec<WeightEntry> = obj
-            .iter()
-            .filter_map(|(k, v)| {
-                v.as_f64().map(|w| {
-                    let short = if k.len() > 14 {
-                        format!("{}...", &k[..14])
-                    } else {
-                        k.clone()
-                    };
-                    WeightEntry {
-                        hotkey: short,
-                        weight: w,
-                    }
-                })
-            })
-            .collect();
-        entries.sort_by(|a, b| {
-            b.weight
-                .partial_cmp(&a.weight)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
-        return entries;
+    if let Some(arr) = body.get("weights").and_then(|w| w.as_array()) {
+        return parse_weight_array(arr);
     }
 
     if let Some(arr) = body.as_array() {
-        let mut entries: Vec<WeightEntry> = arr
+        return parse_weight_array(arr);
+    }
+
+    if let Some(obj) = body.as_object() {
+        let mut entries: Vec<WeightEntry> = obj
             .iter()
-            .filter_map(|e| {
-                let hotkey = e.get("hotkey").and_then(|v| v.as_str())?;
-                let weight = e.get("weight").and_then(|v| v.as_f64())?;
-                let short = if hotkey.len() > 14 {
-                    format!("{}...", &hotkey[..14])
-                } else {
-                    hotkey.to_string()
-                };
-                Some(WeightEntry {
-                    hotkey: short,
-                    weight,
+            .filter_map(|(k, v)| {
+                v.as_f64().map(|w| WeightEntry {
+                    hotkey: short_hotkey(k),
+                    weight: w,
                 })
             })
             .collect();
-        entries.sort_by(|a, b| {
-            b.weight
-                .partial_cmp(&a.weight)
-                .unwrap_or(std::cmp::Ordering::Equal)
-        });
+        sort_by_weight(&mut entries);
         return entries;
     }
 
     vec![]
 }
 
-fn ui(frame: &mut Frame, entries: &[WeightEntry], scroll: usize, error: &Option<String>) {
+/// Bar scaled against the largest weight, 30 cells at most.
+fn weight_bar(weight: f64, max_weight: f64, glyphs: &theme::Glyphs) -> String {
+    let len = if max_weight > 0.0 {
+        ((weight / max_weight) * 30.0) as usize
+    } else {
+        0
+    };
+    glyphs.bar.repeat(len)
+}
+
+fn ui(
+    frame: &mut Frame,
+    entries: &[WeightEntry],
+    scroll: usize,
+    error: &Option<String>,
+    freshness: Span<'static>,
+) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([Constraint::Min(3), Constraint::Length(3)])
@@ -79,7 +103,7 @@ fn ui(frame: &mut Frame, entries: &[WeightEntry], scroll: usize, error: &Option<
         Cell::from("Weight"),
         Cell::from("Bar"),
     ])
-    .style(Style::default().fg(Color::Yellow).bold());
+    .style(theme::fg(Color::Yellow).bold());
 
     let max_weight = entries.iter().map(|e| e.weight).fold(0.0_f64, f64::max);
 
@@ -88,17 +112,12 @@ fn ui(frame: &mut Frame, entries: &[WeightEntry], scroll: usize, error: &Option<
         .enumerate()
         .skip(scroll)
         .map(|(i, e)| {
-            let bar_len = if max_weight > 0.0 {
-                ((e.weight / max_weight) * 30.0) as usize
-            } else {
-                0
-            };
-            let bar = "█".repeat(bar_len);
+            let bar = weight_bar(e.weight, max_weight, theme::glyphs());
             Row::new(vec![
                 Cell::from((i + 1).to_string()),
                 Cell::from(e.hotkey.clone()),
                 Cell::from(format!("{:.6}", e.weight)),
-                Cell::from(Span::styled(bar, Style::default().fg(Color::Green))),
+                Cell::from(Span::styled(bar, theme::fg(Color::Green))),
             ])
         })
         .collect();
@@ -111,23 +130,33 @@ fn ui(frame: &mut Frame, entries: &[WeightEntry], scroll: usize, error: &Option<
     ];
 
     let title = if let Some(ref err) = error {
-        format!(" Weights — ERROR: {} ", err)
+        format!(" Weights {} ERROR: {} ", theme::glyphs().dash, err)
     } else {
-        format!(" Weights — {} miners ", entries.len())
+        format!(
+            " Weights {} {} miners ",
+            theme::glyphs().dash,
+            entries.len()
+        )
     };
 
     let table = Table::new(rows, widths).header(header).block(
-        Block::default()
-            .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Cyan))
+        theme::block()
+            .border_style(theme::fg(Color::Cyan))
             .title(title),
     );
 
     frame.render_widget(table, chunks[0]);
 
-    let help = Paragraph::new(" ↑/↓ scroll  |  q/Esc quit  |  auto-refresh 5s")
-        .style(Style::default().fg(Color::DarkGray))
-        .block(Block::default().borders(Borders::ALL));
+    let help = Paragraph::new(Line::from(vec![
+        Span::raw(format!(
+            " {} scroll  |  q/Esc quit  |  {}  |  ",
+            theme::glyphs().scroll,
+            super::refresh_label()
+        )),
+        freshness,
+    ]))
+    .style(theme::fg(Color::DarkGray))
+    .block(theme::block());
     frame.render_widget(help, chunks[1]);
 }
 
@@ -136,21 +165,22 @@ pub async fn run(rpc_url: &str) -> Result<()> {
     let mut entries: Vec<WeightEntry> = vec![];
     let mut scroll: usize = 0;
     let mut error: Option<String> = None;
-    let mut last_fetch = Instant::now() - Duration::from_secs(10);
+    let mut refresh = super::RefreshTimer::new();
 
     loop {
-        if last_fetch.elapsed() >= Duration::from_secs(5) {
+        if refresh.due() {
             match rpc_call(rpc_url, "GET", "/get_weights", None).await {
                 Ok(data) => {
                     entries = parse_weights(&data);
                     error = None;
+                    refresh.record_success();
                 }
                 Err(e) => error = Some(e.to_string()),
             }
-            last_fetch = Instant::now();
         }
 
-        terminal.draw(|f| ui(f, &entries, scroll, &error))?;
+        let freshness = refresh.freshness();
+        terminal.draw(|f| ui(f, &entries, scroll, &error, freshness))?;
 
         if event::poll(Duration::from_millis(100))? {
             if let Event::Key(key) = event::read()? {
@@ -175,3 +205,48 @@ pub async fn run(rpc_url: &str) -> Result<()> {
     super::restore_terminal(&mut terminal)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_canonical_weights() {
+        let data = serde_json::json!({
+            "body": {
+                "epoch": 42,
+                "emission_scale": 0.5,
+                "weights": [
+                    { "hotkey": "5Alice", "weight": 0.25 },
+                    { "hotkey": "5BobBobBobBobBobBob", "weight": 0.75 }
+                ]
+            }
+        });
+
+        let entries = parse_weights(&data);
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].hotkey, "5BobBobBobBobB...");
+        assert_eq!(entries[0].weight, 0.75);
+        assert_eq!(entries[1].hotkey, "5Alice");
+    }
+
+    #[test]
+    fn test_parse_legacy_weight_shapes() {
+        let array = serde_json::json!([{ "hotkey": "5Alice", "weight": 1.0 }]);
+        let map = serde_json::json!({ "5Alice": 1.0 });
+
+        assert_eq!(parse_weights(&array)[0].hotkey, "5Alice");
+        assert_eq!(parse_weights(&map)[0].weight, 1.0);
+    }
+
+    #[test]
+    fn test_weight_bar() {
+        assert_eq!(weight_bar(0.5, 1.0, &theme::UNICODE).chars().count(), 15);
+        assert_eq!(weight_bar(1.0, 0.0, &theme::UNICODE), "");
+
+        let ascii = weight_bar(1.0, 1.0, &theme::ASCII);
+        assert_eq!(ascii.len(), 30);
+        assert!(ascii.is_ascii());
+    }
+}
@@ -0,0 +1,61 @@
+use anyhow::{anyhow, Result};
+use console::style;
+
+use crate::rpc::rpc_call;
+use crate::theme;
+
+// The challenge's own version module. The WASM crate cannot be linked
+// here, so the file is compiled in directly and both sides parse versions
+// with the same code.
+#[path = "../../../src/version.rs"]
+mod api;
+
+pub use api::is_compatible;
+
+/// Challenge API version this CLI was built against.
+pub const SUPPORTED_API_VERSION: &str = api::VERSION;
+
+pub async fn fetch_server_version(rpc_url: &str) -> Result<String> {
+    let result = rpc_call(rpc_url, "GET", "/version", None).await?;
+    let body = result.get("body").unwrap_or(&result);
+    body.get("version")
+        .and_then(|v| v.as_str())
+        .map(str::to_string)
+        .ok_or_else(|| anyhow!("server did not report a version"))
+}
+
+/// Warn on stderr if the server's API version is incompatible with this CLI.
+/// Never fails: an unreachable or older server only produces a note.
+pub async fn check(rpc_url: &str) {
+    match fetch_server_version(rpc_url).await {
+        Ok(server) if is_compatible(SUPPORTED_API_VERSION, &server) => {}
+        Ok(server) => eprintln!(
+            "{} server API version {} is not compatible with this CLI (built for {}). \
+             Some commands may fail; upgrade bounty-cli.",
+            theme::bad("WARNING:").bold(),
+            server,
+            SUPPORTED_API_VERSION
+        ),
+        Err(e) => eprintln!(
+            "{} could not check server version: {}",
+            style("Note:").dim(),
+            e
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_compatibility() {
+        assert!(is_compatible(SUPPORTED_API_VERSION, "3.0.0"));
+        assert!(is_compatible("3.0.0", "3.5.3-rc.1"));
+        assert!(!is_compatible("3.0.0", "2.0.0"));
+        assert!(!is_compatible("0.1.0", "0.2.0"));
+        assert!(!is_compatible("3.0.0", ""));
+        assert!(!is_compatible("3.0.0.1", "3.0.0"));
+        assert!(!is_compatible("3.0.x", "3.0.0"));
+    }
+}
@@ -1,18 +1,71 @@
 use anyhow::Result;
+use bip39::{Language, Mnemonic};
 use console::style;
 use dialoguer::{Input, Password};
-use sp_core::{crypto::Pair as PairTrait, sr25519::Pair};
-use bip39::{Language, Mnemonic};
 
+use crate::github::parse_issue_ref;
+use crate::keys::Keypair;
 use crate::rpc::rpc_call_auth;
+use crate::theme;
+
+/// Parse a list of issue references into a canonical batch of URLs: sorted by
+/// `(owner, repo, number)` with duplicates removed, so the signed claim body
+/// is identical regardless of input order.
+fn canonical_issue_urls(refs: &[String]) -> Result<Vec<(u32, String)>> {
+    let mut parsed = Vec::with_capacity(refs.len());
+    for issue_ref in refs {
+        parsed.push(parse_issue_ref(issue_ref)?);
+    }
+    parsed.sort();
+    parsed.dedup();
+
+    Ok(parsed
+        .into_iter()
+        .map(|(owner, repo, number)| {
+            let url = format!("https://github.com/{}/{}/issues/{}", owner, repo, number);
+            (number, url)
+        })
+        .collect())
+}
+
+fn prompt_issue_urls() -> Result<Vec<String>> {
+    let first: String = Input::new()
+        .with_prompt("GitHub issue(s): URL, owner/repo#N or #N, comma-separated")
+        .interact_text()?;
+
+    let mut urls: Vec<String> = first
+        .split(',')
+        .map(|u| u.trim().to_string())
+        .filter(|u| !u.is_empty())
+        .collect();
+
+    loop {
+        let next: String = Input::new()
+            .with_prompt("Another issue (leave empty to finish)")
+            .allow_empty(true)
+            .interact_text()?;
+        let next = next.trim();
+        if next.is_empty() {
+            break;
+        }
+        urls.extend(
+            next.split(',')
+                .map(|u| u.trim().to_string())
+                .filter(|u| !u.is_empty()),
+        );
+    }
+
+    Ok(urls)
+}
 
 pub async fn run(rpc_url: &str) -> Result<()> {
     println!("\n{}", style("Claim Bounty").cyan().bold());
-    println!("{}\n", style("─".repeat(40)).dim());
+    println!("{}\n", style(theme::rule(40)).dim());
 
-    let issue_url: String = Input::new()
-        .with_prompt("GitHub issue URL")
-        .interact_text()?;
+    let issues = canonical_issue_urls(&prompt_issue_urls()?)?;
+    if issues.is_empty() {
+        anyhow::bail!("No issue URLs given");
+    }
 
     let mnemonic: String = Password::new()
         .with_prompt("Enter your 12 or 24-word mnemonic (hidden)")
@@ -27,8 +80,8 @@ pub async fn run(rpc_url: &str) -> Result<()> {
         );
     }
 
-    let pair = match Pair::from_phrase(mnemonic, None) {
-        Ok((pair, _seed)) => pair,
+    let pair = match Keypair::from_phrase(mnemonic) {
+        Ok(pair) => pair,
         Err(e) => {
             let error_detail = match Mnemonic::parse_in(Language::English, mnemonic) {
                 Ok(_) => format!("{}", e),
@@ -61,17 +114,16 @@ pub async fn run(rpc_url: &str) -> Result<()> {
         }
     };
 
-    let hotkey_ss58 = sp_core::crypto::Ss58Codec::to_ss58check(&pair.public());
+    let hotkey_ss58 = pair.to_ss58();
 
-    println!(
-        "  {} {}",
-        style("Hotkey:").dim(),
-        style(&hotkey_ss58).green()
-    );
-    println!("  {} {}", style("Issue:").dim(), style(&issue_url).yellow());
+    println!("  {} {}", style("Hotkey:").dim(), theme::good(&hotkey_ss58));
+    for (_, url) in &issues {
+        println!("  {} {}", style("Issue:").dim(), style(url).yellow());
+    }
 
+    let issue_urls: Vec<&str> = issues.iter().map(|(_, url)| url.as_str()).collect();
     let body = serde_json::json!({
-        "issue_url": issue_url,
+        "issue_urls": issue_urls,
     });
 
     println!("{}", style("Submitting authenticated claim...").dim());
@@ -82,34 +134,85 @@ pub async fn run(rpc_url: &str) -> Result<()> {
     let claimed = response_body
         .get("claimed")
         .and_then(|v| v.as_array())
-        .map(|a| a.len())
-        .unwrap_or(0);
+        .cloned()
+        .unwrap_or_default();
     let rejected = response_body
         .get("rejected")
         .and_then(|v| v.as_array())
-        .map(|a| a.len())
-        .unwrap_or(0);
+        .cloned()
+        .unwrap_or_default();
 
-    if claimed > 0 {
-        println!(
-            "\n{}",
-            style("Claim submitted successfully!").green().bold()
-        );
-        println!("  Claimed: {}", claimed);
-        if rejected > 0 {
-            println!("  Rejected: {}", rejected);
-        }
-    } else if let Some(error) = response_body.get("error").and_then(|v| v.as_str()) {
-        println!("\n{}", style("Claim failed.").red().bold());
+    if claimed.is_empty() && rejected.is_empty() {
+        println!("\n{}", theme::bad("Claim failed.").bold());
+        let error = response_body
+            .get("error")
+            .and_then(|v| v.as_str())
+            .unwrap_or("Unknown error");
         println!("  Error: {}", error);
+        println!();
+        return Ok(());
+    }
+
+    if claimed.is_empty() {
+        println!("\n{}", theme::bad("Claim failed.").bold());
     } else {
-        println!("\n{}", style("Claim failed.").red().bold());
-        if rejected > 0 {
-            println!("  Rejected: {}", rejected);
-        }
-        println!("  Check that the issue has both 'ide' and 'valid' labels.");
+        println!("\n{}", theme::good("Claim submitted successfully!").bold());
+    }
+
+    for issue in &claimed {
+        let number = issue
+            .get("issue_number")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0);
+        println!("  {} #{}", theme::good("claimed "), number);
+    }
+    for issue in &rejected {
+        let number = issue
+            .get("issue_number")
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0);
+        let reason = issue
+            .get("reason")
+            .and_then(|v| v.as_str())
+            .unwrap_or("unknown reason");
+        println!(
+            "  {} #{} {}",
+            theme::bad("rejected"),
+            number,
+            style(reason).dim()
+        );
+    }
+
+    // Servers that predate the summary send no message.
+    if let Some(message) = response_body
+        .get("message")
+        .and_then(|v| v.as_str())
+        .filter(|m| !m.is_empty())
+    {
+        println!("  {}", message);
+    }
+    if claimed.is_empty() {
+        println!("  Check that the issues have both 'ide' and 'valid' labels.");
     }
 
     println!();
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_issue_urls_sorted_and_deduped() {
+        let urls = vec![
+            "https://github.com/o/r/issues/10/".to_string(),
+            "https://github.com/o/r/issues/2".to_string(),
+            "o/r#10".to_string(),
+        ];
+        let issues = canonical_issue_urls(&urls).unwrap();
+        let numbers: Vec<u32> = issues.iter().map(|(n, _)| *n).collect();
+        assert_eq!(numbers, vec![2, 10]);
+        assert_eq!(issues[1].1, "https://github.com/o/r/issues/10");
+    }
+}
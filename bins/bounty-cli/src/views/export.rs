@@ -0,0 +1,204 @@
+use anyhow::{Context, Result};
+use console::style;
+use serde_json::Value;
+use std::path::Path;
+
+use crate::error::{CliError, ExitCode};
+use crate::rpc::rpc_call_auth;
+use crate::views::register::{derive_keypair, read_mnemonic};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExportFormat {
+    Json,
+    Csv,
+}
+
+impl ExportFormat {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "json" => Some(Self::Json),
+            "csv" => Some(Self::Csv),
+            _ => None,
+        }
+    }
+}
+
+const CSV_HEADER: &str = "hotkey,github_username,registered_epoch,valid_count,invalid_count,\
+duplicate_count,star_count,malicious_count,is_penalized,is_banned,weight,bounties";
+
+pub(crate) fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn text(value: Option<&Value>) -> String {
+    match value {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Null) | None => String::new(),
+        Some(v) => v.to_string(),
+    }
+}
+
+/// One CSV row per miner; a miner's bounties are joined as
+/// `owner/repo#number` separated by `;`.
+pub fn to_csv(bundle: &Value) -> String {
+    let empty = Vec::new();
+    let miners = bundle
+        .get("miners")
+        .and_then(|v| v.as_array())
+        .unwrap_or(&empty);
+    let bounties = bundle
+        .get("bounties")
+        .and_then(|v| v.as_array())
+        .unwrap_or(&empty);
+
+    let mut out = String::from(CSV_HEADER);
+    out.push('\n');
+    for miner in miners {
+        let hotkey = text(miner.get("hotkey"));
+        let claimed: Vec<String> = bounties
+            .iter()
+            .filter(|b| {
+                b.get("claimed_by_hotkey").and_then(|v| v.as_str()) == Some(hotkey.as_str())
+            })
+            .map(|b| {
+                format!(
+                    "{}/{}#{}",
+                    text(b.get("repo_owner")),
+                    text(b.get("repo_name")),
+                    text(b.get("issue_number"))
+                )
+            })
+            .collect();
+        let balance = miner.get("balance");
+        let balance_field = |name: &str| text(balance.and_then(|b| b.get(name)));
+
+        let fields = [
+            hotkey.clone(),
+            text(miner.get("github_username")),
+            text(miner.get("registered_epoch")),
+            balance_field("valid_count"),
+            balance_field("invalid_count"),
+            balance_field("duplicate_count"),
+            balance_field("star_count"),
+            balance_field("malicious_count"),
+            balance_field("is_penalized"),
+            text(miner.get("is_banned")),
+            text(miner.get("weight")),
+            claimed.join(";"),
+        ];
+        let row: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Fetch `/sudo/export` signed with the sudo owner's key and write it as
+/// JSON or CSV to `output` (stdout when `None`).
+pub async fn run(
+    rpc_url: &str,
+    format: ExportFormat,
+    output: Option<&Path>,
+    mnemonic_file: &Path,
+) -> Result<()> {
+    let mnemonic = read_mnemonic(mnemonic_file)?;
+    let pair = derive_keypair(&mnemonic)?;
+
+    let result = rpc_call_auth(
+        rpc_url,
+        "POST",
+        "/sudo/export",
+        Some(serde_json::json!({})),
+        &pair,
+    )
+    .await?;
+    let body = result.get("body").cloned().unwrap_or(result);
+    if let Some(error) = body.get("error") {
+        let message = body
+            .get("message")
+            .and_then(|v| v.as_str())
+            .unwrap_or_else(|| error.as_str().unwrap_or("Unknown error"));
+        return Err(
+            CliError::new(ExitCode::RpcError, format!("Export failed: {}", message)).into(),
+        );
+    }
+
+    let data = match format {
+        ExportFormat::Json => serde_json::to_string_pretty(&body)?,
+        ExportFormat::Csv => to_csv(&body),
+    };
+    match output {
+        Some(path) => {
+            std::fs::write(path, data)
+                .with_context(|| format!("Failed to write '{}'", path.display()))?;
+            let count = |key: &str| body.get(key).and_then(|v| v.as_array()).map_or(0, Vec::len);
+            eprintln!(
+                "{} {} miners, {} bounties to {}",
+                style("Exported").dim(),
+                count("miners"),
+                count("bounties"),
+                path.display()
+            );
+        }
+        None => println!("{}", data.trim_end()),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_to_csv() {
+        let bundle = json!({
+            "exported_at_ms": 0,
+            "miners": [
+                {
+                    "hotkey": "hk-a",
+                    "github_username": "alice",
+                    "registered_epoch": 7,
+                    "balance": {
+                        "valid_count": 2, "invalid_count": 0, "duplicate_count": 1,
+                        "star_count": 3, "is_penalized": false, "malicious_count": 0
+                    },
+                    "is_banned": false,
+                    "weight": 0.75
+                },
+                {
+                    "hotkey": "hk-b",
+                    "github_username": "bob,jr",
+                    "registered_epoch": 8,
+                    "balance": {},
+                    "is_banned": true,
+                    "weight": 0.0
+                }
+            ],
+            "bounties": [
+                { "issue_number": 1, "repo_owner": "o", "repo_name": "r", "claimed_by_hotkey": "hk-a" },
+                { "issue_number": 5, "repo_owner": "o", "repo_name": "r", "claimed_by_hotkey": "hk-a" }
+            ]
+        });
+
+        let csv = to_csv(&bundle);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(
+            lines[1],
+            "hk-a,alice,7,2,0,1,3,0,false,false,0.75,o/r#1;o/r#5"
+        );
+        assert_eq!(lines[2], "hk-b,\"bob,jr\",8,,,,,,,true,0.0,");
+    }
+
+    #[test]
+    fn test_csv_field_quotes() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
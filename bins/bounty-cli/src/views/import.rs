@@ -0,0 +1,104 @@
+use anyhow::Result;
+use console::style;
+use serde_json::Value;
+use std::path::Path;
+
+use crate::error::{CliError, ExitCode};
+use crate::rpc::rpc_call_auth;
+use crate::theme;
+use crate::views::register::{derive_keypair, read_mnemonic};
+
+/// Read a JSON bundle written by `bounty-cli export`.
+fn read_bundle(path: &Path) -> Result<Value> {
+    let raw = std::fs::read_to_string(path).map_err(|e| {
+        CliError::new(
+            ExitCode::InvalidInput,
+            format!("Cannot read export {}: {}", path.display(), e),
+        )
+    })?;
+    let bundle: Value = serde_json::from_str(&raw).map_err(|e| {
+        CliError::new(
+            ExitCode::InvalidInput,
+            format!(
+                "Export {} is not valid JSON (CSV exports cannot be imported): {}",
+                path.display(),
+                e
+            ),
+        )
+    })?;
+    if !bundle.get("miners").is_some_and(Value::is_array) {
+        return Err(CliError::new(
+            ExitCode::InvalidInput,
+            format!("{} is not a bounty-cli export bundle", path.display()),
+        )
+        .into());
+    }
+    Ok(bundle)
+}
+
+/// Send an export bundle to `/sudo/import`, signed with the sudo owner's key.
+pub async fn run(rpc_url: &str, file: &Path, force: bool, mnemonic_file: &Path) -> Result<()> {
+    let bundle = read_bundle(file)?;
+    let mnemonic = read_mnemonic(mnemonic_file)?;
+    let pair = derive_keypair(&mnemonic)?;
+
+    let path = if force {
+        "/sudo/import?force=true"
+    } else {
+        "/sudo/import"
+    };
+    let result = rpc_call_auth(rpc_url, "POST", path, Some(bundle), &pair).await?;
+    let body = result.get("body").cloned().unwrap_or(result);
+    if let Some(error) = body.get("error") {
+        let message = body
+            .get("message")
+            .and_then(|v| v.as_str())
+            .unwrap_or_else(|| error.as_str().unwrap_or("Unknown error"));
+        return Err(
+            CliError::new(ExitCode::RpcError, format!("Import failed: {}", message)).into(),
+        );
+    }
+
+    let count = |key: &str| body.get(key).and_then(|v| v.as_u64()).unwrap_or(0);
+    println!(
+        "{} {} miners, {} bounties",
+        theme::good("Imported").bold(),
+        count("miners_imported"),
+        count("bounties_imported")
+    );
+    println!(
+        "  {} {} miners, {} bounties",
+        style("Skipped:").dim(),
+        count("miners_skipped"),
+        count("bounties_skipped")
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_read_bundle_rejects_csv_and_other_json() {
+        let dir = std::env::temp_dir();
+        let csv = dir.join("bounty-cli-test-import.csv");
+        std::fs::write(&csv, "hotkey,github_username\nhk,alice\n").unwrap();
+        let err = read_bundle(&csv).unwrap_err();
+        assert!(err.to_string().contains("CSV exports cannot be imported"));
+
+        let other = dir.join("bounty-cli-test-import.json");
+        std::fs::write(&other, "[1, 2]").unwrap();
+        assert!(read_bundle(&other).is_err());
+
+        std::fs::write(
+            &other,
+            r#"{"exported_at_ms": 0, "miners": [], "bounties": []}"#,
+        )
+        .unwrap();
+        assert!(read_bundle(&other).is_ok());
+
+        let _ = std::fs::remove_file(csv);
+        let _ = std::fs::remove_file(other);
+    }
+}
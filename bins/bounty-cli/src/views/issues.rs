@@ -3,6 +3,7 @@ use console::style;
 use serde_json::Value;
 
 use crate::rpc::rpc_call;
+use crate::theme;
 
 fn derive_status(issue: &Value) -> &'static str {
     let has_valid = issue
@@ -28,8 +29,25 @@ fn derive_status(issue: &Value) -> &'static str {
     }
 }
 
+/// Pages arrive as `{ items, offset, limit_applied, limit_requested }`; the
+/// server caps `limit`, so say when the requested one was reduced.
+fn clamp_note(body: &Value) -> Option<String> {
+    let applied = body.get("limit_applied")?.as_u64()?;
+    let requested = body.get("limit_requested")?.as_u64()?;
+    (requested > applied).then(|| {
+        format!(
+            "Requested limit {} was reduced to the server maximum of {}.",
+            requested, applied
+        )
+    })
+}
+
 fn print_issues(data: &Value) {
     let body = data.get("body").unwrap_or(data);
+    if let Some(note) = clamp_note(body) {
+        eprintln!("  {}", style(note).yellow());
+    }
+    let body = body.get("items").unwrap_or(body);
     let arr = match body.as_array() {
         Some(a) => a,
         None => {
@@ -51,7 +69,7 @@ fn print_issues(data: &Value) {
         style("Status").yellow(),
         style("Author").yellow(),
     );
-    println!("  {}", style("─".repeat(75)).dim());
+    println!("  {}", style(theme::rule(75)).dim());
 
     for (i, issue) in arr.iter().enumerate() {
         let issue_num = issue
@@ -79,9 +97,9 @@ fn print_issues(data: &Value) {
         let author = issue.get("author").and_then(|v| v.as_str()).unwrap_or("?");
 
         let status_styled = match status {
-            "valid" => style(status).green(),
+            "valid" => theme::good(status),
             "pending" => style(status).yellow(),
-            "invalid" => style(status).red(),
+            "invalid" => theme::bad(status),
             "duplicate" => style(status).magenta(),
             _ => style(status).dim(),
         };
@@ -99,7 +117,7 @@ fn print_issues(data: &Value) {
 
 pub async fn run_all(rpc_url: &str) -> Result<()> {
     println!("\n{}", style("All Issues").cyan().bold());
-    println!("{}\n", style("─".repeat(40)).dim());
+    println!("{}\n", style(theme::rule(40)).dim());
 
     let result = rpc_call(rpc_url, "GET", "/issues", None).await?;
     print_issues(&result);
@@ -107,12 +125,60 @@ pub async fn run_all(rpc_url: &str) -> Result<()> {
     Ok(())
 }
 
+/// Non-interactive listing with the server-side `state`/`limit`/`offset`
+/// filters of `GET /issues`.
+pub async fn run_filtered(
+    rpc_url: &str,
+    state: Option<&str>,
+    limit: Option<u64>,
+    offset: Option<u64>,
+) -> Result<()> {
+    let mut query: Vec<String> = Vec::new();
+    if let Some(state) = state {
+        query.push(format!("state={}", state));
+    }
+    if let Some(limit) = limit {
+        query.push(format!("limit={}", limit));
+    }
+    if let Some(offset) = offset {
+        query.push(format!("offset={}", offset));
+    }
+    let path = if query.is_empty() {
+        "/issues".to_string()
+    } else {
+        format!("/issues?{}", query.join("&"))
+    };
+
+    let result = rpc_call(rpc_url, "GET", &path, None).await?;
+    print_issues(&result);
+    Ok(())
+}
+
 pub async fn run_pending(rpc_url: &str) -> Result<()> {
     println!("\n{}", style("Pending Issues").cyan().bold());
-    println!("{}\n", style("─".repeat(40)).dim());
+    println!("{}\n", style(theme::rule(40)).dim());
 
     let result = rpc_call(rpc_url, "GET", "/issues/pending", None).await?;
     print_issues(&result);
     println!();
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_clamp_note() {
+        let clamped = json!({ "items": [], "limit_applied": 500, "limit_requested": 1000000 });
+        let note = clamp_note(&clamped).unwrap();
+        assert!(note.contains("1000000") && note.contains("500"), "{}", note);
+        assert_eq!(clamp_note(&json!([])), None);
+
+        let unclamped = json!({ "items": [], "limit_applied": 20, "limit_requested": 20 });
+        assert_eq!(clamp_note(&unclamped), None);
+        let default = json!({ "items": [], "limit_applied": 500, "limit_requested": null });
+        assert_eq!(clamp_note(&default), None);
+    }
+}
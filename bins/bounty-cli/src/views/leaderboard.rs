@@ -0,0 +1,806 @@
+use anyhow::Result;
+use console::{style, Term};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use crate::atomic_file;
+use crate::error::{is_dry_run, CliError, ExitCode};
+use crate::pager;
+use crate::rpc::rpc_call;
+use crate::theme;
+use crate::timestamps;
+use crate::views::export::csv_field;
+use crate::views::register::{derive_keypair, read_mnemonic};
+use crate::views::snapshot;
+
+/// A current leaderboard row annotated against a snapshot.
+#[derive(Debug, PartialEq)]
+pub struct RowDiff {
+    pub rank: u64,
+    pub hotkey: String,
+    pub github: String,
+    pub net_points: f64,
+    /// Places gained since the snapshot (negative if dropped); `None` for
+    /// entries that were not in the snapshot.
+    pub rank_delta: Option<i64>,
+    pub points_delta: Option<f64>,
+}
+
+/// A snapshot entry that is no longer on the leaderboard.
+#[derive(Debug, PartialEq)]
+pub struct DroppedEntry {
+    pub rank: u64,
+    pub hotkey: String,
+    pub github: String,
+    pub net_points: f64,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct LeaderboardDiff {
+    pub rows: Vec<RowDiff>,
+    pub dropped: Vec<DroppedEntry>,
+}
+
+struct Entry<'a> {
+    rank: u64,
+    hotkey: &'a str,
+    github: &'a str,
+    net_points: f64,
+}
+
+/// Leaderboard rows from a `/leaderboard` response or a saved snapshot,
+/// inside the RPC envelope's `body` field if there is one. Rows are the
+/// page's `items`; older servers and snapshots hold the bare array.
+pub fn rows(data: &Value) -> &[Value] {
+    let body = data.get("body").unwrap_or(data);
+    let body = body.get("items").unwrap_or(body);
+    body.as_array().map(Vec::as_slice).unwrap_or_default()
+}
+
+fn entries(data: &Value) -> Vec<Entry<'_>> {
+    rows(data)
+        .iter()
+        .filter_map(|e| {
+            Some(Entry {
+                rank: e.get("rank").and_then(|v| v.as_u64()).unwrap_or(0),
+                hotkey: e.get("hotkey").and_then(|v| v.as_str())?,
+                github: e
+                    .get("github_username")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("?"),
+                net_points: e.get("net_points").and_then(|v| v.as_f64()).unwrap_or(0.0),
+            })
+        })
+        .collect()
+}
+
+/// Match `current` against `snapshot` by hotkey.
+pub fn compute_diff(snapshot: &Value, current: &Value) -> LeaderboardDiff {
+    let previous = entries(snapshot);
+    let by_hotkey: HashMap<&str, &Entry> = previous.iter().map(|e| (e.hotkey, e)).collect();
+    let current = entries(current);
+
+    let rows = current
+        .iter()
+        .map(|e| {
+            let before = by_hotkey.get(e.hotkey);
+            RowDiff {
+                rank: e.rank,
+                hotkey: e.hotkey.to_string(),
+                github: e.github.to_string(),
+                net_points: e.net_points,
+                rank_delta: before.map(|b| b.rank as i64 - e.rank as i64),
+                points_delta: before.map(|b| e.net_points - b.net_points),
+            }
+        })
+        .collect();
+
+    let still_present: Vec<&str> = current.iter().map(|e| e.hotkey).collect();
+    let dropped = previous
+        .iter()
+        .filter(|e| !still_present.contains(&e.hotkey))
+        .map(|e| DroppedEntry {
+            rank: e.rank,
+            hotkey: e.hotkey.to_string(),
+            github: e.github.to_string(),
+            net_points: e.net_points,
+        })
+        .collect();
+
+    LeaderboardDiff { rows, dropped }
+}
+
+/// Leaderboard rows of one GitHub user.
+#[derive(Debug, PartialEq)]
+pub struct GithubGroup {
+    pub github: String,
+    pub hotkeys: Vec<String>,
+    pub valid: u64,
+    pub invalid: u64,
+    /// Stars belong to the GitHub account, so they count once per group.
+    pub stars: u64,
+    /// Member net points, with the star bonus counted once.
+    pub net_points: f64,
+    /// Sum of the members' weight shares.
+    pub score: f64,
+    /// Members reported different star counts for the same account, which
+    /// points at a stale or mislinked registration.
+    pub inconsistent: bool,
+}
+
+/// Combine leaderboard rows that share a GitHub username (case-insensitive),
+/// ordered by combined net points.
+pub fn group_by_github(data: &Value) -> Vec<GithubGroup> {
+    let rows = rows(data);
+    let mut groups: Vec<(GithubGroup, f64)> = Vec::new();
+
+    for row in rows {
+        let hotkey = match row.get("hotkey").and_then(|v| v.as_str()) {
+            Some(h) => h,
+            None => continue,
+        };
+        let github = row
+            .get("github_username")
+            .and_then(|v| v.as_str())
+            .unwrap_or("?");
+        let count = |key: &str| row.get(key).and_then(|v| v.as_u64()).unwrap_or(0);
+        let number = |key: &str| row.get(key).and_then(|v| v.as_f64()).unwrap_or(0.0);
+        let stars = count("star_count");
+        let star_bonus = number("star_bonus");
+
+        let index = match groups
+            .iter()
+            .position(|(g, _)| g.github.eq_ignore_ascii_case(github))
+        {
+            Some(i) => i,
+            None => {
+                groups.push((
+                    GithubGroup {
+                        github: github.to_string(),
+                        hotkeys: Vec::new(),
+                        valid: 0,
+                        invalid: 0,
+                        stars,
+                        net_points: 0.0,
+                        score: 0.0,
+                        inconsistent: false,
+                    },
+                    star_bonus,
+                ));
+                groups.len() - 1
+            }
+        };
+        let (group, max_star_bonus) = &mut groups[index];
+        if !group.hotkeys.is_empty() && stars != group.stars {
+            group.inconsistent = true;
+        }
+        group.hotkeys.push(hotkey.to_string());
+        group.valid += count("valid_issues");
+        group.invalid += count("invalid_issues");
+        group.stars = group.stars.max(stars);
+        // Drop each member's star bonus here and add the largest back below.
+        group.net_points += number("net_points") - star_bonus;
+        group.score += number("score");
+        *max_star_bonus = max_star_bonus.max(star_bonus);
+    }
+
+    let mut groups: Vec<GithubGroup> = groups
+        .into_iter()
+        .map(|(mut g, star_bonus)| {
+            g.net_points = (g.net_points + star_bonus).max(0.0);
+            g
+        })
+        .collect();
+    groups.sort_by(|a, b| {
+        b.net_points
+            .partial_cmp(&a.net_points)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.github.cmp(&b.github))
+    });
+    groups
+}
+
+/// Print the leaderboard with one row per GitHub user, through the pager
+/// unless `no_pager`.
+pub async fn run_grouped(
+    rpc_url: &str,
+    sort: Option<&str>,
+    json: bool,
+    no_pager: bool,
+) -> Result<()> {
+    let groups = group_by_github(&fetch(rpc_url, sort).await?);
+    if json {
+        let rows: Vec<Value> = groups
+            .iter()
+            .map(|g| {
+                serde_json::json!({
+                    "github_username": g.github,
+                    "hotkeys": g.hotkeys,
+                    "valid_issues": g.valid,
+                    "invalid_issues": g.invalid,
+                    "star_count": g.stars,
+                    "net_points": g.net_points,
+                    "score": g.score,
+                    "inconsistent": g.inconsistent,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&rows)?);
+        return Ok(());
+    }
+
+    let mut out = vec![
+        format!(
+            "  {:<6} {:<18} {:>6} {:>8} {:>6} {:>10} {:>8}  {}",
+            style("Rank").yellow(),
+            style("GitHub").yellow(),
+            style("Valid").yellow(),
+            style("Invalid").yellow(),
+            style("Stars").yellow(),
+            style("Net Pts").yellow(),
+            style("Score").yellow(),
+            style("Hotkeys").yellow(),
+        ),
+        format!("  {}", style(theme::rule(90)).dim()),
+    ];
+    for (i, group) in groups.iter().enumerate() {
+        let hotkeys: Vec<String> = group.hotkeys.iter().map(|h| short(h)).collect();
+        let github = if group.inconsistent {
+            theme::bad(format!("{}*", group.github))
+        } else {
+            style(group.github.clone())
+        };
+        out.push(format!(
+            "  {:<6} {:<18} {:>6} {:>8} {:>6} {:>10.2} {:>8.4}  {}",
+            i + 1,
+            github,
+            group.valid,
+            group.invalid,
+            group.stars,
+            group.net_points,
+            group.score,
+            hotkeys.join(", "),
+        ));
+    }
+    if groups.iter().any(|g| g.inconsistent) {
+        out.push(String::new());
+        out.push(format!(
+            "  {}",
+            style("* hotkeys of this user report different star counts; check their registrations")
+                .dim()
+        ));
+    }
+    pager::print(&out, no_pager)?;
+    Ok(())
+}
+
+fn format_rank_delta(delta: Option<i64>, glyphs: &theme::Glyphs) -> String {
+    match delta {
+        None => "new".to_string(),
+        Some(0) => glyphs.dash.to_string(),
+        Some(d) if d > 0 => format!("{}{}", glyphs.up, d),
+        Some(d) => format!("{}{}", glyphs.down, -d),
+    }
+}
+
+fn short(hotkey: &str) -> String {
+    if hotkey.len() > 14 {
+        format!("{}...", &hotkey[..14])
+    } else {
+        hotkey.to_string()
+    }
+}
+
+fn leaderboard_path(sort: Option<&str>) -> String {
+    match sort {
+        Some(field) => format!("/leaderboard?sort={}", field),
+        None => "/leaderboard".to_string(),
+    }
+}
+
+async fn fetch(rpc_url: &str, sort: Option<&str>) -> Result<Value> {
+    let result = rpc_call(rpc_url, "GET", &leaderboard_path(sort), None).await?;
+    Ok(result.get("body").cloned().unwrap_or(result))
+}
+
+/// Print the leaderboard as a JSON array, suitable as a `--diff` snapshot.
+/// With `sign_with`, sign it with the mnemonic in that file and print the
+/// envelope instead, for `verify-snapshot`.
+pub async fn run_json(rpc_url: &str, sort: Option<&str>, sign_with: Option<&Path>) -> Result<()> {
+    let keypair = match sign_with {
+        Some(path) => Some(derive_keypair(&read_mnemonic(path)?)?),
+        None => None,
+    };
+    let body = fetch(rpc_url, sort).await?;
+    let body = match keypair {
+        Some(keypair) => {
+            let now_ms = chrono::Utc::now().timestamp_millis();
+            snapshot::sign(&keypair, "leaderboard", now_ms, body)
+        }
+        None => body,
+    };
+    println!("{}", serde_json::to_string_pretty(&body)?);
+    Ok(())
+}
+
+/// Columns of `--csv-out`, in order.
+const CSV_COLUMNS: &[&str] = &[
+    "rank",
+    "hotkey",
+    "github_username",
+    "net_points",
+    "score",
+    "valid_issues",
+    "invalid_issues",
+    "duplicate_issues",
+    "malicious_issues",
+    "star_count",
+    "is_penalized",
+    "suspicious",
+];
+
+fn rfc3339(ms: i64) -> String {
+    chrono::DateTime::from_timestamp_millis(ms)
+        .map(|t| t.to_rfc3339_opts(chrono::SecondsFormat::Millis, true))
+        .unwrap_or_default()
+}
+
+/// The leaderboard as CSV, after a `# fetched_at: <RFC 3339>` comment line.
+fn to_csv(data: &Value, fetched_at_ms: i64) -> String {
+    let rows = rows(data);
+
+    let mut out = format!(
+        "# fetched_at: {}\n{}\n",
+        rfc3339(fetched_at_ms),
+        CSV_COLUMNS.join(",")
+    );
+    for row in rows {
+        let cells: Vec<String> = CSV_COLUMNS
+            .iter()
+            .map(|column| match row.get(*column) {
+                Some(Value::String(s)) => csv_field(s),
+                Some(Value::Null) | None => String::new(),
+                Some(v) => csv_field(&v.to_string()),
+            })
+            .collect();
+        out.push_str(&cells.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// The leaderboard for `--json-out`: the entries under `body`, so the file
+/// still works as a `--diff` snapshot, next to when they were fetched.
+fn to_saved_json(data: &Value, fetched_at_ms: i64) -> Value {
+    serde_json::json!({
+        "fetched_at": rfc3339(fetched_at_ms),
+        "fetched_at_ms": fetched_at_ms,
+        "body": data.get("body").unwrap_or(data),
+    })
+}
+
+fn save(path: &Path, contents: &str) -> Result<()> {
+    atomic_file::write(path, contents.as_bytes()).map_err(|e| {
+        CliError::new(
+            ExitCode::Failure,
+            format!("Failed to write '{}': {}", path.display(), e),
+        )
+    })?;
+    eprintln!("{}", style(format!("Wrote {}", path.display())).dim());
+    Ok(())
+}
+
+/// Fetch the leaderboard once and write it to `csv_out` and/or `json_out`,
+/// each replaced atomically so a concurrent reader never sees a partial
+/// file. Meant for scheduled snapshots.
+pub async fn run_save(
+    rpc_url: &str,
+    sort: Option<&str>,
+    csv_out: Option<&Path>,
+    json_out: Option<&Path>,
+) -> Result<()> {
+    let data = fetch(rpc_url, sort).await?;
+    let fetched_at_ms = chrono::Utc::now().timestamp_millis();
+
+    if let Some(path) = csv_out {
+        save(path, &to_csv(&data, fetched_at_ms))?;
+    }
+    if let Some(path) = json_out {
+        let json = serde_json::to_string_pretty(&to_saved_json(&data, fetched_at_ms))?;
+        save(path, &json)?;
+    }
+    Ok(())
+}
+
+/// The plain leaderboard table reprinted by `--follow`.
+fn table_lines(data: &Value) -> Vec<String> {
+    let rows = rows(data);
+    let mut out = vec![
+        format!(
+            "  {:<6} {:<18} {:<18} {:>6} {:>8} {:>6} {:>10} {:>8}",
+            style("Rank").yellow(),
+            style("Hotkey").yellow(),
+            style("GitHub").yellow(),
+            style("Valid").yellow(),
+            style("Invalid").yellow(),
+            style("Stars").yellow(),
+            style("Net Pts").yellow(),
+            style("Score").yellow(),
+        ),
+        format!("  {}", style(theme::rule(88)).dim()),
+    ];
+    for row in rows {
+        let text = |key: &str| row.get(key).and_then(|v| v.as_str()).unwrap_or("?");
+        let count = |key: &str| row.get(key).and_then(|v| v.as_u64()).unwrap_or(0);
+        let number = |key: &str| row.get(key).and_then(|v| v.as_f64()).unwrap_or(0.0);
+        out.push(format!(
+            "  {:<6} {:<18} {:<18} {:>6} {:>8} {:>6} {:>10.2} {:>8.4}",
+            count("rank"),
+            short(text("hotkey")),
+            text("github_username"),
+            count("valid_issues"),
+            count("invalid_issues"),
+            count("star_count"),
+            number("net_points"),
+            number("score"),
+        ));
+    }
+    if rows.is_empty() {
+        out.push(format!("  {}", style("No miners yet").dim()));
+    }
+    out
+}
+
+/// Time from `elapsed` (since following started) to the next tick, so ticks
+/// keep a fixed `interval` cadence however long each fetch takes. A fetch
+/// that overruns a tick skips it rather than firing twice.
+pub fn until_next_tick(elapsed: Duration, interval: Duration) -> Duration {
+    let interval_ms = interval.as_millis().max(1);
+    let into_tick = elapsed.as_millis() % interval_ms;
+    Duration::from_millis((interval_ms - into_tick) as u64)
+}
+
+/// Reprint the leaderboard every `interval_secs` until Ctrl-C, like `watch`:
+/// a plain table redrawn on a cleared screen, without the TUI's alternate
+/// screen, so the last table stays on screen to copy. With `json`, print
+/// one compact JSON array per tick (NDJSON).
+pub async fn run_follow(
+    rpc_url: &str,
+    sort: Option<&str>,
+    interval_secs: u64,
+    json: bool,
+) -> Result<()> {
+    let term = Term::stdout();
+    let redraw = !json && term.is_term();
+    let interval = Duration::from_secs(interval_secs);
+    let started = Instant::now();
+
+    loop {
+        let lines = match fetch(rpc_url, sort).await {
+            Ok(body) if json => vec![body.to_string()],
+            Ok(body) => {
+                let mut lines = vec![
+                    format!(
+                        "  {} {}  {}",
+                        style("Updated").dim(),
+                        chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC"),
+                        style(format!("every {}s, Ctrl-C to stop", interval_secs)).dim()
+                    ),
+                    String::new(),
+                ];
+                lines.extend(table_lines(&body));
+                lines
+            }
+            Err(e) if is_dry_run(&e) => return Err(e),
+            Err(e) if json => vec![serde_json::json!({ "error": e.to_string() }).to_string()],
+            Err(e) => vec![format!("Error: {}", e)],
+        };
+        if redraw {
+            term.clear_screen()?;
+        }
+        for line in &lines {
+            println!("{}", line);
+        }
+
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            _ = tokio::time::sleep(until_next_tick(started.elapsed(), interval)) => {}
+        }
+    }
+    Ok(())
+}
+
+/// Print the current leaderboard annotated with changes since `snapshot`,
+/// through the pager unless `no_pager`.
+pub async fn run_diff(
+    rpc_url: &str,
+    sort: Option<&str>,
+    snapshot: &Path,
+    no_pager: bool,
+) -> Result<()> {
+    let raw = std::fs::read_to_string(snapshot).map_err(|e| {
+        CliError::new(
+            ExitCode::InvalidInput,
+            format!("Cannot read snapshot {}: {}", snapshot.display(), e),
+        )
+    })?;
+    let previous: Value = serde_json::from_str(&raw).map_err(|e| {
+        CliError::new(
+            ExitCode::InvalidInput,
+            format!("Snapshot {} is not valid JSON: {}", snapshot.display(), e),
+        )
+    })?;
+    let current = fetch(rpc_url, sort).await?;
+    let diff = compute_diff(&previous, &current);
+
+    let mut out = vec![
+        format!(
+            "  {:<6} {:<7} {:<18} {:<18} {:>10} {:>10}",
+            style("Rank").yellow(),
+            style("Move").yellow(),
+            style("Hotkey").yellow(),
+            style("GitHub").yellow(),
+            style("Net Pts").yellow(),
+            style(format!("{} Pts", theme::glyphs().delta)).yellow(),
+        ),
+        format!("  {}", style(theme::rule(74)).dim()),
+    ];
+
+    for row in &diff.rows {
+        let movement = format_rank_delta(row.rank_delta, theme::glyphs());
+        let movement = match row.rank_delta {
+            None => style(movement).cyan(),
+            Some(d) if d > 0 => theme::good(movement),
+            Some(d) if d < 0 => theme::bad(movement),
+            Some(_) => style(movement).dim(),
+        };
+        let points_delta = match row.points_delta {
+            Some(d) => format!("{:+.2}", d),
+            None => "-".to_string(),
+        };
+        out.push(format!(
+            "  {:<6} {:<7} {:<18} {:<18} {:>10.2} {:>10}",
+            row.rank,
+            movement,
+            short(&row.hotkey),
+            row.github,
+            row.net_points,
+            points_delta,
+        ));
+    }
+
+    if !diff.dropped.is_empty() {
+        out.push(String::new());
+        out.push(format!("  {}", style("Dropped").yellow()));
+        for entry in &diff.dropped {
+            out.push(format!(
+                "  {:<6} {:<7} {:<18} {:<18} {:>10.2}",
+                entry.rank,
+                theme::bad("out"),
+                short(&entry.hotkey),
+                entry.github,
+                entry.net_points,
+            ));
+        }
+    }
+    pager::print(&out, no_pager)?;
+    Ok(())
+}
+
+fn print_movers(title: &str, movers: &[Value]) {
+    println!("\n  {}", style(title).yellow());
+    if movers.is_empty() {
+        println!("  {}", style("None").dim());
+        return;
+    }
+    for mover in movers {
+        let hotkey = mover.get("hotkey").and_then(|v| v.as_str()).unwrap_or("?");
+        let github = mover
+            .get("github_username")
+            .and_then(|v| v.as_str())
+            .unwrap_or("?");
+        let now = mover
+            .get("net_points_now")
+            .and_then(|v| v.as_f64())
+            .unwrap_or(0.0);
+        let change = mover.get("change").and_then(|v| v.as_f64()).unwrap_or(0.0);
+        let change_text = format!("{:+.2}", change);
+        let change_styled = if change >= 0.0 {
+            theme::good(change_text)
+        } else {
+            theme::bad(change_text)
+        };
+        println!(
+            "  {:<18} {:<18} {:>10.2} {:>10}",
+            short(hotkey),
+            github,
+            now,
+            change_styled
+        );
+    }
+}
+
+/// Print the biggest gainers and losers over `window` (e.g. `7d`) from
+/// `/leaderboard/movers`.
+pub async fn run_movers(rpc_url: &str, window: &str, json: bool) -> Result<()> {
+    let path = format!("/leaderboard/movers?window={}", window);
+    let result = rpc_call(rpc_url, "GET", &path, None).await?;
+    let body = result.get("body").cloned().unwrap_or(result);
+    if json {
+        println!("{}", serde_json::to_string_pretty(&body)?);
+        return Ok(());
+    }
+
+    if body.get("from_ms").is_none_or(Value::is_null) {
+        println!(
+            "  {}",
+            style("Not enough leaderboard history yet (snapshots are taken daily).").dim()
+        );
+        return Ok(());
+    }
+    let list = |key: &str| body.get(key).and_then(|v| v.as_array()).cloned();
+    let since = body
+        .get("from_ms")
+        .and_then(|v| v.as_i64())
+        .map(timestamps::format_ms)
+        .unwrap_or_default();
+    println!(
+        "  {} {} {}",
+        style("Top movers over").dim(),
+        style(window).cyan(),
+        style(format!("(since {})", since)).dim()
+    );
+    print_movers("Gainers", &list("gainers").unwrap_or_default());
+    print_movers("Losers", &list("losers").unwrap_or_default());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_compute_diff() {
+        let snapshot = json!([
+            { "rank": 1, "hotkey": "alice", "github_username": "a", "net_points": 10.0 },
+            { "rank": 2, "hotkey": "bob", "github_username": "b", "net_points": 8.0 },
+            { "rank": 3, "hotkey": "carol", "github_username": "c", "net_points": 5.0 },
+        ]);
+        let current = json!({ "body": [
+            { "rank": 1, "hotkey": "bob", "github_username": "b", "net_points": 12.0 },
+            { "rank": 2, "hotkey": "alice", "github_username": "a", "net_points": 10.0 },
+            { "rank": 3, "hotkey": "dave", "github_username": "d", "net_points": 4.0 },
+        ]});
+
+        let diff = compute_diff(&snapshot, &current);
+
+        let moves: Vec<_> = diff
+            .rows
+            .iter()
+            .map(|r| (r.hotkey.as_str(), r.rank_delta, r.points_delta))
+            .collect();
+        assert_eq!(
+            moves,
+            vec![
+                ("bob", Some(1), Some(4.0)),
+                ("alice", Some(-1), Some(0.0)),
+                ("dave", None, None),
+            ]
+        );
+        assert_eq!(diff.dropped.len(), 1);
+        assert_eq!(diff.dropped[0].hotkey, "carol");
+    }
+
+    #[test]
+    fn test_group_by_github() {
+        let data = json!([
+            { "hotkey": "hk-a1", "github_username": "alice", "valid_issues": 5, "invalid_issues": 1,
+              "star_count": 2, "star_bonus": 0.5, "net_points": 5.0, "score": 0.4 },
+            { "hotkey": "hk-b", "github_username": "bob", "valid_issues": 6, "invalid_issues": 0,
+              "star_count": 0, "star_bonus": 0.0, "net_points": 6.0, "score": 0.35 },
+            { "hotkey": "hk-a2", "github_username": "Alice", "valid_issues": 3, "invalid_issues": 0,
+              "star_count": 2, "star_bonus": 0.5, "net_points": 3.5, "score": 0.25 },
+        ]);
+
+        let groups = group_by_github(&data);
+
+        assert_eq!(groups.len(), 2);
+        let alice = &groups[0];
+        assert_eq!(alice.github, "alice");
+        assert_eq!(alice.hotkeys, ["hk-a1", "hk-a2"]);
+        assert_eq!((alice.valid, alice.invalid, alice.stars), (8, 1, 2));
+        // 5.0 + 3.5 with the 0.5 star bonus counted once.
+        assert_eq!(alice.net_points, 8.0);
+        assert!((alice.score - 0.65).abs() < 1e-9);
+        assert!(!alice.inconsistent);
+        assert_eq!(groups[1].hotkeys, ["hk-b"]);
+    }
+
+    #[test]
+    fn test_group_by_github_flags_mismatched_stars() {
+        let data = json!({ "body": [
+            { "hotkey": "hk-1", "github_username": "carol", "valid_issues": 1,
+              "star_count": 1, "star_bonus": 0.25, "net_points": 1.25 },
+            { "hotkey": "hk-2", "github_username": "carol", "valid_issues": 2,
+              "star_count": 3, "star_bonus": 0.75, "net_points": 2.75 },
+        ]});
+
+        let groups = group_by_github(&data);
+
+        assert_eq!(groups.len(), 1);
+        assert!(groups[0].inconsistent);
+        assert_eq!(groups[0].stars, 3);
+        assert_eq!(groups[0].net_points, 3.75);
+    }
+
+    #[test]
+    fn test_follow_ticks_keep_cadence() {
+        let interval = Duration::from_secs(5);
+        let ms = Duration::from_millis;
+        assert_eq!(until_next_tick(ms(300), interval), ms(4_700));
+        assert_eq!(until_next_tick(ms(5_000), interval), ms(5_000));
+        // A 7s fetch overran the 5s tick: wait for the 10s one.
+        assert_eq!(until_next_tick(ms(7_000), interval), ms(3_000));
+        assert_eq!(until_next_tick(ms(0), Duration::ZERO), ms(1));
+    }
+
+    #[test]
+    fn test_table_lines() {
+        let lines = table_lines(&json!({ "body": [
+            { "rank": 1, "hotkey": "5Alice", "github_username": "alice",
+              "valid_issues": 3, "net_points": 3.0, "score": 0.75 }
+        ]}));
+        assert_eq!(lines.len(), 3);
+        assert!(lines[2].contains("5Alice") && lines[2].contains("3.00"));
+        assert!(table_lines(&json!([]))[2].contains("No miners yet"));
+    }
+
+    #[test]
+    fn test_saved_files() {
+        let data = json!({ "body": [
+            { "rank": 1, "hotkey": "5Alice", "github_username": "alice, inc",
+              "net_points": 3.0, "valid_issues": 3, "suspicious": false },
+        ]});
+        let at = 1_705_590_000_000;
+
+        let csv = to_csv(&data, at);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "# fetched_at: 2024-01-18T15:00:00.000Z");
+        assert!(lines[1].starts_with("rank,hotkey,github_username,net_points,"));
+        assert!(lines[2].starts_with("1,5Alice,\"alice, inc\",3.0,,3,"));
+        assert_eq!(lines.len(), 3);
+
+        let saved = to_saved_json(&data, at);
+        assert_eq!(saved["fetched_at_ms"], at);
+        // Still readable as a --diff snapshot.
+        let diff = compute_diff(&saved, &data);
+        assert_eq!(diff.rows[0].rank_delta, Some(0));
+    }
+
+    #[test]
+    fn test_leaderboard_path() {
+        assert_eq!(leaderboard_path(None), "/leaderboard");
+        assert_eq!(leaderboard_path(Some("net")), "/leaderboard?sort=net");
+    }
+
+    #[test]
+    fn test_format_rank_delta() {
+        let unicode = &theme::UNICODE;
+        assert_eq!(format_rank_delta(Some(3), unicode), "▲3");
+        assert_eq!(format_rank_delta(Some(-2), unicode), "▼2");
+        assert_eq!(format_rank_delta(Some(0), unicode), "—");
+        assert_eq!(format_rank_delta(None, unicode), "new");
+    }
+
+    #[test]
+    fn test_format_rank_delta_ascii() {
+        for delta in [Some(3), Some(-2), Some(0), None] {
+            let text = format_rank_delta(delta, &theme::ASCII);
+            assert!(text.is_ascii(), "{:?}", text);
+        }
+        assert_eq!(format_rank_delta(Some(3), &theme::ASCII), "+3");
+    }
+}
@@ -1,4 +1,10 @@
 pub mod claim;
+pub mod export;
+pub mod import;
 pub mod issues;
+pub mod leaderboard;
 pub mod register;
+pub mod snapshot;
+pub mod stats;
 pub mod status;
+pub mod whoami;
@@ -1,14 +1,18 @@
-use anyhow::Result;
+use anyhow::{Context, Result};
+use bip39::{Language, Mnemonic};
 use console::style;
 use dialoguer::{Input, Password};
-use sp_core::{crypto::Pair as PairTrait, sr25519::Pair};
-use bip39::{Language, Mnemonic};
+use serde_json::Value;
+use std::path::Path;
 
-use crate::rpc::rpc_call_auth;
+use crate::args::{RegisterArgs, RegisterAuth};
+use crate::keys::Keypair;
+use crate::rpc::{rpc_call, rpc_call_auth};
+use crate::theme;
 
 pub async fn run(rpc_url: &str) -> Result<()> {
     println!("\n{}", style("Register GitHub Username").cyan().bold());
-    println!("{}\n", style("─".repeat(40)).dim());
+    println!("{}\n", style(theme::rule(40)).dim());
 
     let github: String = Input::new()
         .with_prompt("GitHub username")
@@ -18,6 +22,78 @@ pub async fn run(rpc_url: &str) -> Result<()> {
         .with_prompt("Enter your 12 or 24-word mnemonic (hidden)")
         .interact()?;
 
+    let pair = derive_keypair(&mnemonic)?;
+
+    let hotkey_ss58 = pair.to_ss58();
+
+    println!("  {} {}", style("Hotkey:").dim(), theme::good(&hotkey_ss58));
+
+    let body = serde_json::json!({
+        "github_username": github,
+    });
+
+    println!("{}", style("Sending authenticated registration...").dim());
+
+    let result = rpc_call_auth(rpc_url, "POST", "/register", Some(body), &pair).await?;
+    report_result(&github, &hotkey_ss58, &result);
+
+    println!();
+    Ok(())
+}
+
+/// Non-interactive registration for scripts and CI: never prompts, and fails
+/// with a non-zero exit code if the validator rejects the registration.
+pub async fn run_non_interactive(rpc_url: &str, args: RegisterArgs) -> Result<()> {
+    let github = args.github;
+    let (hotkey_ss58, result) = match args.auth {
+        RegisterAuth::MnemonicFile { path } => {
+            let mnemonic = read_mnemonic(&path)?;
+            let pair = derive_keypair(&mnemonic)?;
+            let hotkey_ss58 = pair.to_ss58();
+            let body = serde_json::json!({
+                "github_username": github,
+            });
+            let result = rpc_call_auth(rpc_url, "POST", "/register", Some(body), &pair).await?;
+            (hotkey_ss58, result)
+        }
+        RegisterAuth::Signed {
+            hotkey,
+            signature,
+            timestamp,
+        } => {
+            let body = serde_json::json!({
+                "hotkey": hotkey,
+                "github_username": github,
+                "signature": signature,
+                "timestamp": timestamp,
+            });
+            let result = rpc_call(rpc_url, "POST", "/register", Some(body)).await?;
+            (hotkey, result)
+        }
+    };
+
+    if !report_result(&github, &hotkey_ss58, &result) {
+        anyhow::bail!("Registration failed");
+    }
+    Ok(())
+}
+
+/// Read a mnemonic from `path`, or from stdin when `path` is `-`.
+pub(crate) fn read_mnemonic(path: &Path) -> Result<String> {
+    if path == Path::new("-") {
+        let mut mnemonic = String::new();
+        std::io::stdin()
+            .read_line(&mut mnemonic)
+            .context("Failed to read mnemonic from stdin")?;
+        return Ok(mnemonic);
+    }
+    std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read mnemonic file '{}'", path.display()))
+}
+
+/// Derive a hotkey pair from a BIP39 mnemonic, explaining common mistakes
+/// (wrong word count, typos, bad checksum) in the error.
+pub(crate) fn derive_keypair(mnemonic: &str) -> Result<Keypair> {
     let mnemonic = mnemonic.trim();
     let words: Vec<&str> = mnemonic.split_whitespace().collect();
     if words.len() != 12 && words.len() != 24 {
@@ -27,10 +103,11 @@ pub async fn run(rpc_url: &str) -> Result<()> {
         );
     }
 
-    println!("{}", style("Deriving sr25519 keypair...").dim());
+    // stderr, so commands that print data to stdout stay parseable.
+    eprintln!("{}", style("Deriving sr25519 keypair...").dim());
 
-    let pair = match Pair::from_phrase(mnemonic, None) {
-        Ok((pair, _seed)) => pair,
+    match Keypair::from_phrase(mnemonic) {
+        Ok(pair) => Ok(pair),
         Err(e) => {
             let error_detail = match Mnemonic::parse_in(Language::English, mnemonic) {
                 Ok(_) => format!("{}", e),
@@ -61,24 +138,12 @@ pub async fn run(rpc_url: &str) -> Result<()> {
             };
             anyhow::bail!("Invalid mnemonic: {}", error_detail);
         }
-    };
-
-    let hotkey_ss58 = sp_core::crypto::Ss58Codec::to_ss58check(&pair.public());
-
-    println!(
-        "  {} {}",
-        style("Hotkey:").dim(),
-        style(&hotkey_ss58).green()
-    );
-
-    let body = serde_json::json!({
-        "github_username": github,
-    });
-
-    println!("{}", style("Sending authenticated registration...").dim());
+    }
+}
 
-    let result = rpc_call_auth(rpc_url, "POST", "/register", Some(body), &pair).await?;
-    let response_body = result.get("body").unwrap_or(&result);
+/// Print the outcome of a `/register` call; returns whether it succeeded.
+fn report_result(github: &str, hotkey_ss58: &str, result: &Value) -> bool {
+    let response_body = result.get("body").unwrap_or(result);
 
     let success = response_body
         .get("success")
@@ -88,11 +153,10 @@ pub async fn run(rpc_url: &str) -> Result<()> {
     if success {
         println!(
             "\n{}",
-            style(format!(
+            theme::good(format!(
                 "Successfully registered '{}' with hotkey {}",
                 github, hotkey_ss58
             ))
-            .green()
             .bold()
         );
     } else {
@@ -100,10 +164,40 @@ pub async fn run(rpc_url: &str) -> Result<()> {
             .get("error")
             .and_then(|v| v.as_str())
             .unwrap_or("Unknown error");
-        println!("\n{}", style("Registration failed.").red().bold());
+        println!("\n{}", theme::bad("Registration failed.").bold());
         println!("  Error: {}", error);
     }
 
-    println!();
-    Ok(())
+    success
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mnemonic_word_count() {
+        let twelve = format!("{} about", "abandon ".repeat(11).trim_end());
+        let twenty_four = format!("{} art", "abandon ".repeat(23).trim_end());
+        assert!(derive_keypair(&twelve).is_ok());
+        assert!(derive_keypair(&format!("  {}\n", twenty_four)).is_ok());
+
+        for count in [0, 11, 13, 18, 25] {
+            let phrase = "abandon ".repeat(count);
+            let err = derive_keypair(&phrase).unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains(&format!("Expected 12 or 24 words, got {}", count)),
+                "{}",
+                err
+            );
+        }
+    }
+
+    #[test]
+    fn test_mnemonic_typo_is_named() {
+        let phrase = format!("{} abuot", "abandon ".repeat(11).trim_end());
+        let err = derive_keypair(&phrase).unwrap_err();
+        assert!(err.to_string().contains("'abuot'"), "{}", err);
+    }
 }
@@ -0,0 +1,157 @@
+use anyhow::Result;
+use console::{style, Term};
+use serde_json::Value;
+use std::time::Duration;
+
+use crate::error::is_dry_run;
+use crate::rpc::rpc_call;
+use crate::timestamps;
+
+const FIELDS: &[(&str, &str)] = &[
+    ("total_bounties", "Total bounties"),
+    ("active_miners", "Active miners"),
+    ("validator_count", "Validators"),
+    ("total_issues", "Total issues"),
+    ("last_refreshed", "Last refreshed"),
+];
+
+/// `/issues/stats` fields shown with `--detailed`.
+const ISSUE_FIELDS: &[(&str, &str)] = &[
+    ("open", "Open issues"),
+    ("closed", "Closed issues"),
+    ("valid", "Valid"),
+    ("invalid", "Invalid"),
+    ("pending", "Pending"),
+    ("credited", "Credited"),
+    ("uncredited", "Uncredited"),
+    ("unclaimed", "Unclaimed"),
+];
+
+/// Print `/stats` once, or with `watch` keep polling every `interval_secs`
+/// until Ctrl-C. Output is plain lines (or one JSON object per poll) so it
+/// can be redirected to a log file. `detailed` adds the `/issues/stats`
+/// pipeline counts, under `issues` in JSON.
+pub async fn run(
+    rpc_url: &str,
+    watch: bool,
+    interval_secs: u64,
+    json: bool,
+    detailed: bool,
+) -> Result<()> {
+    if !watch {
+        let body = fetch(rpc_url, detailed).await?;
+        print_block(&body, json);
+        return Ok(());
+    }
+
+    let term = Term::stdout();
+    let redraw = !json && term.is_term();
+    let mut printed_lines = 0;
+    let interval = Duration::from_secs(interval_secs);
+
+    loop {
+        if redraw && printed_lines > 0 {
+            term.clear_last_lines(printed_lines)?;
+        }
+        printed_lines = match fetch(rpc_url, detailed).await {
+            Ok(body) => print_block(&body, json),
+            Err(e) if is_dry_run(&e) => return Err(e),
+            Err(e) if json => {
+                println!("{}", serde_json::json!({ "error": e.to_string() }));
+                1
+            }
+            Err(e) => {
+                println!("Error: {}", e);
+                1
+            }
+        };
+
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            _ = tokio::time::sleep(interval) => {}
+        }
+    }
+
+    Ok(())
+}
+
+async fn fetch(rpc_url: &str, detailed: bool) -> Result<Value> {
+    let result = rpc_call(rpc_url, "GET", "/stats", None).await?;
+    let mut body = result.get("body").cloned().unwrap_or(result);
+    if detailed {
+        let result = rpc_call(rpc_url, "GET", "/issues/stats", None).await?;
+        let issues = result.get("body").cloned().unwrap_or(result);
+        if let Some(object) = body.as_object_mut() {
+            object.insert("issues".to_string(), issues);
+        }
+    }
+    Ok(body)
+}
+
+fn block_lines(body: &Value) -> Vec<String> {
+    let field = |value: Option<&Value>, label: &str, key: &str| {
+        let value = match value {
+            Some(Value::Null) | None => "-".to_string(),
+            Some(v) if key == "last_refreshed" => match v.as_i64() {
+                Some(ms) => timestamps::format_ms(ms),
+                None => v.to_string(),
+            },
+            Some(v) => v.to_string(),
+        };
+        format!("{:<16} {}", style(label).dim(), value)
+    };
+
+    let mut lines: Vec<String> = FIELDS
+        .iter()
+        .map(|(key, label)| field(body.get(key), label, key))
+        .collect();
+    if let Some(issues) = body.get("issues") {
+        lines.extend(
+            ISSUE_FIELDS
+                .iter()
+                .map(|(key, label)| field(issues.get(key), label, key)),
+        );
+    }
+    lines
+}
+
+/// Print one stats block and return how many lines it used.
+fn print_block(body: &Value, json: bool) -> usize {
+    if json {
+        println!("{}", body);
+        return 1;
+    }
+
+    let lines = block_lines(body);
+    for line in &lines {
+        println!("{}", line);
+    }
+    lines.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_detailed_block() {
+        let mut body = json!({
+            "total_bounties": 12,
+            "active_miners": 3,
+            "validator_count": 2,
+            "total_issues": 40,
+            "last_refreshed": null,
+        });
+        assert_eq!(block_lines(&body).len(), FIELDS.len());
+
+        body["issues"] = json!({
+            "total": 40, "open": 10, "closed": 30, "valid": 20, "invalid": 5,
+            "pending": 15, "credited": 12, "uncredited": 28, "unclaimed": 4,
+        });
+        let lines = block_lines(&body);
+        assert_eq!(lines.len(), FIELDS.len() + ISSUE_FIELDS.len());
+        let unclaimed = lines.last().unwrap();
+        assert!(unclaimed.contains("Unclaimed") && unclaimed.ends_with(" 4"));
+    }
+}
@@ -1,18 +1,199 @@
 use anyhow::Result;
 use console::style;
 use dialoguer::Input;
+use serde_json::Value;
 
+use crate::error::{CliError, ExitCode};
 use crate::rpc::rpc_call;
+use crate::theme;
+use crate::timestamps;
 
 pub async fn run(rpc_url: &str) -> Result<()> {
     println!("\n{}", style("Miner Status").cyan().bold());
-    println!("{}\n", style("─".repeat(40)).dim());
+    println!("{}\n", style(theme::rule(40)).dim());
 
     let hotkey: String = Input::new().with_prompt("SS58 hotkey").interact_text()?;
 
-    let path = format!("/status/{}", hotkey.trim());
-    let result = rpc_call(rpc_url, "GET", &path, None).await?;
+    show_status(rpc_url, hotkey.trim()).await?;
+    Ok(())
+}
+
+/// Non-interactive variant: fails with `ExitCode::NotFound` for an
+/// unregistered hotkey. With `explain`, follows the status with a paragraph
+/// on how the weight is derived.
+pub async fn run_hotkey(rpc_url: &str, hotkey: &str, explain: bool) -> Result<()> {
+    let hotkey = hotkey.trim();
+    if !show_status(rpc_url, hotkey).await? {
+        return Err(CliError::new(ExitCode::NotFound, "Hotkey not registered").into());
+    }
+    if explain {
+        let path = format!("/hotkey/{}", hotkey);
+        let result = rpc_call(rpc_url, "GET", &path, None).await?;
+        let body = result.get("body").unwrap_or(&result);
+        match Breakdown::from_details(body) {
+            Some(breakdown) => println!("  {}\n", explain_weight(&breakdown)),
+            None => println!(
+                "  {}\n",
+                style("The validator does not report a weight breakdown.").dim()
+            ),
+        }
+    }
+    Ok(())
+}
+
+/// The `weight_breakdown` of `/hotkey/:hotkey` with the counts behind it.
+#[derive(Debug, Default, PartialEq)]
+pub struct Breakdown {
+    pub valid: u64,
+    pub invalid: u64,
+    pub duplicates: u64,
+    pub malicious: u64,
+    pub stars: u64,
+    pub issue_points: f64,
+    pub invalid_penalty: f64,
+    pub duplicate_penalty: f64,
+    pub malicious_penalty: f64,
+    pub star_bonus: f64,
+    pub net_points: f64,
+    pub weight: f64,
+    pub miners: u64,
+}
+
+impl Breakdown {
+    fn from_details(body: &Value) -> Option<Self> {
+        let terms = body.get("weight_breakdown")?;
+        let term = |key: &str| terms.get(key).and_then(|v| v.as_f64()).unwrap_or(0.0);
+        let count = |value: Option<&Value>| value.and_then(|v| v.as_u64()).unwrap_or(0);
+        let balance = body.get("balance");
+        Some(Self {
+            valid: count(body.get("valid_issues")),
+            invalid: count(body.get("invalid_issues")),
+            duplicates: count(body.get("duplicate_issues")),
+            malicious: count(balance.and_then(|b| b.get("malicious_count"))),
+            stars: count(balance.and_then(|b| b.get("star_count"))),
+            issue_points: term("issue_points"),
+            invalid_penalty: term("invalid_penalty"),
+            duplicate_penalty: term("duplicate_penalty"),
+            malicious_penalty: term("malicious_penalty"),
+            star_bonus: term("star_bonus"),
+            net_points: term("net_points"),
+            weight: term("normalized_weight"),
+            miners: count(terms.get("miners")),
+        })
+    }
+}
+
+fn plural(count: u64, noun: &str) -> String {
+    if count == 1 {
+        format!("1 {}", noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
+
+/// One paragraph walking from issue counts to the normalized weight; terms
+/// that are zero are left out.
+pub fn explain_weight(b: &Breakdown) -> String {
+    let mut text = format!(
+        "Your weight of {:.4} comes from {} (base {:.2})",
+        b.weight,
+        plural(b.valid, "valid issue"),
+        b.issue_points
+    );
+    let penalties = [
+        (b.invalid_penalty, b.invalid, "invalid issue"),
+        (b.duplicate_penalty, b.duplicates, "duplicate"),
+        (b.malicious_penalty, b.malicious, "malicious issue"),
+    ];
+    for (penalty, count, noun) in penalties {
+        if penalty > 0.0 {
+            text.push_str(&format!(
+                ", minus a penalty of {:.2} for {}",
+                penalty,
+                plural(count, noun)
+            ));
+        }
+    }
+    if b.star_bonus > 0.0 {
+        text.push_str(&format!(
+            ", plus {:.2} star bonus for {}",
+            b.star_bonus,
+            plural(b.stars, "starred repo")
+        ));
+    }
+    if b.net_points <= 0.0 {
+        text.push_str(". Penalties consume all points, so the weight is 0.");
+    } else if b.weight <= 0.0 {
+        text.push_str(&format!(
+            ", for {:.2} net points. The weight is 0 because the hotkey is banned or \
+             not yet on the leaderboard.",
+            b.net_points
+        ));
+    } else {
+        text.push_str(&format!(
+            ", for {:.2} net points, normalized against {}.",
+            b.net_points,
+            plural(b.miners, "miner")
+        ));
+    }
+    text
+}
+
+/// Print the status body as pretty JSON. Unregistered hotkeys print
+/// `{"registered": false, "hotkey": ...}` and only fail (with
+/// `ExitCode::NotFound`) when `fail_if_unregistered` is set.
+pub async fn run_hotkey_json(
+    rpc_url: &str,
+    hotkey: &str,
+    fail_if_unregistered: bool,
+) -> Result<()> {
+    let hotkey = hotkey.trim();
+    let body = fetch_status(rpc_url, hotkey).await?;
+    let registered = body
+        .get("registered")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+
+    let output = if registered {
+        body
+    } else {
+        serde_json::json!({ "registered": false, "hotkey": hotkey })
+    };
+    println!("{}", serde_json::to_string_pretty(&output)?);
+
+    if !registered && fail_if_unregistered {
+        return Err(CliError::new(ExitCode::NotFound, "Hotkey not registered").into());
+    }
+    Ok(())
+}
+
+/// "12 of 340 (top 4%)" from `/leaderboard/:hotkey/rank`, or `None` if the
+/// validator doesn't serve the route.
+async fn fetch_rank(rpc_url: &str, hotkey: &str) -> Option<String> {
+    let path = format!("/leaderboard/{}/rank", hotkey);
+    let result = rpc_call(rpc_url, "GET", &path, None).await.ok()?;
     let body = result.get("body").unwrap_or(&result);
+    let rank = body.get("rank")?.as_u64()?;
+    let total = body.get("total_miners")?.as_u64()?;
+    let percentile = body.get("percentile")?.as_f64()?;
+    Some(format!(
+        "{} of {} (top {}%)",
+        rank,
+        total,
+        percentile.ceil().max(1.0) as u64
+    ))
+}
+
+async fn fetch_status(rpc_url: &str, hotkey: &str) -> Result<Value> {
+    let path = format!("/status/{}", hotkey);
+    let result = rpc_call(rpc_url, "GET", &path, None).await?;
+    Ok(result.get("body").cloned().unwrap_or(result))
+}
+
+/// Print the status of `hotkey`; returns whether it is registered.
+async fn show_status(rpc_url: &str, hotkey: &str) -> Result<bool> {
+    let body = fetch_status(rpc_url, hotkey).await?;
+    let body = &body;
 
     let registered = body
         .get("registered")
@@ -20,13 +201,10 @@ pub async fn run(rpc_url: &str) -> Result<()> {
         .unwrap_or(false);
 
     if !registered {
-        println!("\n{} {}", style("Hotkey").dim(), style(&hotkey).yellow());
-        println!(
-            "{}",
-            style("Not registered. Use Register to sign up.").red()
-        );
+        println!("\n{} {}", style("Hotkey").dim(), style(hotkey).yellow());
+        println!("{}", theme::bad("Not registered. Use Register to sign up."));
         println!();
-        return Ok(());
+        return Ok(false);
     }
 
     let github = body
@@ -42,6 +220,7 @@ pub async fn run(rpc_url: &str) -> Result<()> {
         .and_then(|v| v.as_u64())
         .unwrap_or(0);
     let weight = body.get("weight").and_then(|v| v.as_f64()).unwrap_or(0.0);
+    let net_points = body.get("net_points").and_then(|v| v.as_f64());
 
     let balance = body.get("balance");
     let duplicates = balance
@@ -56,20 +235,20 @@ pub async fn run(rpc_url: &str) -> Result<()> {
         .and_then(|b| b.get("is_penalized"))
         .and_then(|v| v.as_bool())
         .unwrap_or(false);
+    let suspicious = body
+        .get("suspicious")
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
 
     println!();
-    println!("  {} {}", style("Hotkey:").dim(), style(&hotkey).green());
+    println!("  {} {}", style("Hotkey:").dim(), theme::good(hotkey));
     println!("  {} {}", style("GitHub:").dim(), style(github).cyan());
     println!();
-    println!(
-        "  {} {}",
-        style("Valid Issues:").dim(),
-        style(valid).green()
-    );
+    println!("  {} {}", style("Valid Issues:").dim(), theme::good(valid));
     println!(
         "  {} {}",
         style("Invalid Issues:").dim(),
-        style(invalid).red()
+        theme::bad(invalid)
     );
     println!(
         "  {} {}",
@@ -77,27 +256,118 @@ pub async fn run(rpc_url: &str) -> Result<()> {
         style(duplicates).yellow()
     );
     println!("  {} {}", style("Stars:").dim(), style(stars).yellow());
+    if let Some(net) = net_points {
+        println!(
+            "  {} {}",
+            style("Net Points:").dim(),
+            style(format!("{:.2}", net)).bold()
+        );
+    }
     println!();
     println!(
         "  {} {}",
         style("Weight:").dim(),
         style(format!("{:.4}", weight)).bold()
     );
+    if let Some(rank) = fetch_rank(rpc_url, hotkey).await {
+        println!("  {} {}", style("Rank:").dim(), style(rank).cyan());
+    }
+    if let Some(ms) = body.get("last_refreshed").and_then(|v| v.as_i64()) {
+        println!(
+            "  {} {}",
+            style("Updated:").dim(),
+            timestamps::format_ms(ms)
+        );
+    }
 
     if penalized {
         println!(
             "  {} {}",
             style("Status:").dim(),
-            style("PENALIZED (weight = 0)").red().bold()
+            theme::bad("PENALIZED (weight = 0)").bold()
         );
     } else {
         println!(
             "  {} {}",
             style("Status:").dim(),
-            style("Active").green().bold()
+            theme::good("Active").bold()
+        );
+    }
+    if suspicious {
+        println!(
+            "  {} {}",
+            style("Review:").dim(),
+            style("Flagged for operator review (claim pattern)").yellow()
         );
     }
 
     println!();
-    Ok(())
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breakdown() -> Breakdown {
+        Breakdown {
+            valid: 5,
+            invalid: 3,
+            stars: 2,
+            issue_points: 5.0,
+            invalid_penalty: 1.5,
+            star_bonus: 0.5,
+            net_points: 4.0,
+            weight: 0.0321,
+            miners: 340,
+            ..Breakdown::default()
+        }
+    }
+
+    #[test]
+    fn test_explain_weight() {
+        assert_eq!(
+            explain_weight(&breakdown()),
+            "Your weight of 0.0321 comes from 5 valid issues (base 5.00), minus a penalty \
+             of 1.50 for 3 invalid issues, plus 0.50 star bonus for 2 starred repos, for \
+             4.00 net points, normalized against 340 miners."
+        );
+    }
+
+    #[test]
+    fn test_explain_weight_when_penalties_consume_points() {
+        let b = Breakdown {
+            valid: 1,
+            issue_points: 1.0,
+            duplicates: 4,
+            duplicate_penalty: 2.0,
+            invalid_penalty: 0.0,
+            star_bonus: 0.0,
+            net_points: 0.0,
+            weight: 0.0,
+            ..breakdown()
+        };
+        assert_eq!(
+            explain_weight(&b),
+            "Your weight of 0.0000 comes from 1 valid issue (base 1.00), minus a penalty \
+             of 2.00 for 4 duplicates. Penalties consume all points, so the weight is 0."
+        );
+    }
+
+    #[test]
+    fn test_breakdown_from_details() {
+        let body = serde_json::json!({
+            "valid_issues": 5,
+            "invalid_issues": 3,
+            "duplicate_issues": 0,
+            "balance": { "star_count": 2, "malicious_count": 0 },
+            "weight_breakdown": {
+                "issue_points": 5.0, "invalid_penalty": 1.5, "duplicate_penalty": 0.0,
+                "malicious_penalty": 0.0, "star_bonus": 0.5, "net_points": 4.0,
+                "normalized_weight": 0.0321, "miners": 340
+            }
+        });
+        assert_eq!(Breakdown::from_details(&body), Some(breakdown()));
+        assert_eq!(Breakdown::from_details(&serde_json::json!({})), None);
+    }
 }
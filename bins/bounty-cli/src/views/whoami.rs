@@ -0,0 +1,91 @@
+use anyhow::Result;
+use console::style;
+use dialoguer::Password;
+use std::io::IsTerminal;
+use std::path::Path;
+
+use super::register::{derive_keypair, read_mnemonic};
+use crate::keys;
+use crate::theme;
+
+/// Addresses of the hotkey a mnemonic derives to.
+#[derive(Debug, PartialEq)]
+pub struct Identity {
+    pub ss58: String,
+    /// Hex of the 32-byte public key, `0x`-prefixed.
+    pub public_key: String,
+}
+
+pub fn identity(mnemonic: &str, ss58_format: u16) -> Result<Identity> {
+    let pair = derive_keypair(mnemonic)?;
+    Ok(Identity {
+        ss58: pair.to_ss58_with_format(ss58_format),
+        public_key: format!("0x{}", hex::encode(pair.public_bytes())),
+    })
+}
+
+/// Print the hotkey a mnemonic derives to, without contacting the server.
+/// Without `--mnemonic-file` the mnemonic is prompted for (hidden) on a
+/// terminal, or read from piped stdin.
+pub fn run(mnemonic_file: Option<&Path>) -> Result<()> {
+    let ss58_format = keys::ss58_format();
+    let mnemonic = match mnemonic_file {
+        Some(path) => read_mnemonic(path)?,
+        None if std::io::stdin().is_terminal() => Password::new()
+            .with_prompt("Enter your 12 or 24-word mnemonic (hidden)")
+            .interact()?,
+        None => read_mnemonic(Path::new("-"))?,
+    };
+    let identity = identity(&mnemonic, ss58_format)?;
+
+    println!("{} {}", style("Hotkey:").dim(), theme::good(&identity.ss58));
+    println!("{} {}", style("Public key:").dim(), identity.public_key);
+    println!(
+        "{} sr25519 (SS58 format {})",
+        style("Scheme:").dim(),
+        ss58_format
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_core::crypto::Ss58Codec;
+    use sp_core::sr25519;
+
+    /// Substrate's development phrase, whose derivation is published.
+    const DEV_PHRASE: &str =
+        "bottom drive obey lake curtain smoke basket hold race lonely fit walk";
+
+    #[test]
+    fn test_dev_phrase_vector() {
+        let id = identity(DEV_PHRASE, 42).unwrap();
+        assert_eq!(
+            id.public_key,
+            "0x46ebddef8cd9bb167dc30878d7113b7e168e6f0646beffd77d69d39bad76b47a"
+        );
+        assert_eq!(id.ss58, "5DfhGyQdFobKM8NsWvEeAKk5EQQgYe9AydgJ7rMB6E1EqRzV");
+    }
+
+    #[test]
+    fn test_ss58_format_changes_only_the_prefix() {
+        let generic = identity(DEV_PHRASE, 42).unwrap();
+        let polkadot = identity(DEV_PHRASE, 0).unwrap();
+        assert_eq!(generic.public_key, polkadot.public_key);
+        assert_ne!(generic.ss58, polkadot.ss58);
+
+        let (public, version) =
+            sr25519::Public::from_ss58check_with_version(&polkadot.ss58).unwrap();
+        assert_eq!(u16::from(version), 0);
+        assert_eq!(format!("0x{}", hex::encode(public.0)), polkadot.public_key);
+    }
+
+    #[test]
+    fn test_twenty_four_words() {
+        let phrase = format!("{} art", "abandon ".repeat(23).trim_end());
+        let id = identity(&phrase, 42).unwrap();
+        let public = sr25519::Public::from_ss58check(&id.ss58).unwrap();
+        assert_eq!(format!("0x{}", hex::encode(public.0)), id.public_key);
+    }
+}
@@ -0,0 +1,60 @@
+//! Captures build metadata for `GET /version`. Each value can be set in the
+//! environment (e.g. by CI); otherwise it is read from git and rustc,
+//! falling back to "unknown". The build time is never read from the clock,
+//! so rebuilding the same commit gives the same module.
+
+use std::env;
+use std::path::Path;
+use std::process::Command;
+
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let text = String::from_utf8(output.stdout).ok()?;
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+fn from_env(name: &str) -> Option<String> {
+    println!("cargo:rerun-if-env-changed={}", name);
+    env::var(name).ok().filter(|v| !v.trim().is_empty())
+}
+
+/// Rerun when the checked-out commit changes: HEAD moves on checkout, and
+/// the branch it names moves on commit (loose or packed).
+fn watch_git_head() {
+    let mut paths = vec!["HEAD".to_string(), "packed-refs".to_string()];
+    if let Some(branch) = command_output("git", &["symbolic-ref", "-q", "HEAD"]) {
+        paths.push(branch);
+    }
+    for path in paths {
+        // A missing path would make cargo rerun the script on every build.
+        if let Some(file) = command_output("git", &["rev-parse", "--git-path", &path])
+            .filter(|file| Path::new(file).exists())
+        {
+            println!("cargo:rerun-if-changed={}", file);
+        }
+    }
+}
+
+fn main() {
+    let git_commit = from_env("GIT_COMMIT")
+        .or_else(|| command_output("git", &["rev-parse", "--short=12", "HEAD"]))
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let build_timestamp = from_env("BUILD_TIMESTAMP")
+        .or_else(|| from_env("SOURCE_DATE_EPOCH"))
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let rustc_version =
+        command_output(&rustc, &["--version"]).unwrap_or_else(|| "unknown".to_string());
+
+    println!("cargo:rustc-env=BOUNTY_GIT_COMMIT={}", git_commit);
+    println!("cargo:rustc-env=BOUNTY_BUILD_TIMESTAMP={}", build_timestamp);
+    println!("cargo:rustc-env=BOUNTY_RUSTC_VERSION={}", rustc_version);
+    watch_git_head();
+    println!("cargo:rerun-if-changed=build.rs");
+}
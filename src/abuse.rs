@@ -0,0 +1,279 @@
+//! Heuristics that flag miners for operator review. A flag never changes
+//! points or weights; it only marks the miner's leaderboard and status
+//! entries as `suspicious`.
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
+
+use crate::types::{AuditEntry, IssueRecord};
+
+/// Thresholds of the suspicious-activity heuristics. Set by the sudo owner
+/// through `/sudo/suspicion_config`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SuspicionConfig {
+    /// Claiming this many issues within `burst_window_secs` is a burst.
+    pub burst_claims: u32,
+    pub burst_window_secs: u64,
+    /// At least this many claimed issues, all created within
+    /// `cluster_window_secs` of each other, look pre-arranged.
+    pub cluster_min_issues: u32,
+    pub cluster_window_secs: u64,
+}
+
+impl Default for SuspicionConfig {
+    fn default() -> Self {
+        Self {
+            burst_claims: 20,
+            burst_window_secs: 10 * 60,
+            cluster_min_issues: 5,
+            cluster_window_secs: 5 * 60,
+        }
+    }
+}
+
+impl SuspicionConfig {
+    /// Thresholds and windows must be non-zero.
+    pub fn is_valid(&self) -> bool {
+        self.burst_claims > 0
+            && self.burst_window_secs > 0
+            && self.cluster_min_issues > 0
+            && self.cluster_window_secs > 0
+    }
+}
+
+fn window_ms(secs: u64) -> i64 {
+    i64::try_from(secs.saturating_mul(1000)).unwrap_or(i64::MAX)
+}
+
+/// Whether `claims`, as (timestamp, issues credited) pairs, credit at least
+/// `burst_claims` issues within any `burst_window_secs` span.
+pub fn is_claim_burst(claims: &[(i64, u32)], config: &SuspicionConfig) -> bool {
+    let mut claims = claims.to_vec();
+    claims.sort_unstable_by_key(|(at, _)| *at);
+    let window = window_ms(config.burst_window_secs);
+
+    let mut start = 0;
+    let mut in_window = 0u32;
+    for &(at, count) in &claims {
+        in_window = in_window.saturating_add(count);
+        while at.saturating_sub(claims[start].0) > window {
+            in_window -= claims[start].1;
+            start += 1;
+        }
+        if in_window >= config.burst_claims {
+            return true;
+        }
+    }
+    false
+}
+
+/// Whether at least `cluster_min_issues` issues were created, all within
+/// `cluster_window_secs` of each other. Issues of unknown creation time
+/// (`0`) are ignored.
+pub fn is_created_cluster(created_ms: &[i64], config: &SuspicionConfig) -> bool {
+    let known: Vec<i64> = created_ms.iter().copied().filter(|ms| *ms > 0).collect();
+    if known.len() < config.cluster_min_issues as usize {
+        return false;
+    }
+    let first = known.iter().min().copied().unwrap_or(0);
+    let last = known.iter().max().copied().unwrap_or(0);
+    last - first <= window_ms(config.cluster_window_secs)
+}
+
+fn normalize(hotkey: &str) -> String {
+    crate::ss58::normalize_hotkey(hotkey).unwrap_or_else(|| hotkey.to_string())
+}
+
+/// Issues credited by one audited claim.
+fn claimed_count(entry: &AuditEntry) -> u32 {
+    serde_json::from_str::<serde_json::Value>(&entry.detail)
+        .ok()
+        .and_then(|d| d.get("claimed").and_then(|c| c.as_array()).map(Vec::len))
+        .unwrap_or(0) as u32
+}
+
+/// Hotkeys (normalized to SS58) that trip either heuristic, judged from the
+/// audited claims and the claimed issues among `issues`.
+pub fn suspicious_hotkeys(
+    audit: &[AuditEntry],
+    issues: &[IssueRecord],
+    config: &SuspicionConfig,
+) -> BTreeSet<String> {
+    let mut claims: BTreeMap<String, Vec<(i64, u32)>> = BTreeMap::new();
+    for entry in audit.iter().filter(|e| e.action == "claim") {
+        claims
+            .entry(normalize(&entry.actor))
+            .or_default()
+            .push((entry.timestamp_ms, claimed_count(entry)));
+    }
+
+    let mut created: BTreeMap<String, Vec<i64>> = BTreeMap::new();
+    for issue in issues {
+        if let Some(hotkey) = &issue.claimed_by_hotkey {
+            created
+                .entry(normalize(hotkey))
+                .or_default()
+                .push(issue.created_at_ms);
+        }
+    }
+
+    let mut flagged: BTreeSet<String> = claims
+        .into_iter()
+        .filter(|(_, c)| is_claim_burst(c, config))
+        .map(|(hotkey, _)| hotkey)
+        .collect();
+    flagged.extend(
+        created
+            .into_iter()
+            .filter(|(_, c)| is_created_cluster(c, config))
+            .map(|(hotkey, _)| hotkey),
+    );
+    flagged
+}
+
+/// How often the background tick re-runs the heuristics. They read the
+/// whole audit log and every synced issue, so leaderboard rebuilds and
+/// `/status` use the stored result of the last run instead.
+pub const SUSPICION_REFRESH_INTERVAL_MS: i64 = 10 * 60 * 1000;
+
+/// Run [`suspicious_hotkeys`] over the stored audit log and synced issues
+/// and store the result.
+pub fn refresh_suspicious_hotkeys(now_ms: i64) -> BTreeSet<String> {
+    let flagged = suspicious_hotkeys(
+        &crate::storage::get_audit_log(),
+        &crate::storage::get_synced_issues(),
+        &crate::storage::get_suspicion_config(),
+    );
+    crate::storage::store_suspicious_hotkeys(&flagged, now_ms);
+    flagged
+}
+
+/// [`refresh_suspicious_hotkeys`] once the stored result is older than
+/// [`SUSPICION_REFRESH_INTERVAL_MS`].
+pub fn maybe_refresh_suspicious_hotkeys(now_ms: i64) {
+    let last = crate::storage::get_suspicion_refreshed_at();
+    if last == 0 || now_ms - last >= SUSPICION_REFRESH_INTERVAL_MS {
+        refresh_suspicious_hotkeys(now_ms);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::fixtures::IssueBuilder;
+
+    const MINUTE: i64 = 60_000;
+
+    fn claim(actor: &str, at: i64, issues: &[u32]) -> AuditEntry {
+        AuditEntry {
+            timestamp_ms: at,
+            epoch: 0,
+            actor: String::from(actor),
+            action: String::from("claim"),
+            detail: serde_json::json!({ "claimed": issues, "rejected": 0 }).to_string(),
+        }
+    }
+
+    fn issue(number: u32, hotkey: &str, created_at_ms: i64) -> IssueRecord {
+        IssueBuilder::new(number)
+            .claimed_by(hotkey)
+            .created_at(created_at_ms)
+            .build()
+    }
+
+    #[test]
+    fn test_rapid_claims_are_flagged() {
+        let config = SuspicionConfig::default();
+        // 24 issues claimed within two minutes.
+        let audit: Vec<AuditEntry> = (0..4)
+            .map(|i| {
+                let numbers: Vec<u32> = (i * 6..i * 6 + 6).collect();
+                claim("hk-rapid", 1_000 + i64::from(i) * 30_000, &numbers)
+            })
+            .collect();
+        // The same issues, but created days apart.
+        let issues: Vec<IssueRecord> = (0..24)
+            .map(|n| issue(n, "hk-rapid", 1 + i64::from(n) * 24 * 60 * MINUTE))
+            .collect();
+
+        let flagged = suspicious_hotkeys(&audit, &issues, &config);
+        assert!(flagged.contains("hk-rapid"));
+    }
+
+    #[test]
+    fn test_issues_created_together_are_flagged() {
+        let config = SuspicionConfig::default();
+        let issues: Vec<IssueRecord> = (0..5)
+            .map(|n| issue(n, "hk-batch", 1_000 + i64::from(n) * 30_000))
+            .collect();
+
+        assert!(suspicious_hotkeys(&[], &issues, &config).contains("hk-batch"));
+        // Four issues are too few to call a cluster.
+        assert!(suspicious_hotkeys(&[], &issues[..4], &config).is_empty());
+    }
+
+    #[test]
+    fn test_normal_cadence_is_not_flagged() {
+        let config = SuspicionConfig::default();
+        // One claim of two issues every few hours for two days.
+        let audit: Vec<AuditEntry> = (0..12)
+            .map(|i| {
+                claim(
+                    "hk-normal",
+                    i64::from(i) * 4 * 60 * MINUTE,
+                    &[i * 2, i * 2 + 1],
+                )
+            })
+            .collect();
+        let issues: Vec<IssueRecord> = (0..24)
+            .map(|n| issue(n, "hk-normal", 1 + i64::from(n) * 2 * 60 * MINUTE))
+            .collect();
+
+        assert!(suspicious_hotkeys(&audit, &issues, &config).is_empty());
+    }
+
+    #[test]
+    fn test_burst_window_slides() {
+        let config = SuspicionConfig {
+            burst_claims: 3,
+            burst_window_secs: 60,
+            ..SuspicionConfig::default()
+        };
+        assert!(!is_claim_burst(&[(0, 2), (2 * MINUTE, 2)], &config));
+        assert!(is_claim_burst(
+            &[(5 * MINUTE, 1), (0, 2), (5 * MINUTE + 59_000, 2)],
+            &config
+        ));
+        assert!(!SuspicionConfig {
+            burst_claims: 0,
+            ..config
+        }
+        .is_valid());
+    }
+
+    #[test]
+    fn test_stored_flags_refresh_on_the_interval() {
+        crate::storage::host::reset(1);
+        let burst = |at| {
+            let issues: Vec<u32> = (0..20).collect();
+            let detail = serde_json::json!({ "claimed": issues, "rejected": 0 });
+            assert!(crate::storage::append_audit(
+                "hk-burst", "claim", &detail, at
+            ));
+        };
+
+        maybe_refresh_suspicious_hotkeys(1_000);
+        burst(2_000);
+        maybe_refresh_suspicious_hotkeys(2_000);
+        assert!(crate::storage::get_suspicious_hotkeys().is_empty());
+
+        maybe_refresh_suspicious_hotkeys(1_000 + SUSPICION_REFRESH_INTERVAL_MS);
+        assert!(crate::storage::get_suspicious_hotkeys().contains("hk-burst"));
+        assert_eq!(
+            crate::storage::get_suspicion_refreshed_at(),
+            1_000 + SUSPICION_REFRESH_INTERVAL_MS
+        );
+    }
+}
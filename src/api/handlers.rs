@@ -1,15 +1,18 @@
-use alloc::string::ToString;
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use bincode::Options;
 use platform_challenge_sdk_wasm::host_functions::host_consensus_get_submission_count;
 use platform_challenge_sdk_wasm::{WasmRouteRequest, WasmRouteResponse};
 use serde::{Deserialize, Serialize};
 
+use crate::error::BountyError;
 use crate::ss58;
 use crate::types::{
-    BountySubmission, BulkMigrationRequest, ClaimRequest, GitHubUserDetailsResponse,
-    HotkeyDetailsResponse, IssueRecord, IssueShort, IssuesStatsResponse, RegisterRequest,
-    StatsResponse, StatusResponse, UserBalance,
+    BountySubmission, BulkMigrationRequest, ClaimRequest, ClaimResult, ClaimSummary, ExportBundle,
+    GitHubUserDetailsResponse, HotkeyDetailsResponse, IssueClaimStatusResponse, IssueRecord,
+    IssueShort, IssuesStatsResponse, LeaderboardEntry, RankResponse, RegisterRequest,
+    StatsResponse, StatusResponse, UserBalance, WeightBreakdown,
 };
 
 fn to_ss58(hotkey: &str) -> alloc::string::String {
@@ -18,6 +21,7 @@ fn to_ss58(hotkey: &str) -> alloc::string::String {
 use crate::{scoring, storage, validation};
 
 const MAX_ROUTE_BODY_SIZE: usize = 1_048_576;
+const MAX_IDEMPOTENCY_KEY_LEN: usize = 128;
 
 fn bincode_options_route_body() -> impl Options {
     bincode::DefaultOptions::new()
@@ -49,8 +53,12 @@ fn json_error(status: u16, error: &str, message: &str) -> WasmRouteResponse {
     }
 }
 
+fn error_response(err: &BountyError) -> WasmRouteResponse {
+    json_error(err.status(), err.code(), &err.to_string())
+}
+
 fn unauthorized_response() -> WasmRouteResponse {
-    json_error(401, "unauthorized", "Authentication required")
+    error_response(&BountyError::Auth)
 }
 
 fn bad_request_response() -> WasmRouteResponse {
@@ -61,6 +69,64 @@ fn not_found_response() -> WasmRouteResponse {
     json_error(404, "not_found", "Resource not found")
 }
 
+/// 413 for request bodies over `MAX_ROUTE_BODY_SIZE`.
+pub(crate) fn check_body_size(body: &[u8]) -> Option<WasmRouteResponse> {
+    if body.len() <= MAX_ROUTE_BODY_SIZE {
+        return None;
+    }
+    Some(json_error(
+        413,
+        "payload_too_large",
+        &alloc::format!(
+            "Request body is {} bytes; the limit is {} bytes",
+            body.len(),
+            MAX_ROUTE_BODY_SIZE
+        ),
+    ))
+}
+
+fn rate_limited_response(retry_after_secs: u64) -> WasmRouteResponse {
+    WasmRouteResponse {
+        status: 429,
+        body: serde_json::to_vec(&serde_json::json!({
+            "error": "rate_limited",
+            "message": alloc::format!("Too many requests; retry in {}s", retry_after_secs),
+            "retry_after_secs": retry_after_secs
+        }))
+        .unwrap_or_default(),
+    }
+}
+
+/// 429 for a username change inside the relink cooldown, which ends at
+/// `until_ms`.
+fn relink_cooldown_response(until_ms: i64, now_ms: i64) -> WasmRouteResponse {
+    let retry_after_secs = (until_ms.saturating_sub(now_ms) as u64).div_ceil(1000);
+    WasmRouteResponse {
+        status: 429,
+        body: serde_json::to_vec(&serde_json::json!({
+            "error": "relink_cooldown",
+            "message": alloc::format!(
+                "GitHub username was changed recently; retry in {}s",
+                retry_after_secs
+            ),
+            "retry_after_secs": retry_after_secs
+        }))
+        .unwrap_or_default(),
+    }
+}
+
+/// 429 once `hotkey` has used up its `action` requests for the minute.
+fn check_rate_limit(action: &str, hotkey: &str) -> Option<WasmRouteResponse> {
+    let per_minute = storage::get_rate_limit_per_minute();
+    if per_minute == 0 {
+        return None;
+    }
+    let now = platform_challenge_sdk_wasm::host_functions::host_get_timestamp();
+    storage::take_rate_token(action, hotkey, per_minute, now)
+        .err()
+        .map(rate_limited_response)
+}
+
 fn is_authenticated(request: &WasmRouteRequest) -> bool {
     request
         .auth_hotkey
@@ -77,10 +143,106 @@ fn get_param<'a>(request: &'a WasmRouteRequest, name: &str) -> Option<&'a str> {
         .map(|(_, v)| v.as_str())
 }
 
-pub fn handle_leaderboard(_request: &WasmRouteRequest) -> WasmRouteResponse {
+/// Look up a query-string value, e.g. `sort` in `/leaderboard?sort=invalid`.
+fn get_query_param<'a>(request: &'a WasmRouteRequest, name: &str) -> Option<&'a str> {
+    if let Some(v) = get_param(request, name) {
+        return Some(v);
+    }
+    let (_, query) = request.path.split_once('?')?;
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(k, _)| *k == name)
+        .map(|(_, v)| v)
+}
+
+pub fn handle_leaderboard(request: &WasmRouteRequest) -> WasmRouteResponse {
+    let sort = match get_query_param(request, "sort") {
+        None => None,
+        Some(value) => match scoring::LeaderboardSort::parse(value) {
+            Some(sort) => Some(sort),
+            None => {
+                return json_error(
+                    400,
+                    "invalid_sort",
+                    "sort must be one of: score, valid, invalid, stars, net",
+                )
+            }
+        },
+    };
+    let paging = match get_paging(request) {
+        Ok(p) => p,
+        Err(resp) => return resp,
+    };
+
     scoring::maybe_refresh();
-    let entries = scoring::rebuild_leaderboard();
-    json_response(&entries)
+    let mut entries = scoring::rebuild_leaderboard();
+    if let Some(sort) = sort {
+        scoring::sort_leaderboard(&mut entries, sort);
+    }
+    let page: Vec<LeaderboardEntry> = entries
+        .into_iter()
+        .skip(paging.offset)
+        .take(paging.limit)
+        .collect();
+    json_response(&paged_body(&page, &paging))
+}
+
+fn rank_response(entries: &[LeaderboardEntry], hotkey: &str) -> Option<RankResponse> {
+    let entry = entries.iter().find(|e| e.hotkey == hotkey)?;
+    let total_miners = entries.len() as u32;
+    Some(RankResponse {
+        hotkey: entry.hotkey.clone(),
+        rank: entry.rank,
+        score: entry.score,
+        total_miners,
+        percentile: entry.rank as f64 / total_miners as f64 * 100.0,
+    })
+}
+
+/// Rank of a single hotkey, read from the leaderboard cached by the
+/// background tick rather than rebuilding it.
+pub fn handle_leaderboard_rank(request: &WasmRouteRequest) -> WasmRouteResponse {
+    let hotkey = match get_param(request, "hotkey") {
+        Some(h) => h,
+        None => return bad_request_response(),
+    };
+    if storage::get_user_by_hotkey(hotkey).is_none() {
+        return error_response(&BountyError::NotRegistered);
+    }
+    let normalized = ss58::normalize_hotkey(hotkey).unwrap_or_else(|| String::from(hotkey));
+
+    // A miner registered since the last tick is not in the cache yet
+    let rank = rank_response(&storage::get_leaderboard(), &normalized)
+        .or_else(|| rank_response(&scoring::rebuild_leaderboard(), &normalized));
+    match rank {
+        Some(rank) => json_response(&rank),
+        None => not_found_response(),
+    }
+}
+
+/// Biggest net-point gains and losses over `window` days (default 7d),
+/// from the daily leaderboard snapshots.
+pub fn handle_leaderboard_movers(request: &WasmRouteRequest) -> WasmRouteResponse {
+    let window_days = match get_query_param(request, "window") {
+        None => 7,
+        Some(value) => match scoring::parse_window_days(value) {
+            Some(days) => days,
+            None => {
+                return json_error(
+                    400,
+                    "invalid_window",
+                    "window must be a number of days between 1d and 30d",
+                )
+            }
+        },
+    };
+    let snapshots = storage::get_leaderboard_snapshots();
+    json_response(&scoring::compute_movers(
+        &snapshots,
+        window_days,
+        scoring::MAX_MOVERS,
+    ))
 }
 
 pub fn handle_stats(_request: &WasmRouteRequest) -> WasmRouteResponse {
@@ -116,14 +278,20 @@ pub fn handle_status(request: &WasmRouteRequest) -> WasmRouteResponse {
                 valid_issues_count: 0,
                 invalid_issues_count: 0,
                 balance: UserBalance::default(),
+                net_points: 0.0,
                 weight: 0.0,
+                suspicious: false,
+                last_refreshed: storage::get_last_refreshed(),
             };
             return json_response(&status);
         }
     };
 
-    let balance = storage::get_user_balance(hotkey);
+    let config = storage::get_penalty_config();
+    let mut balance = storage::get_user_balance(hotkey);
+    balance.is_penalized = config.is_penalized(&balance);
     let net = scoring::calculate_net_points(
+        &config,
         balance.valid_count,
         balance.invalid_count,
         balance.duplicate_count,
@@ -143,7 +311,10 @@ pub fn handle_status(request: &WasmRouteRequest) -> WasmRouteResponse {
         valid_issues_count: balance.valid_count,
         invalid_issues_count: balance.invalid_count,
         balance,
+        net_points: net,
         weight,
+        suspicious: storage::get_suspicious_hotkeys().contains(&to_ss58(hotkey)),
+        last_refreshed: storage::get_last_refreshed(),
     };
     json_response(&status)
 }
@@ -152,9 +323,6 @@ pub fn handle_register(request: &WasmRouteRequest) -> WasmRouteResponse {
     if !is_authenticated(request) {
         return unauthorized_response();
     }
-    if request.body.len() > MAX_ROUTE_BODY_SIZE {
-        return bad_request_response();
-    }
 
     // Try JSON first, then bincode for backwards compatibility
     let reg: RegisterRequest = match serde_json::from_slice(&request.body) {
@@ -171,6 +339,9 @@ pub fn handle_register(request: &WasmRouteRequest) -> WasmRouteResponse {
 
     // Use authenticated hotkey from headers, or fall back to body hotkey
     let hotkey = request.auth_hotkey.as_deref().unwrap_or(&reg.hotkey);
+    if let Some(resp) = check_rate_limit("register", hotkey) {
+        return resp;
+    }
 
     // Check specific error conditions for better error messages
     let existing_hotkey_for_github = storage::get_hotkey_by_github(&reg.github_username);
@@ -200,9 +371,23 @@ pub fn handle_register(request: &WasmRouteRequest) -> WasmRouteResponse {
         }
     }
 
+    // Registering again after an unregister is a relink.
+    let now = platform_challenge_sdk_wasm::host_functions::host_get_timestamp();
+    if existing_github_for_hotkey.is_none() {
+        if let Some(until) = storage::relink_cooldown_until(hotkey, now) {
+            return relink_cooldown_response(until, now);
+        }
+    }
+
     let result = storage::register_user(&reg.github_username, hotkey);
     if result {
         storage::ensure_hotkey_tracked(hotkey);
+        storage::append_audit(
+            hotkey,
+            "register",
+            &serde_json::json!({ "github_username": reg.github_username }),
+            now,
+        );
         json_response(&serde_json::json!({
             "success": true,
             "message": "Registration successful",
@@ -218,60 +403,225 @@ pub fn handle_register(request: &WasmRouteRequest) -> WasmRouteResponse {
     }
 }
 
-pub fn handle_claim(request: &WasmRouteRequest) -> WasmRouteResponse {
+#[derive(Deserialize)]
+struct UpdateGithubRequest {
+    pub github_username: String,
+}
+
+/// Link the authenticated hotkey to a different GitHub username.
+pub fn handle_register_update(request: &WasmRouteRequest) -> WasmRouteResponse {
     if !is_authenticated(request) {
         return unauthorized_response();
     }
-    if request.body.len() > MAX_ROUTE_BODY_SIZE {
+    let auth_hotkey = match &request.auth_hotkey {
+        Some(h) if !h.is_empty() => h.clone(),
+        _ => return unauthorized_response(),
+    };
+    if let Some(resp) = check_rate_limit("register_update", &auth_hotkey) {
+        return resp;
+    }
+    let req: UpdateGithubRequest = match serde_json::from_slice(&request.body) {
+        Ok(r) => r,
+        Err(_) => return bad_request_response(),
+    };
+    let github_username = req.github_username.trim();
+    if github_username.is_empty() {
         return bad_request_response();
     }
 
+    let now = platform_challenge_sdk_wasm::host_functions::host_get_timestamp();
+    let previous = match storage::update_github(&auth_hotkey, github_username, now) {
+        storage::RelinkOutcome::Relinked(previous) => previous,
+        storage::RelinkOutcome::NotRegistered => {
+            return error_response(&BountyError::NotRegistered)
+        }
+        storage::RelinkOutcome::GithubTaken(owner) => {
+            return json_error(
+                400,
+                "github_already_registered",
+                &alloc::format!(
+                    "GitHub '{}' is already registered to hotkey {}",
+                    github_username,
+                    owner
+                ),
+            )
+        }
+        storage::RelinkOutcome::CoolingDown(until) => return relink_cooldown_response(until, now),
+        storage::RelinkOutcome::StorageError => {
+            return error_response(&BountyError::Storage("update registration"))
+        }
+    };
+    storage::append_audit(
+        &auth_hotkey,
+        "register_update",
+        &serde_json::json!({ "from": previous, "to": github_username }),
+        now,
+    );
+    scoring::rebuild_leaderboard();
+    json_response(&serde_json::json!({
+        "success": true,
+        "hotkey": to_ss58(&auth_hotkey),
+        "previous_github_username": previous,
+        "github_username": github_username
+    }))
+}
+
+/// Unlink the authenticated hotkey from its GitHub username.
+pub fn handle_unregister(request: &WasmRouteRequest) -> WasmRouteResponse {
+    if !is_authenticated(request) {
+        return unauthorized_response();
+    }
+    let auth_hotkey = match &request.auth_hotkey {
+        Some(h) if !h.is_empty() => h.clone(),
+        _ => return unauthorized_response(),
+    };
+    if let Some(resp) = check_rate_limit("unregister", &auth_hotkey) {
+        return resp;
+    }
+    let now = platform_challenge_sdk_wasm::host_functions::host_get_timestamp();
+    let github_username = match storage::unregister_user(&auth_hotkey, now) {
+        Some(g) => g,
+        None => return error_response(&BountyError::NotRegistered),
+    };
+    storage::append_audit(
+        &auth_hotkey,
+        "unregister",
+        &serde_json::json!({ "github_username": github_username }),
+        now,
+    );
+    scoring::rebuild_leaderboard();
+    json_response(&serde_json::json!({
+        "success": true,
+        "hotkey": to_ss58(&auth_hotkey),
+        "github_username": github_username
+    }))
+}
+
+/// A retried request replays the first result instead of claiming again
+/// (which would report "already claimed" and count duplicates). `claim` runs
+/// only when `key` is empty or has no live result stored.
+fn claim_idempotently(
+    hotkey: &str,
+    key: &str,
+    now: i64,
+    claim: impl FnOnce() -> Result<ClaimResult, WasmRouteResponse>,
+) -> Result<ClaimResult, WasmRouteResponse> {
+    if key.is_empty() {
+        return claim();
+    }
+    if let Some(cached) = storage::get_cached_claim(hotkey, key, now) {
+        return Ok(cached);
+    }
+    let result = claim()?;
+    storage::store_cached_claim(hotkey, key, now, &result);
+    Ok(result)
+}
+
+pub fn handle_claim(request: &WasmRouteRequest) -> WasmRouteResponse {
+    if !is_authenticated(request) {
+        return unauthorized_response();
+    }
+
     // Get authenticated hotkey from headers
     let auth_hotkey = match &request.auth_hotkey {
         Some(h) if !h.is_empty() => h.clone(),
         _ => return unauthorized_response(),
     };
 
-    // Try new JSON format first (ClaimRequest with issue_url)
+    // Try new JSON format first (ClaimRequest with issue_url / issue_urls)
     if let Ok(claim_req) = serde_json::from_slice::<ClaimRequest>(&request.body) {
-        // Parse issue URL: https://github.com/{owner}/{repo}/issues/{number}
-        let parts: Vec<&str> = claim_req.issue_url.split('/').collect();
-        if parts.len() < 7 {
-            return bad_request_response();
+        let mut urls = claim_req.issue_urls;
+        if !claim_req.issue_url.is_empty() {
+            urls.push(claim_req.issue_url);
+        }
+        let mut issues = Vec::with_capacity(urls.len());
+        for url in &urls {
+            match validation::parse_issue_url(url) {
+                Some(parsed) => issues.push(parsed),
+                None => return bad_request_response(),
+            }
+        }
+        // Same checks, and messages, as a bincode submission's issue_numbers.
+        let numbers: Vec<u32> = issues.iter().map(|(_, _, number)| *number).collect();
+        let check = validation::check_issue_numbers(&numbers, storage::get_max_claim_batch());
+        if !check.errors.is_empty() {
+            return json_error(400, "bad_request", &check.errors.join("; "));
+        }
+        if claim_req.idempotency_key.len() > MAX_IDEMPOTENCY_KEY_LEN {
+            return json_error(
+                400,
+                "bad_request",
+                "idempotency_key must be at most 128 bytes",
+            );
         }
 
-        let repo_owner = parts[3].to_string();
-        let repo_name = parts[4].to_string();
-        let issue_number: u32 = match parts[6].parse() {
-            Ok(n) => n,
-            Err(_) => return bad_request_response(),
-        };
-
-        // Get user's github username
-        let github_username = match storage::get_user_by_hotkey(&auth_hotkey) {
-            Some(reg) => reg.github_username,
-            None => return unauthorized_response(),
-        };
+        let now = platform_challenge_sdk_wasm::host_functions::host_get_timestamp();
+        let claimed = claim_idempotently(&auth_hotkey, &claim_req.idempotency_key, now, || {
+            if let Some(resp) = check_rate_limit("claim", &auth_hotkey) {
+                return Err(resp);
+            }
 
-        // Create submission from authenticated request
-        let submission = BountySubmission {
-            hotkey: auth_hotkey,
-            github_username,
-            issue_numbers: alloc::vec![issue_number],
-            repo_owner,
-            repo_name,
-            signature: alloc::vec![],
-            timestamp: 0,
-        };
+            // Group issue numbers by repository: each submission targets one repo
+            let mut by_repo: BTreeMap<(String, String), Vec<u32>> = BTreeMap::new();
+            for (repo_owner, repo_name, issue_number) in issues {
+                let numbers = by_repo.entry((repo_owner, repo_name)).or_default();
+                if !numbers.contains(&issue_number) {
+                    numbers.push(issue_number);
+                }
+            }
 
-        let synced_issues = storage::get_synced_issues();
-        let result = validation::process_claims(&submission, &synced_issues);
+            // Get user's github username
+            let github_username = match storage::get_user_by_hotkey(&auth_hotkey) {
+                Some(reg) => reg.github_username,
+                None => return Err(unauthorized_response()),
+            };
 
-        if !result.claimed.is_empty() {
-            scoring::rebuild_leaderboard();
-        }
+            let synced_issues = storage::get_synced_issues();
+            let mut result = ClaimResult {
+                claimed: Vec::new(),
+                rejected: Vec::new(),
+                total_valid: 0,
+                score: 0.0,
+                summary: ClaimSummary::default(),
+                message: String::new(),
+            };
 
-        return json_response(&result);
+            for ((repo_owner, repo_name), issue_numbers) in by_repo {
+                // Create submission from authenticated request
+                let submission = BountySubmission {
+                    hotkey: auth_hotkey.clone(),
+                    github_username: github_username.clone(),
+                    issue_numbers,
+                    repo_owner,
+                    repo_name,
+                    signature: alloc::vec![],
+                    timestamp: 0,
+                };
+
+                let partial = validation::process_claims(&submission, &synced_issues);
+                result.claimed.extend(partial.claimed);
+                result.rejected.extend(partial.rejected);
+                result.summary.newly_claimed += partial.summary.newly_claimed;
+                result.summary.rejected += partial.summary.rejected;
+                result.summary.already_had += partial.summary.already_had;
+            }
+            // Totals cover every repo in the batch, whatever order they ran in.
+            let balance = storage::get_user_balance(&auth_hotkey);
+            result.total_valid = balance.valid_count;
+            result.score =
+                scoring::calculate_weight_from_points(balance.valid_count, balance.star_count);
+            result.message = validation::claim_message(&result);
+
+            if !result.claimed.is_empty() {
+                scoring::rebuild_leaderboard();
+                audit_claims(&auth_hotkey, &result, now);
+            }
+            Ok(result)
+        });
+        return match claimed {
+            Ok(result) => json_response(&result),
+            Err(resp) => resp,
+        };
     }
 
     // Fallback: try legacy bincode format (BountySubmission)
@@ -280,6 +630,9 @@ pub fn handle_claim(request: &WasmRouteRequest) -> WasmRouteResponse {
             Ok(s) => s,
             Err(_) => return bad_request_response(),
         };
+    if let Some(resp) = check_rate_limit("claim", &auth_hotkey) {
+        return resp;
+    }
 
     // Override body-provided identity with authenticated hotkey to prevent impersonation
     submission.hotkey = auth_hotkey.clone();
@@ -289,8 +642,9 @@ pub fn handle_claim(request: &WasmRouteRequest) -> WasmRouteResponse {
         return unauthorized_response();
     }
 
-    if !validation::validate_submission(&submission) {
-        return bad_request_response();
+    let errors = validation::submission_errors(&submission);
+    if !errors.is_empty() {
+        return json_error(400, "bad_request", &errors.join("; "));
     }
 
     let synced_issues = storage::get_synced_issues();
@@ -298,19 +652,273 @@ pub fn handle_claim(request: &WasmRouteRequest) -> WasmRouteResponse {
 
     if !result.claimed.is_empty() {
         scoring::rebuild_leaderboard();
+        audit_claims(
+            &auth_hotkey,
+            &result,
+            platform_challenge_sdk_wasm::host_functions::host_get_timestamp(),
+        );
     }
 
     json_response(&result)
 }
 
-pub fn handle_issues(_request: &WasmRouteRequest) -> WasmRouteResponse {
+fn audit_claims(hotkey: &str, result: &ClaimResult, now_ms: i64) {
+    let claimed: Vec<u32> = result.claimed.iter().map(|c| c.issue_number).collect();
+    storage::append_audit(
+        hotkey,
+        "claim",
+        &serde_json::json!({ "claimed": claimed, "rejected": result.rejected.len() }),
+        now_ms,
+    );
+}
+
+const DEFAULT_AUDIT_LIMIT: usize = 100;
+const MAX_AUDIT_LIMIT: usize = 1000;
+
+/// Recent state-changing requests, newest first. Callers see only their own
+/// entries; the sudo owner sees everyone's, optionally narrowed to one `actor`.
+pub fn handle_audit(request: &WasmRouteRequest) -> WasmRouteResponse {
+    if !is_authenticated(request) {
+        return unauthorized_response();
+    }
+    let auth_hotkey = match request.auth_hotkey.as_deref() {
+        Some(hk) => hk,
+        None => return unauthorized_response(),
+    };
+    let limit = match get_query_param(request, "limit") {
+        None => DEFAULT_AUDIT_LIMIT,
+        Some(v) => match v.parse::<usize>() {
+            Ok(n) => n.min(MAX_AUDIT_LIMIT),
+            Err(_) => {
+                return json_error(400, "bad_request", "limit must be a non-negative integer")
+            }
+        },
+    };
+    let requested = get_query_param(request, "actor").filter(|a| !a.is_empty());
+    let actor = if storage::is_sudo_owner(auth_hotkey) {
+        requested
+    } else {
+        if requested.is_some_and(|a| to_ss58(a) != to_ss58(auth_hotkey)) {
+            return json_error(
+                403,
+                "forbidden",
+                "Only the sudo owner can read other actors' entries",
+            );
+        }
+        Some(auth_hotkey)
+    };
+
+    let entries = storage::recent_audit_entries(&storage::get_audit_log(), actor, limit);
+    let entries: Vec<serde_json::Value> = entries
+        .into_iter()
+        .map(|e| {
+            serde_json::json!({
+                "timestamp_ms": e.timestamp_ms,
+                "epoch": e.epoch,
+                "actor": e.actor,
+                "action": e.action,
+                "detail": serde_json::from_str::<serde_json::Value>(&e.detail)
+                    .unwrap_or(serde_json::Value::Null),
+            })
+        })
+        .collect();
+    json_response(&entries)
+}
+
+/// `state` filter for `GET /issues`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum IssueState {
+    All,
+    Open,
+    Closed,
+    Valid,
+    Invalid,
+}
+
+impl IssueState {
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "all" => Some(Self::All),
+            "open" => Some(Self::Open),
+            "closed" => Some(Self::Closed),
+            "valid" => Some(Self::Valid),
+            "invalid" => Some(Self::Invalid),
+            _ => None,
+        }
+    }
+
+    fn matches(self, issue: &IssueRecord) -> bool {
+        match self {
+            Self::All => true,
+            Self::Open => !issue.is_closed,
+            Self::Closed => issue.is_closed,
+            Self::Valid => issue.has_valid_label,
+            Self::Invalid => issue.has_invalid_label,
+        }
+    }
+}
+
+fn filter_issues(
+    issues: Vec<IssueRecord>,
+    state: IssueState,
+    offset: usize,
+    limit: usize,
+) -> Vec<IssueRecord> {
+    issues
+        .into_iter()
+        .filter(|i| state.matches(i))
+        .skip(offset)
+        .take(limit)
+        .collect()
+}
+
+/// Largest `limit` a listing endpoint honours; larger requests are clamped,
+/// and a request without `limit` gets this many.
+pub const MAX_PAGE_SIZE: usize = 500;
+
+/// `limit`/`offset` paging for a listing endpoint.
+struct Paging {
+    offset: usize,
+    /// The page size applied, at most [`MAX_PAGE_SIZE`].
+    limit: usize,
+    /// The `limit` the client asked for, if any.
+    requested: Option<usize>,
+}
+
+fn parse_paging(offset: Option<&str>, limit: Option<&str>) -> Result<Paging, WasmRouteResponse> {
+    let offset = match offset {
+        None => 0,
+        Some(v) => match v.parse() {
+            Ok(n) => n,
+            Err(_) => {
+                return Err(json_error(
+                    400,
+                    "bad_request",
+                    "offset must be a non-negative integer",
+                ))
+            }
+        },
+    };
+    let requested: Option<usize> = match limit {
+        None => None,
+        Some(v) => match v.parse() {
+            Ok(n) => Some(n),
+            Err(_) => {
+                return Err(json_error(
+                    400,
+                    "bad_request",
+                    "limit must be a non-negative integer",
+                ))
+            }
+        },
+    };
+    Ok(Paging {
+        offset,
+        limit: requested.unwrap_or(MAX_PAGE_SIZE).min(MAX_PAGE_SIZE),
+        requested,
+    })
+}
+
+/// Parse optional `limit` and `offset` query parameters.
+fn get_paging(request: &WasmRouteRequest) -> Result<Paging, WasmRouteResponse> {
+    parse_paging(
+        get_query_param(request, "offset"),
+        get_query_param(request, "limit"),
+    )
+}
+
+/// A page of a listing as `{ items, offset, limit_applied, limit_requested }`,
+/// so the client can tell when its page was cut short.
+fn paged_body<T: Serialize>(items: &[T], paging: &Paging) -> serde_json::Value {
+    serde_json::json!({
+        "items": items,
+        "offset": paging.offset,
+        "limit_applied": paging.limit,
+        "limit_requested": paging.requested,
+    })
+}
+
+pub fn handle_issues(request: &WasmRouteRequest) -> WasmRouteResponse {
+    let state = match get_query_param(request, "state") {
+        None => IssueState::All,
+        Some(v) => match IssueState::parse(v) {
+            Some(s) => s,
+            None => {
+                return json_error(
+                    400,
+                    "bad_request",
+                    "state must be one of: all, open, closed, valid, invalid",
+                )
+            }
+        },
+    };
+    let paging = match get_paging(request) {
+        Ok(p) => p,
+        Err(resp) => return resp,
+    };
+
     let issues = storage::get_synced_issues();
-    json_response(&issues)
+    let page = filter_issues(issues, state, paging.offset, paging.limit);
+    json_response(&paged_body(&page, &paging))
 }
 
-pub fn handle_issues_pending(_request: &WasmRouteRequest) -> WasmRouteResponse {
+pub fn handle_issues_pending(request: &WasmRouteRequest) -> WasmRouteResponse {
+    let paging = match get_paging(request) {
+        Ok(p) => p,
+        Err(resp) => return resp,
+    };
+
     let issues = storage::get_pending_issues();
-    json_response(&issues)
+    let page = filter_issues(issues, IssueState::All, paging.offset, paging.limit);
+    json_response(&paged_body(&page, &paging))
+}
+
+/// Dead-lettered issues: those that failed enough consecutive syncs to be
+/// skipped until reset. The list only grows until the owner resets
+/// entries, so it is paged like the other listings.
+pub fn handle_issues_failed(request: &WasmRouteRequest) -> WasmRouteResponse {
+    let paging = match get_paging(request) {
+        Ok(p) => p,
+        Err(resp) => return resp,
+    };
+
+    let page: Vec<_> = storage::get_dead_letter_issues()
+        .into_iter()
+        .skip(paging.offset)
+        .take(paging.limit)
+        .collect();
+    let mut body = paged_body(&page, &paging);
+    body["max_failures"] = serde_json::json!(storage::MAX_ISSUE_FAILURES);
+    json_response(&body)
+}
+
+pub fn handle_issue_claim_status(request: &WasmRouteRequest) -> WasmRouteResponse {
+    let issue_number: u32 = match get_param(request, "number").and_then(|n| n.parse().ok()) {
+        Some(n) => n,
+        None => return bad_request_response(),
+    };
+
+    let record = storage::get_issue_record(
+        crate::github_sync::GITHUB_REPO_OWNER,
+        crate::github_sync::GITHUB_REPO_NAME,
+        issue_number,
+    );
+
+    let status = match record {
+        Some(r) if r.claimed_by_hotkey.is_some() => IssueClaimStatusResponse {
+            claimed: true,
+            miner_hotkey: r.claimed_by_hotkey.map(|h| to_ss58(&h)),
+            github_username: Some(r.author),
+            validated_epoch: Some(r.recorded_epoch),
+        },
+        _ => IssueClaimStatusResponse {
+            claimed: false,
+            miner_hotkey: None,
+            github_username: None,
+            validated_epoch: None,
+        },
+    };
+    json_response(&status)
 }
 
 pub fn handle_hotkey_details(request: &WasmRouteRequest) -> WasmRouteResponse {
@@ -324,8 +932,10 @@ pub fn handle_hotkey_details(request: &WasmRouteRequest) -> WasmRouteResponse {
         None => return not_found_response(),
     };
 
+    let config = storage::get_penalty_config();
     let balance = storage::get_user_balance(hotkey);
     let net = scoring::calculate_net_points(
+        &config,
         balance.valid_count,
         balance.invalid_count,
         balance.duplicate_count,
@@ -391,7 +1001,24 @@ pub fn handle_hotkey_details(request: &WasmRouteRequest) -> WasmRouteResponse {
     let _ =
         core::fmt::Write::write_fmt(&mut registered_at, format_args!("{}", reg.registered_epoch));
 
-    let is_penalized = balance.is_penalized;
+    let hotkey_ss58 = to_ss58(hotkey);
+    let weights = scoring::calculate_weights_from_leaderboard(&storage::get_leaderboard());
+    let normalized_weight = weights
+        .iter()
+        .find(|w| w.hotkey == hotkey_ss58)
+        .map(|w| w.weight)
+        .unwrap_or(0.0);
+    let weight_breakdown = WeightBreakdown {
+        miners: weights.len() as u32,
+        ..scoring::weight_breakdown(
+            &config,
+            &balance,
+            storage::is_banned(hotkey),
+            normalized_weight,
+        )
+    };
+
+    let is_penalized = config.is_penalized(&balance);
     let details = HotkeyDetailsResponse {
         hotkey: to_ss58(hotkey),
         github_username: reg.github_username,
@@ -406,32 +1033,55 @@ pub fn handle_hotkey_details(request: &WasmRouteRequest) -> WasmRouteResponse {
         is_penalized,
         weight,
         recent_issues: recent,
+        weight_breakdown,
     };
     json_response(&details)
 }
 
 pub fn handle_issues_stats(_request: &WasmRouteRequest) -> WasmRouteResponse {
-    let issues = storage::get_synced_issues();
+    let stats = issues_stats(&storage::get_synced_issues(), &|github| {
+        storage::get_hotkey_by_github(github).is_some()
+    });
+    json_response(&stats)
+}
 
+/// Summarize `issues`; `is_registered` says whether a GitHub username is
+/// linked to a hotkey.
+fn issues_stats(
+    issues: &[IssueRecord],
+    is_registered: &dyn Fn(&str) -> bool,
+) -> IssuesStatsResponse {
     let total = issues.len() as u64;
     let mut open = 0u64;
     let mut closed = 0u64;
     let mut valid = 0u64;
     let mut invalid = 0u64;
     let mut pending = 0u64;
+    let mut credited = 0u64;
+    let mut unclaimed = 0u64;
 
-    for issue in &issues {
+    for issue in issues {
         if issue.is_closed {
             closed += 1;
         } else {
             open += 1;
         }
+        let is_credited = issue
+            .claimed_by_hotkey
+            .as_deref()
+            .is_some_and(|h| !h.is_empty());
+        if is_credited {
+            credited += 1;
+        }
         if issue.has_valid_label
             && !issue.has_invalid_label
             && !issue.has_duplicate_label
             && !issue.has_malicious_label
         {
             valid += 1;
+            if issue.is_closed && !is_credited && !is_registered(&issue.author) {
+                unclaimed += 1;
+            }
         } else if issue.has_invalid_label || issue.has_duplicate_label || issue.has_malicious_label
         {
             invalid += 1;
@@ -440,15 +1090,17 @@ pub fn handle_issues_stats(_request: &WasmRouteRequest) -> WasmRouteResponse {
         }
     }
 
-    let stats = IssuesStatsResponse {
+    IssuesStatsResponse {
         total,
         open,
         closed,
         valid,
         invalid,
         pending,
-    };
-    json_response(&stats)
+        credited,
+        uncredited: total - credited,
+        unclaimed,
+    }
 }
 
 pub fn handle_github_user(request: &WasmRouteRequest) -> WasmRouteResponse {
@@ -532,8 +1184,58 @@ pub fn handle_github_user(request: &WasmRouteRequest) -> WasmRouteResponse {
 
 pub fn handle_get_weights(_request: &WasmRouteRequest) -> WasmRouteResponse {
     let entries = scoring::rebuild_leaderboard();
-    let weights = scoring::calculate_weights_from_leaderboard(&entries);
-    json_response(&weights)
+    let mut weights = scoring::calculate_weights_from_leaderboard(&entries);
+    let emission_scale = scoring::normalize_weights(&mut weights);
+    let epoch = platform_challenge_sdk_wasm::host_functions::host_consensus_get_epoch();
+
+    json_response(&scoring::WeightsExport {
+        epoch: if epoch >= 0 { epoch as u64 } else { 0 },
+        emission_scale,
+        weights,
+    })
+}
+
+pub fn handle_version(_request: &WasmRouteRequest) -> WasmRouteResponse {
+    json_response(&crate::build_info::version_info())
+}
+
+/// Check storage, GitHub and sync freshness. Responds 503 when any check
+/// fails, so orchestrators can gate on the status alone.
+pub fn handle_selftest(_request: &WasmRouteRequest) -> WasmRouteResponse {
+    let report = crate::selftest::current_report();
+    WasmRouteResponse {
+        status: if report.ok { 200 } else { 503 },
+        body: serde_json::to_vec(&report).unwrap_or_default(),
+    }
+}
+
+/// Check a submission (JSON or bincode `BountySubmission`) without claiming.
+/// `?check_github=true` adds warnings when the GitHub user does not exist or
+/// is not registered to the submission's hotkey.
+pub fn handle_validate(request: &WasmRouteRequest) -> WasmRouteResponse {
+    let auth_hotkey = match &request.auth_hotkey {
+        Some(h) if !h.is_empty() => h.clone(),
+        _ => return unauthorized_response(),
+    };
+    if let Some(resp) = check_rate_limit("validate", &auth_hotkey) {
+        return resp;
+    }
+    let submission: BountySubmission = match serde_json::from_slice(&request.body) {
+        Ok(s) => s,
+        Err(_) => match bincode_options_route_body().deserialize(&request.body) {
+            Ok(s) => s,
+            Err(_) => return bad_request_response(),
+        },
+    };
+    let check_github = get_query_param(request, "check_github") == Some("true");
+    json_response(&validation::validation_report(&submission, check_github))
+}
+
+pub fn handle_metrics(_request: &WasmRouteRequest) -> WasmRouteResponse {
+    WasmRouteResponse {
+        status: 200,
+        body: crate::metrics::render().into_bytes(),
+    }
 }
 
 pub fn handle_sudo_bulk_migrate(request: &WasmRouteRequest) -> WasmRouteResponse {
@@ -559,10 +1261,6 @@ pub fn handle_sudo_bulk_migrate(request: &WasmRouteRequest) -> WasmRouteResponse
         );
     }
 
-    if request.body.len() > MAX_ROUTE_BODY_SIZE {
-        return bad_request_response();
-    }
-
     let migration: BulkMigrationRequest = match serde_json::from_slice(&request.body) {
         Ok(m) => m,
         Err(_) => return json_error(400, "bad_request", "Invalid migration request JSON"),
@@ -597,11 +1295,89 @@ pub fn handle_sudo_bulk_migrate(request: &WasmRouteRequest) -> WasmRouteResponse
     }))
 }
 
-#[derive(Debug, Deserialize)]
-struct SudoRegisterRequest {
-    pub hotkey: alloc::string::String,
-    pub github_username: alloc::string::String,
-}
+/// Dump every registered miner with their balance, ban status and current
+/// weight, plus every claimed issue (sudo only).
+pub fn handle_sudo_export(request: &WasmRouteRequest) -> WasmRouteResponse {
+    if !is_authenticated(request) {
+        return unauthorized_response();
+    }
+
+    let auth_hotkey = match &request.auth_hotkey {
+        Some(h) if !h.is_empty() => h.clone(),
+        _ => return unauthorized_response(),
+    };
+
+    if !storage::is_sudo_owner(&auth_hotkey) {
+        return json_error(403, "forbidden", "Only the sudo owner can export data");
+    }
+
+    let entries = scoring::rebuild_leaderboard();
+    let mut weights = scoring::calculate_weights_from_leaderboard(&entries);
+    scoring::normalize_weights(&mut weights);
+    let weights: BTreeMap<String, f64> =
+        weights.into_iter().map(|w| (w.hotkey, w.weight)).collect();
+
+    let mut bundle = ExportBundle {
+        exported_at_ms: platform_challenge_sdk_wasm::host_functions::host_get_timestamp(),
+        ..Default::default()
+    };
+    storage::export_all(
+        |hotkey| weights.get(hotkey).copied().unwrap_or(0.0),
+        |row| bundle.push(row),
+    );
+
+    json_response(&bundle)
+}
+
+/// Restore a `/sudo/export` bundle (sudo only). Refuses to write into a
+/// store that already has registered miners unless `?force=true`.
+pub fn handle_sudo_import(request: &WasmRouteRequest) -> WasmRouteResponse {
+    if !is_authenticated(request) {
+        return unauthorized_response();
+    }
+
+    let auth_hotkey = match &request.auth_hotkey {
+        Some(h) if !h.is_empty() => h.clone(),
+        _ => return unauthorized_response(),
+    };
+
+    if !storage::is_sudo_owner(&auth_hotkey) {
+        return json_error(403, "forbidden", "Only the sudo owner can import data");
+    }
+
+    let bundle: ExportBundle = match serde_json::from_slice(&request.body) {
+        Ok(b) => b,
+        Err(_) => return json_error(400, "bad_request", "Invalid export bundle JSON"),
+    };
+
+    let force = get_query_param(request, "force") == Some("true");
+    if !force && !storage::get_registered_hotkeys().is_empty() {
+        return json_error(
+            409,
+            "not_empty",
+            "Storage already has registered miners; retry with force=true to merge",
+        );
+    }
+
+    let report = storage::import_all(&bundle);
+    if report.miners_imported > 0 || report.bounties_imported > 0 {
+        scoring::rebuild_leaderboard();
+        storage::append_audit(
+            &auth_hotkey,
+            "import",
+            &serde_json::json!(report),
+            platform_challenge_sdk_wasm::host_functions::host_get_timestamp(),
+        );
+    }
+
+    json_response(&report)
+}
+
+#[derive(Debug, Deserialize)]
+struct SudoRegisterRequest {
+    pub hotkey: alloc::string::String,
+    pub github_username: alloc::string::String,
+}
 
 pub fn handle_sudo_register_user(request: &WasmRouteRequest) -> WasmRouteResponse {
     if !is_authenticated(request) {
@@ -657,20 +1433,65 @@ pub fn handle_sudo_sync_github(request: &WasmRouteRequest) -> WasmRouteResponse
         return json_error(403, "forbidden", "Only the sudo owner can trigger sync");
     }
 
-    let github_token: Option<alloc::string::String> =
-        serde_json::from_slice::<serde_json::Value>(&request.body)
-            .ok()
-            .and_then(|v| {
-                v.get("github_token")
-                    .and_then(|t| t.as_str())
-                    .map(alloc::string::String::from)
-            });
+    let body = serde_json::from_slice::<serde_json::Value>(&request.body).ok();
+    // A token in the body overrides the validator's GITHUB_TOKEN
+    let github_token: Option<alloc::string::String> = body
+        .as_ref()
+        .and_then(|v| {
+            v.get("github_token")
+                .and_then(|t| t.as_str())
+                .map(alloc::string::String::from)
+        })
+        .or_else(crate::github_sync::env_github_token);
+    let dry_run = body
+        .as_ref()
+        .and_then(|v| v.get("dry_run"))
+        .and_then(|d| d.as_bool())
+        .unwrap_or(false);
+
+    let stats =
+        crate::github_sync::fetch_and_process_issues_with_options(github_token.as_deref(), dry_run);
 
-    let stats = crate::github_sync::fetch_and_process_issues_with_token(github_token.as_deref());
+    // Dry run: report what would be credited without touching storage
+    if dry_run {
+        return json_response(&serde_json::json!({
+            "success": true,
+            "dry_run": true,
+            "github_auth": if stats.authenticated { "token" } else { "anonymous" },
+            "fetched": stats.fetched,
+            "awarded": stats.awarded,
+            "penalized": stats.penalized,
+            "synced": stats.synced,
+            "skipped": stats.skipped,
+            "failed": stats.failed.len(),
+            "failures": stats.failed,
+            "dead_lettered": stats.dead_lettered,
+            "would_credit": stats.would_credit,
+            "error": stats.last_error
+        }));
+    }
+
+    // Nothing fetched at all: GitHub is unreachable, so don't record a sync
+    if stats.fetched == 0 {
+        if let Some(reason) = &stats.last_error {
+            return error_response(&BountyError::GitHub(reason.clone()));
+        }
+    }
 
     // Update last_refreshed timestamp
     let now = platform_challenge_sdk_wasm::host_functions::host_get_timestamp();
     storage::store_last_refreshed(now);
+    storage::append_audit(
+        &auth_hotkey,
+        "sync_github",
+        &serde_json::json!({
+            "fetched": stats.fetched,
+            "awarded": stats.awarded,
+            "penalized": stats.penalized,
+            "synced": stats.synced,
+        }),
+        now,
+    );
 
     // Verify blob read-back
     let issues_readback = storage::get_synced_issues();
@@ -681,9 +1502,15 @@ pub fn handle_sudo_sync_github(request: &WasmRouteRequest) -> WasmRouteResponse
 
     json_response(&serde_json::json!({
         "success": true,
+        "github_auth": if stats.authenticated { "token" } else { "anonymous" },
         "fetched": stats.fetched,
         "awarded": stats.awarded,
         "penalized": stats.penalized,
+        "synced": stats.synced,
+        "skipped": stats.skipped,
+        "failed": stats.failed.len(),
+        "failures": stats.failed,
+        "dead_lettered": stats.dead_lettered,
         "leaderboard_entries": leaderboard.len(),
         "recount": recount,
         "issues_readback": issues_readback.len(),
@@ -691,6 +1518,45 @@ pub fn handle_sudo_sync_github(request: &WasmRouteRequest) -> WasmRouteResponse
     }))
 }
 
+pub fn handle_sudo_sync_stars(request: &WasmRouteRequest) -> WasmRouteResponse {
+    if !is_authenticated(request) {
+        return unauthorized_response();
+    }
+
+    let auth_hotkey = match &request.auth_hotkey {
+        Some(h) if !h.is_empty() => h.clone(),
+        _ => return unauthorized_response(),
+    };
+
+    if !storage::is_sudo_owner(&auth_hotkey) {
+        return json_error(403, "forbidden", "Only the sudo owner can sync stars");
+    }
+
+    // A token in the body overrides the validator's GITHUB_TOKEN; without
+    // either, GitHub's 60 requests an hour run out partway through.
+    let github_token: Option<alloc::string::String> =
+        serde_json::from_slice::<serde_json::Value>(&request.body)
+            .ok()
+            .and_then(|v| {
+                v.get("github_token")
+                    .and_then(|t| t.as_str())
+                    .map(alloc::string::String::from)
+            })
+            .or_else(crate::github_sync::env_github_token);
+
+    let stats = crate::github_sync::refresh_star_counts(github_token.as_deref());
+    let now = platform_challenge_sdk_wasm::host_functions::host_get_timestamp();
+    storage::store_last_star_refresh(now);
+    let leaderboard = scoring::rebuild_leaderboard();
+
+    json_response(&serde_json::json!({
+        "success": true,
+        "checked": stats.checked,
+        "updated": stats.updated,
+        "leaderboard_entries": leaderboard.len()
+    }))
+}
+
 pub fn handle_sudo_recount(request: &WasmRouteRequest) -> WasmRouteResponse {
     if !is_authenticated(request) {
         return unauthorized_response();
@@ -716,6 +1582,398 @@ struct BanRequest {
     pub hotkey: alloc::string::String,
 }
 
+#[derive(serde::Deserialize)]
+struct FormerRepoNamesRequest {
+    pub names: Vec<alloc::string::String>,
+}
+
+#[derive(serde::Deserialize)]
+struct RateLimitRequest {
+    pub per_minute: u32,
+}
+
+/// Set the requests per minute each hotkey may send to each rate-limited
+/// route; 0 turns rate limiting off. Every validator reads the same stored
+/// value, so they all accept and refuse the same requests.
+pub fn handle_sudo_rate_limit(request: &WasmRouteRequest) -> WasmRouteResponse {
+    if !is_authenticated(request) {
+        return unauthorized_response();
+    }
+    let auth_hotkey = match &request.auth_hotkey {
+        Some(h) if !h.is_empty() => h.clone(),
+        _ => return unauthorized_response(),
+    };
+    if !storage::is_sudo_owner(&auth_hotkey) {
+        return json_error(
+            403,
+            "forbidden",
+            "Only the sudo owner can set the rate limit",
+        );
+    }
+    let req: RateLimitRequest = match serde_json::from_slice(&request.body) {
+        Ok(r) => r,
+        Err(_) => return json_error(400, "bad_request", "Invalid request JSON"),
+    };
+    if !storage::store_rate_limit_per_minute(req.per_minute) {
+        return error_response(&BountyError::Storage("store rate limit"));
+    }
+    json_response(&serde_json::json!({
+        "success": true,
+        "per_minute": req.per_minute
+    }))
+}
+
+#[derive(serde::Deserialize)]
+struct MaxClaimBatchRequest {
+    pub max: u32,
+}
+
+/// Set how many issues one claim may carry.
+pub fn handle_sudo_max_claim_batch(request: &WasmRouteRequest) -> WasmRouteResponse {
+    if !is_authenticated(request) {
+        return unauthorized_response();
+    }
+    let auth_hotkey = match &request.auth_hotkey {
+        Some(h) if !h.is_empty() => h.clone(),
+        _ => return unauthorized_response(),
+    };
+    if !storage::is_sudo_owner(&auth_hotkey) {
+        return json_error(
+            403,
+            "forbidden",
+            "Only the sudo owner can set the claim batch size",
+        );
+    }
+    let req: MaxClaimBatchRequest = match serde_json::from_slice(&request.body) {
+        Ok(r) => r,
+        Err(_) => return json_error(400, "bad_request", "Invalid request JSON"),
+    };
+    if req.max == 0 {
+        return json_error(400, "bad_request", "max must be at least 1");
+    }
+    if !storage::store_max_claim_batch(req.max) {
+        return error_response(&BountyError::Storage("store claim batch size"));
+    }
+    json_response(&serde_json::json!({
+        "success": true,
+        "max": req.max
+    }))
+}
+
+#[derive(serde::Deserialize)]
+struct GithubApiUrlRequest {
+    pub url: alloc::string::String,
+}
+
+/// Set the GitHub REST API root (a GitHub Enterprise instance, or a mock
+/// on a test network). Every validator reads the stored value, so they all
+/// sync the same issues.
+pub fn handle_sudo_github_api_url(request: &WasmRouteRequest) -> WasmRouteResponse {
+    if !is_authenticated(request) {
+        return unauthorized_response();
+    }
+    let auth_hotkey = match &request.auth_hotkey {
+        Some(h) if !h.is_empty() => h.clone(),
+        _ => return unauthorized_response(),
+    };
+    if !storage::is_sudo_owner(&auth_hotkey) {
+        return json_error(
+            403,
+            "forbidden",
+            "Only the sudo owner can set the GitHub API URL",
+        );
+    }
+    let req: GithubApiUrlRequest = match serde_json::from_slice(&request.body) {
+        Ok(r) => r,
+        Err(_) => return json_error(400, "bad_request", "Invalid request JSON"),
+    };
+    let url = req.url.trim().trim_end_matches('/');
+    let host = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"));
+    if host.is_none_or(str::is_empty) {
+        return json_error(400, "bad_request", "url must be an http:// or https:// URL");
+    }
+    if !storage::store_github_api_url(url) {
+        return error_response(&BountyError::Storage("store GitHub API URL"));
+    }
+    json_response(&serde_json::json!({
+        "success": true,
+        "url": storage::get_github_api_url()
+    }))
+}
+
+#[derive(serde::Deserialize)]
+struct SyncDeadlineRequest {
+    pub secs: u32,
+}
+
+/// Set how long one GitHub issue sync may page before it stops.
+pub fn handle_sudo_sync_deadline(request: &WasmRouteRequest) -> WasmRouteResponse {
+    if !is_authenticated(request) {
+        return unauthorized_response();
+    }
+    let auth_hotkey = match &request.auth_hotkey {
+        Some(h) if !h.is_empty() => h.clone(),
+        _ => return unauthorized_response(),
+    };
+    if !storage::is_sudo_owner(&auth_hotkey) {
+        return json_error(
+            403,
+            "forbidden",
+            "Only the sudo owner can set the sync deadline",
+        );
+    }
+    let req: SyncDeadlineRequest = match serde_json::from_slice(&request.body) {
+        Ok(r) => r,
+        Err(_) => return json_error(400, "bad_request", "Invalid request JSON"),
+    };
+    if req.secs == 0 {
+        return json_error(400, "bad_request", "secs must be at least 1");
+    }
+    if !storage::store_sync_deadline_secs(req.secs) {
+        return error_response(&BountyError::Storage("store sync deadline"));
+    }
+    json_response(&serde_json::json!({
+        "success": true,
+        "secs": req.secs
+    }))
+}
+
+#[derive(serde::Deserialize)]
+struct ValidLabelRequest {
+    pub label: alloc::string::String,
+}
+
+pub fn handle_sudo_valid_label(request: &WasmRouteRequest) -> WasmRouteResponse {
+    if !is_authenticated(request) {
+        return unauthorized_response();
+    }
+    let auth_hotkey = match &request.auth_hotkey {
+        Some(h) if !h.is_empty() => h.clone(),
+        _ => return unauthorized_response(),
+    };
+    if !storage::is_sudo_owner(&auth_hotkey) {
+        return json_error(
+            403,
+            "forbidden",
+            "Only the sudo owner can set the valid label",
+        );
+    }
+    let req: ValidLabelRequest = match serde_json::from_slice(&request.body) {
+        Ok(r) => r,
+        Err(_) => return json_error(400, "bad_request", "Invalid request JSON"),
+    };
+    let label = req.label.trim();
+    if label.is_empty() {
+        return json_error(400, "bad_request", "label required");
+    }
+    if !storage::store_valid_label(label) {
+        return error_response(&BountyError::Storage("store valid label"));
+    }
+    json_response(&serde_json::json!({
+        "success": true,
+        "valid_label": storage::get_valid_label()
+    }))
+}
+
+/// Replace the list of names (`owner/name`) the repository was previously
+/// known by; claims on those are credited to the current repository.
+pub fn handle_sudo_former_repo_names(request: &WasmRouteRequest) -> WasmRouteResponse {
+    if !is_authenticated(request) {
+        return unauthorized_response();
+    }
+    let auth_hotkey = match &request.auth_hotkey {
+        Some(h) if !h.is_empty() => h.clone(),
+        _ => return unauthorized_response(),
+    };
+    if !storage::is_sudo_owner(&auth_hotkey) {
+        return json_error(
+            403,
+            "forbidden",
+            "Only the sudo owner can set former repository names",
+        );
+    }
+    let req: FormerRepoNamesRequest = match serde_json::from_slice(&request.body) {
+        Ok(r) => r,
+        Err(_) => return json_error(400, "bad_request", "Invalid request JSON"),
+    };
+    let names: Vec<String> = req.names.iter().map(|n| n.trim().to_lowercase()).collect();
+    if names.iter().any(|n| !is_repo_full_name(n)) {
+        return json_error(400, "bad_request", "names must be of the form owner/name");
+    }
+    if !storage::store_former_repo_names(&names) {
+        return error_response(&BountyError::Storage("store former repository names"));
+    }
+    json_response(&serde_json::json!({
+        "success": true,
+        "former_repo_names": storage::get_former_repo_names()
+    }))
+}
+
+fn is_repo_full_name(name: &str) -> bool {
+    let mut parts = name.split('/');
+    let valid = |p: Option<&str>| {
+        p.is_some_and(|p| {
+            !p.is_empty()
+                && p.chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        })
+    };
+    valid(parts.next()) && valid(parts.next()) && parts.next().is_none()
+}
+
+/// Set the invalid/duplicate penalty weights and penalty threshold.
+pub fn handle_sudo_penalty_config(request: &WasmRouteRequest) -> WasmRouteResponse {
+    if !is_authenticated(request) {
+        return unauthorized_response();
+    }
+    let auth_hotkey = match &request.auth_hotkey {
+        Some(h) if !h.is_empty() => h.clone(),
+        _ => return unauthorized_response(),
+    };
+    if !storage::is_sudo_owner(&auth_hotkey) {
+        return json_error(
+            403,
+            "forbidden",
+            "Only the sudo owner can set the penalty config",
+        );
+    }
+    let config: scoring::PenaltyConfig = match serde_json::from_slice(&request.body) {
+        Ok(c) => c,
+        Err(_) => return json_error(400, "bad_request", "Invalid request JSON"),
+    };
+    if !config.is_valid() {
+        return json_error(
+            400,
+            "bad_request",
+            "penalty weights must be finite and >= 0, and the threshold finite",
+        );
+    }
+    if !storage::store_penalty_config(&config) {
+        return error_response(&BountyError::Storage("store penalty config"));
+    }
+    scoring::rebuild_leaderboard();
+    json_response(&serde_json::json!({
+        "success": true,
+        "penalty_config": config
+    }))
+}
+
+/// Set the thresholds of the suspicious-claim heuristics.
+pub fn handle_sudo_suspicion_config(request: &WasmRouteRequest) -> WasmRouteResponse {
+    if !is_authenticated(request) {
+        return unauthorized_response();
+    }
+    let auth_hotkey = match &request.auth_hotkey {
+        Some(h) if !h.is_empty() => h.clone(),
+        _ => return unauthorized_response(),
+    };
+    if !storage::is_sudo_owner(&auth_hotkey) {
+        return json_error(
+            403,
+            "forbidden",
+            "Only the sudo owner can set the suspicion config",
+        );
+    }
+    let config: crate::abuse::SuspicionConfig = match serde_json::from_slice(&request.body) {
+        Ok(c) => c,
+        Err(_) => return json_error(400, "bad_request", "Invalid request JSON"),
+    };
+    if !config.is_valid() {
+        return json_error(
+            400,
+            "bad_request",
+            "thresholds and windows must be greater than 0",
+        );
+    }
+    if !storage::store_suspicion_config(&config) {
+        return error_response(&BountyError::Storage("store suspicion config"));
+    }
+    crate::abuse::refresh_suspicious_hotkeys(
+        platform_challenge_sdk_wasm::host_functions::host_get_timestamp(),
+    );
+    scoring::rebuild_leaderboard();
+    json_response(&serde_json::json!({
+        "success": true,
+        "suspicion_config": config
+    }))
+}
+
+/// Replace the conditions a synced issue must meet before it can be claimed.
+pub fn handle_sudo_verification_policy(request: &WasmRouteRequest) -> WasmRouteResponse {
+    if !is_authenticated(request) {
+        return unauthorized_response();
+    }
+    let auth_hotkey = match &request.auth_hotkey {
+        Some(h) if !h.is_empty() => h.clone(),
+        _ => return unauthorized_response(),
+    };
+    if !storage::is_sudo_owner(&auth_hotkey) {
+        return json_error(
+            403,
+            "forbidden",
+            "Only the sudo owner can set the verification policy",
+        );
+    }
+    let policy: validation::VerificationPolicy = match serde_json::from_slice(&request.body) {
+        Ok(p) => p,
+        Err(_) => return json_error(400, "bad_request", "Invalid request JSON"),
+    };
+    let policy = policy.normalized();
+    if !storage::store_verification_policy(&policy) {
+        return error_response(&BountyError::Storage("store verification policy"));
+    }
+    json_response(&serde_json::json!({
+        "success": true,
+        "verification_policy": policy
+    }))
+}
+
+#[derive(serde::Deserialize)]
+struct ResetIssueFailuresRequest {
+    pub issue_number: u32,
+}
+
+/// Clear an issue's failure count so the next sync scans it again.
+pub fn handle_sudo_reset_issue_failures(request: &WasmRouteRequest) -> WasmRouteResponse {
+    if !is_authenticated(request) {
+        return unauthorized_response();
+    }
+    let auth_hotkey = match &request.auth_hotkey {
+        Some(h) if !h.is_empty() => h.clone(),
+        _ => return unauthorized_response(),
+    };
+    if !storage::is_sudo_owner(&auth_hotkey) {
+        return json_error(
+            403,
+            "forbidden",
+            "Only the sudo owner can reset issue failures",
+        );
+    }
+    let req: ResetIssueFailuresRequest = match serde_json::from_slice(&request.body) {
+        Ok(r) => r,
+        Err(_) => return json_error(400, "bad_request", "Invalid request JSON"),
+    };
+    if !storage::reset_issue_failures(req.issue_number) {
+        return json_error(
+            404,
+            "not_found",
+            &alloc::format!("Issue #{} has no recorded failures", req.issue_number),
+        );
+    }
+    storage::append_audit(
+        &auth_hotkey,
+        "reset_issue_failures",
+        &serde_json::json!({ "issue_number": req.issue_number }),
+        platform_challenge_sdk_wasm::host_functions::host_get_timestamp(),
+    );
+    json_response(&serde_json::json!({
+        "success": true,
+        "issue_number": req.issue_number
+    }))
+}
+
 pub fn handle_sudo_ban_user(request: &WasmRouteRequest) -> WasmRouteResponse {
     if !is_authenticated(request) {
         return unauthorized_response();
@@ -767,3 +2025,210 @@ pub fn handle_sudo_unban_user(request: &WasmRouteRequest) -> WasmRouteResponse {
         "unbanned": req.hotkey
     }))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::fixtures::IssueBuilder;
+
+    fn issue(issue_number: u32, valid: bool, invalid: bool, closed: bool) -> IssueRecord {
+        let mut labels = alloc::vec!["ide"];
+        if valid {
+            labels.push("valid");
+        }
+        if invalid {
+            labels.push("invalid");
+        }
+        let issue = IssueBuilder::new(issue_number).labels(&labels);
+        let issue = if closed { issue } else { issue.open() };
+        issue.build()
+    }
+
+    fn numbers(issues: &[IssueRecord]) -> Vec<u32> {
+        issues.iter().map(|i| i.issue_number).collect()
+    }
+
+    #[test]
+    fn test_filter_issues_by_valid_state() {
+        let issues = alloc::vec![
+            issue(1, true, false, true),
+            issue(2, false, true, true),
+            issue(3, true, false, false),
+            issue(4, false, false, false),
+        ];
+
+        let valid = filter_issues(issues.clone(), IssueState::Valid, 0, MAX_PAGE_SIZE);
+        assert_eq!(numbers(&valid), [1, 3]);
+
+        let open = filter_issues(issues, IssueState::Open, 0, MAX_PAGE_SIZE);
+        assert_eq!(numbers(&open), [3, 4]);
+    }
+
+    #[test]
+    fn test_filter_issues_limit_and_offset() {
+        let issues: Vec<IssueRecord> = (1..=10).map(|n| issue(n, true, false, true)).collect();
+
+        let page = filter_issues(issues.clone(), IssueState::All, 0, 3);
+        assert_eq!(numbers(&page), [1, 2, 3]);
+
+        let page = filter_issues(issues, IssueState::Valid, 8, 5);
+        assert_eq!(numbers(&page), [9, 10]);
+        assert_eq!(IssueState::parse("merged"), None);
+    }
+
+    #[test]
+    fn test_issues_stats() {
+        let by = |mut record: IssueRecord, author: &str, hotkey: Option<&str>| {
+            record.author = String::from(author);
+            record.claimed_by_hotkey = hotkey.map(String::from);
+            record
+        };
+        let issues = alloc::vec![
+            // Claimed.
+            by(issue(1, true, false, true), "alice", Some("hk-alice")),
+            // Valid, author registered but not yet claimed.
+            by(issue(2, true, false, true), "alice", None),
+            // Valid, author never registered: an unclaimed bounty.
+            by(issue(3, true, false, true), "carol", None),
+            // Still open, so not claimable yet.
+            by(issue(4, true, false, false), "carol", None),
+            by(issue(5, false, true, true), "bob", Some("hk-bob")),
+            by(issue(6, false, false, false), "carol", None),
+        ];
+
+        let stats = issues_stats(&issues, &|github| github != "carol");
+        assert_eq!((stats.total, stats.open, stats.closed), (6, 2, 4));
+        assert_eq!((stats.valid, stats.invalid, stats.pending), (4, 1, 1));
+        assert_eq!((stats.credited, stats.uncredited), (2, 4));
+        assert_eq!(stats.unclaimed, 1);
+    }
+
+    #[test]
+    fn test_paging_clamps_over_max_limit() {
+        let issues: Vec<IssueRecord> = (1..=3).map(|n| issue(n, true, false, true)).collect();
+
+        let paging = parse_paging(None, Some("1000000")).ok().unwrap();
+        assert_eq!(paging.limit, MAX_PAGE_SIZE);
+        let body = paged_body(&issues, &paging);
+        assert_eq!(body["limit_applied"], MAX_PAGE_SIZE);
+        assert_eq!(body["limit_requested"], 1_000_000);
+        assert_eq!(body["items"].as_array().map(Vec::len), Some(3));
+
+        // Every page has the same shape, whether or not a limit was given.
+        let paging = parse_paging(Some("1"), Some("20")).ok().unwrap();
+        assert_eq!((paging.offset, paging.limit), (1, 20));
+        let body = paged_body(&issues, &paging);
+        assert_eq!(body["offset"], 1);
+        assert_eq!(body["limit_applied"], 20);
+
+        let paging = parse_paging(None, None).ok().unwrap();
+        assert_eq!(paging.limit, MAX_PAGE_SIZE);
+        let body = paged_body(&issues, &paging);
+        assert_eq!(body["limit_applied"], MAX_PAGE_SIZE);
+        assert!(body["limit_requested"].is_null());
+        assert_eq!(body["items"].as_array().map(Vec::len), Some(3));
+        assert!(parse_paging(None, Some("-1")).is_err());
+    }
+
+    fn entry(rank: u32, hotkey: &str, score: f64) -> LeaderboardEntry {
+        LeaderboardEntry {
+            rank,
+            hotkey: String::from(hotkey),
+            github_username: String::new(),
+            score,
+            valid_issues: 0,
+            invalid_issues: 0,
+            pending_issues: 0,
+            star_count: 0,
+            star_bonus: 0.0,
+            net_points: 0.0,
+            is_penalized: false,
+            last_epoch: 0,
+            duplicate_issues: 0,
+            malicious_issues: 0,
+            suspicious: false,
+        }
+    }
+
+    #[test]
+    fn test_is_repo_full_name() {
+        assert!(is_repo_full_name("cortexlm/bounty-challenge"));
+        assert!(is_repo_full_name("some_org/repo.rs"));
+        for bad in ["", "bounty-challenge", "a/", "/b", "a/b/c", "a b/c"] {
+            assert!(!is_repo_full_name(bad), "{}", bad);
+        }
+    }
+
+    #[test]
+    fn test_rank_response() {
+        let entries: Vec<LeaderboardEntry> = ["a", "b", "c", "d"]
+            .iter()
+            .enumerate()
+            .map(|(i, h)| entry(i as u32 + 1, h, 1.0 / (i as f64 + 1.0)))
+            .collect();
+
+        let rank = rank_response(&entries, "b").unwrap();
+        assert_eq!(rank.rank, 2);
+        assert_eq!(rank.total_miners, 4);
+        assert_eq!(rank.score, 0.5);
+        assert_eq!(rank.percentile, 50.0);
+
+        assert_eq!(rank_response(&entries, "a").unwrap().percentile, 25.0);
+        assert!(rank_response(&entries, "z").is_none());
+    }
+
+    #[test]
+    fn test_oversized_body_is_rejected_with_413() {
+        assert!(check_body_size(&alloc::vec![0u8; MAX_ROUTE_BODY_SIZE]).is_none());
+
+        let resp = check_body_size(&alloc::vec![0u8; MAX_ROUTE_BODY_SIZE + 1]).unwrap();
+        assert_eq!(resp.status, 413);
+        let body: serde_json::Value = serde_json::from_slice(&resp.body).unwrap();
+        assert_eq!(body["error"], "payload_too_large");
+        assert!(body["message"].as_str().unwrap().contains("1048576"));
+    }
+
+    #[test]
+    fn test_rate_limited_response_is_429() {
+        let resp = rate_limited_response(20);
+        assert_eq!(resp.status, 429);
+        let body: serde_json::Value = serde_json::from_slice(&resp.body).unwrap();
+        assert_eq!(body["error"], "rate_limited");
+        assert_eq!(body["retry_after_secs"], 20);
+    }
+
+    #[test]
+    fn test_claim_replay_returns_first_result_without_writing() {
+        use crate::storage::host::{host_storage_list_prefix, reset};
+
+        reset(1);
+        let synced = [IssueBuilder::new(7).build()];
+        let submission = BountySubmission {
+            hotkey: String::from("miner-a"),
+            github_username: String::from("alice"),
+            issue_numbers: alloc::vec![7],
+            repo_owner: String::from("PlatformNetwork"),
+            repo_name: String::from("bounty-challenge"),
+            signature: alloc::vec![],
+            timestamp: 0,
+        };
+        let mut runs = 0;
+        let mut claim = || {
+            let result = claim_idempotently("miner-a", "retry-1", 1_000, || {
+                runs += 1;
+                Ok(validation::process_claims(&submission, &synced))
+            });
+            serde_json::to_string(&result.ok().unwrap()).unwrap()
+        };
+
+        let first = claim();
+        let after_first = host_storage_list_prefix(b"", u32::MAX).unwrap();
+        let replay = claim();
+        assert_eq!(replay, first);
+        assert_eq!(
+            host_storage_list_prefix(b"", u32::MAX).unwrap(),
+            after_first
+        );
+        assert_eq!(runs, 1);
+    }
+}
@@ -0,0 +1,56 @@
+//! Build metadata captured by `build.rs`, reported by `GET /version` to
+//! identify the exact build a validator runs.
+
+use serde::Serialize;
+
+/// Short git commit hash, or "unknown" when built outside a checkout.
+pub const GIT_COMMIT: &str = env!("BOUNTY_GIT_COMMIT");
+/// `BUILD_TIMESTAMP` or `SOURCE_DATE_EPOCH` (Unix seconds), or "unknown".
+pub const BUILD_TIMESTAMP: &str = env!("BOUNTY_BUILD_TIMESTAMP");
+/// `rustc --version` of the compiler that built the module.
+pub const RUSTC_VERSION: &str = env!("BOUNTY_RUSTC_VERSION");
+
+/// `GET /version` body.
+#[derive(Clone, Debug, Serialize)]
+pub struct VersionInfo {
+    pub name: &'static str,
+    pub version: &'static str,
+    pub git_commit: &'static str,
+    pub build_timestamp: &'static str,
+    pub rustc_version: &'static str,
+}
+
+pub fn version_info() -> VersionInfo {
+    VersionInfo {
+        name: "bounty-challenge",
+        version: crate::version::VERSION,
+        git_commit: GIT_COMMIT,
+        build_timestamp: BUILD_TIMESTAMP,
+        rustc_version: RUSTC_VERSION,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_version_info_reports_compiled_in_build() {
+        let info = version_info();
+        assert_eq!(info.version, crate::version::VERSION);
+        assert!(!info.git_commit.is_empty());
+        assert!(!info.build_timestamp.is_empty());
+        assert!(!info.rustc_version.is_empty());
+
+        let body = serde_json::to_value(&info).unwrap();
+        for key in [
+            "name",
+            "version",
+            "git_commit",
+            "build_timestamp",
+            "rustc_version",
+        ] {
+            assert!(body.get(key).is_some(), "{}", key);
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Error categories shared by the route handlers, each with the HTTP status
+//! and `error` code it is reported with.
+
+use alloc::string::String;
+use core::fmt;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum BountyError {
+    /// A storage write failed; the message says what was being stored.
+    Storage(&'static str),
+    /// GitHub could not be reached or answered with an error.
+    GitHub(String),
+    /// The request carries no authenticated hotkey.
+    Auth,
+    /// The hotkey has no GitHub registration.
+    NotRegistered,
+    /// The validator's configuration is missing or invalid.
+    Config(String),
+}
+
+impl BountyError {
+    pub fn status(&self) -> u16 {
+        match self {
+            Self::Storage(_) | Self::Config(_) => 500,
+            Self::GitHub(_) => 502,
+            Self::Auth => 401,
+            Self::NotRegistered => 404,
+        }
+    }
+
+    /// Machine-readable `error` field of the response body.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Storage(_) => "storage_error",
+            Self::GitHub(_) => "github_error",
+            Self::Auth => "unauthorized",
+            Self::NotRegistered => "not_registered",
+            Self::Config(_) => "config_error",
+        }
+    }
+}
+
+impl fmt::Display for BountyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Storage(what) => write!(f, "Failed to {}", what),
+            Self::GitHub(reason) => write!(f, "GitHub request failed: {}", reason),
+            Self::Auth => f.write_str("Authentication required"),
+            Self::NotRegistered => f.write_str("Hotkey is not registered"),
+            Self::Config(reason) => write!(f, "Invalid configuration: {}", reason),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_variants_map_to_http_status() {
+        let cases = [
+            (
+                BountyError::Storage("store penalty config"),
+                500,
+                "storage_error",
+            ),
+            (
+                BountyError::GitHub(String::from("HTTP 503")),
+                502,
+                "github_error",
+            ),
+            (BountyError::Auth, 401, "unauthorized"),
+            (BountyError::NotRegistered, 404, "not_registered"),
+            (
+                BountyError::Config(String::from("bad seed")),
+                500,
+                "config_error",
+            ),
+        ];
+        for (err, status, code) in cases {
+            assert_eq!(err.status(), status, "{:?}", err);
+            assert_eq!(err.code(), code, "{:?}", err);
+        }
+        assert_eq!(
+            BountyError::Storage("store penalty config").to_string(),
+            "Failed to store penalty config"
+        );
+        assert_eq!(
+            BountyError::GitHub(String::from("page 1: HTTP 503")).to_string(),
+            "GitHub request failed: page 1: HTTP 503"
+        );
+    }
+}
@@ -5,11 +5,30 @@ use serde::{Deserialize, Serialize};
 
 use crate::storage;
 
-const GITHUB_REPO_OWNER: &str = "PlatformNetwork";
-const GITHUB_REPO_NAME: &str = "bounty-challenge";
+pub(crate) const GITHUB_REPO_OWNER: &str = "PlatformNetwork";
+pub(crate) const GITHUB_REPO_NAME: &str = "bounty-challenge";
+/// GitHub REST API root, unless the sudo owner sets another with
+/// `/sudo/github_api_url`.
+pub(crate) const DEFAULT_GITHUB_API_URL: &str = "https://api.github.com";
 const MAX_PAGES: u32 = 500;
 const ISSUES_PER_PAGE: usize = 100;
 const SECONDS_24H: i64 = 86_400;
+const MAX_STAR_PAGES: u32 = 50;
+/// Redirects followed per request. GitHub answers 301 with the canonical
+/// URL for renamed or transferred repositories.
+const MAX_REDIRECTS: u32 = 3;
+/// Time budget of one issue sync unless the sudo owner sets another with
+/// `/sudo/sync_deadline`.
+pub(crate) const DEFAULT_SYNC_DEADLINE_SECS: u32 = 120;
+
+/// Repositories that earn the star bonus, as lowercase `owner/name`.
+const STAR_BONUS_REPOS: &[&str] = &[
+    "cortexlm/vgrep",
+    "cortexlm/cortex",
+    "platformnetwork/platform",
+    "platformnetwork/term-challenge",
+    "platformnetwork/bounty-challenge",
+];
 
 #[derive(Serialize, Deserialize)]
 struct HttpGetRequest {
@@ -20,11 +39,17 @@ struct HttpGetRequest {
 #[derive(Deserialize)]
 struct HttpResponse {
     pub status: u16,
-    #[allow(dead_code)]
     pub headers: BTreeMap<String, String>,
     pub body: Vec<u8>,
 }
 
+/// One page of a paginated API response.
+struct Page {
+    body: Vec<u8>,
+    /// `rel="next"` URL from the `Link` header, if there are more pages.
+    next: Option<String>,
+}
+
 #[derive(Deserialize)]
 struct GitHubIssue {
     pub number: u32,
@@ -32,6 +57,10 @@ struct GitHubIssue {
     pub labels: Vec<GitHubLabel>,
     pub state: String,
     pub created_at: Option<String>,
+    #[serde(default)]
+    pub assignee: Option<GitHubUser>,
+    #[serde(default)]
+    pub milestone: Option<GitHubMilestone>,
 }
 
 #[derive(Deserialize)]
@@ -44,14 +73,130 @@ struct GitHubLabel {
     pub name: String,
 }
 
+#[derive(Deserialize)]
+struct GitHubMilestone {
+    pub title: String,
+}
+
+/// An issue that would be credited by a sync, reported in dry-run mode.
+#[derive(Clone, Debug, Serialize)]
+pub struct PendingCredit {
+    pub issue_number: u32,
+    pub github_username: String,
+    pub hotkey: Option<String>,
+}
+
+/// An issue entry the sync could not process.
+#[derive(Clone, Debug, Serialize)]
+pub struct FailedIssue {
+    /// `None` when the entry was too malformed to read its number.
+    pub issue_number: Option<u32>,
+    pub reason: String,
+}
+
+#[derive(Deserialize)]
+struct GitHubRepo {
+    pub full_name: String,
+}
+
+pub struct StarSyncStats {
+    pub checked: u32,
+    pub updated: u32,
+}
+
 pub struct SyncStats {
     pub fetched: u32,
     pub awarded: u32,
     pub penalized: u32,
     pub last_error: Option<String>,
+    /// Populated only in dry-run mode.
+    pub would_credit: Vec<PendingCredit>,
+    /// Tracked issues that were new or changed since the last sync.
+    pub synced: u32,
+    /// Tracked issues identical to the stored record.
+    pub skipped: u32,
+    pub failed: Vec<FailedIssue>,
+    /// Dead-lettered issues skipped without being parsed.
+    pub dead_lettered: u32,
+    /// Whether requests carried a GitHub token (5000 req/hr instead of 60).
+    pub authenticated: bool,
 }
 
-fn http_get(url: &str, github_token: Option<&str>) -> Option<Vec<u8>> {
+/// Parse one page of the issues API. Entries that don't match the expected
+/// shape are recorded in `failed` and skipped, so one bad issue doesn't drop
+/// the whole page; entries numbered in `dead_letter` are skipped silently.
+/// Returns the issues and the raw entry count (for paging), or `None` if the
+/// body isn't a JSON array at all.
+fn parse_issue_page(
+    body: &[u8],
+    dead_letter: &[u32],
+    failed: &mut Vec<FailedIssue>,
+) -> Option<(Vec<GitHubIssue>, usize)> {
+    let entries: Vec<serde_json::Value> = serde_json::from_slice(body).ok()?;
+    let count = entries.len();
+    let mut issues = Vec::with_capacity(count);
+
+    for entry in entries {
+        let issue_number = entry
+            .get("number")
+            .and_then(|n| n.as_u64())
+            .and_then(|n| u32::try_from(n).ok());
+        if issue_number.is_some_and(|n| dead_letter.contains(&n)) {
+            continue;
+        }
+        match serde_json::from_value::<GitHubIssue>(entry) {
+            Ok(issue) => issues.push(issue),
+            Err(e) => failed.push(FailedIssue {
+                issue_number,
+                reason: alloc::format!("{}", e),
+            }),
+        }
+    }
+
+    Some((issues, count))
+}
+
+/// Whether a freshly built record carries the same state as the stored one.
+fn is_unchanged(fresh: &crate::types::IssueRecord, stored: &crate::types::IssueRecord) -> bool {
+    fresh.author == stored.author
+        && fresh.is_closed == stored.is_closed
+        && fresh.has_valid_label == stored.has_valid_label
+        && fresh.has_invalid_label == stored.has_invalid_label
+        && fresh.has_duplicate_label == stored.has_duplicate_label
+        && fresh.has_malicious_label == stored.has_malicious_label
+        && fresh.claimed_by_hotkey == stored.claimed_by_hotkey
+        && fresh.labels == stored.labels
+        && fresh.assignee == stored.assignee
+        && fresh.milestone == stored.milestone
+}
+
+/// The hotkey a synced issue counts towards: the one it was bound to when
+/// first synced, or else whoever its author is linked to now. Keeping the
+/// first binding stops an author from shedding penalties by relinking or
+/// unregistering their username.
+fn bound_hotkey<F>(
+    record: &crate::types::IssueRecord,
+    stored: Option<&crate::types::IssueRecord>,
+    hotkey_by_github: F,
+) -> Option<String>
+where
+    F: FnOnce(&str) -> Option<String>,
+{
+    stored
+        .and_then(|e| e.claimed_by_hotkey.clone())
+        .filter(|h| !h.is_empty())
+        .or_else(|| hotkey_by_github(&record.author))
+}
+
+/// `GITHUB_TOKEN` from the validator environment, if set and non-empty.
+pub fn env_github_token() -> Option<String> {
+    platform_challenge_sdk_wasm::host_functions::host_env_get("GITHUB_TOKEN")
+        .and_then(|b| String::from_utf8(b).ok())
+        .map(|t| String::from(t.trim()))
+        .filter(|t| !t.is_empty())
+}
+
+fn request_headers(github_token: Option<&str>) -> BTreeMap<String, String> {
     let mut headers = BTreeMap::new();
     headers.insert(
         String::from("Accept"),
@@ -67,10 +212,189 @@ fn http_get(url: &str, github_token: Option<&str>) -> Option<Vec<u8>> {
             alloc::format!("Bearer {}", token),
         );
     }
+    headers
+}
+
+/// The `rel="next"` target of a GitHub `Link` header, e.g.
+/// `<https://api.github.com/...&page=2>; rel="next", <...>; rel="last"`.
+fn parse_next_link(link: &str) -> Option<String> {
+    link.split(',').find_map(|part| {
+        let (target, params) = part.split_once(';')?;
+        let is_next = params
+            .split(';')
+            .any(|p| p.trim().eq_ignore_ascii_case("rel=\"next\""));
+        let target = target.trim().strip_prefix('<')?.strip_suffix('>')?;
+        is_next.then(|| String::from(target))
+    })
+}
+
+fn header<'a>(resp: &'a HttpResponse, name: &str) -> Option<&'a str> {
+    resp.headers
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(name))
+        .map(|(_, v)| v.as_str())
+}
+
+/// Whether `url` is on the GitHub API at `api_url`, the only place the
+/// token is sent.
+fn is_api_url(url: &str, api_url: &str) -> bool {
+    url.strip_prefix(api_url)
+        .is_some_and(|rest| rest.starts_with('/'))
+}
+
+/// Issue `get` for `url`, following up to `MAX_REDIRECTS` redirects. Only
+/// redirects back to the GitHub API at `api_url` are followed, so the token
+/// is never sent elsewhere.
+fn follow_redirects<F>(url: &str, api_url: &str, mut get: F) -> Result<HttpResponse, String>
+where
+    F: FnMut(&str) -> Result<HttpResponse, String>,
+{
+    let mut url = String::from(url);
+    for _ in 0..=MAX_REDIRECTS {
+        let resp = get(&url)?;
+        if !matches!(resp.status, 301 | 302 | 307 | 308) {
+            return Ok(resp);
+        }
+        url = match header(&resp, "location") {
+            Some(location) if is_api_url(location, api_url) => String::from(location),
+            Some(location) => {
+                return Err(alloc::format!(
+                    "HTTP {} redirect to {} refused",
+                    resp.status,
+                    location
+                ))
+            }
+            None => return Err(alloc::format!("HTTP {} without Location", resp.status)),
+        };
+    }
+    Err(String::from("too many redirects"))
+}
+
+fn http_get_response(url: &str, github_token: Option<&str>) -> Result<HttpResponse, String> {
+    let req = HttpGetRequest {
+        url: String::from(url),
+        headers: request_headers(github_token),
+    };
+    let req_bytes = bincode::serialize(&req).map_err(|_| String::from("request encode failed"))?;
+    let resp_bytes = platform_challenge_sdk_wasm::host_functions::host_http_get(&req_bytes)
+        .map_err(|_| String::from("HTTP request failed"))?;
+    let result: Result<HttpResponse, String> = bincode::deserialize(&resp_bytes)
+        .map_err(|_| String::from("HTTP response decode failed"))?;
+    result
+}
+
+/// `Retry-After` seconds of a secondary (abuse) rate limit: a 403 or 429
+/// carrying `Retry-After`. A 403 without it is a genuine refusal (private
+/// repository, bad token) and is not retried.
+fn secondary_retry_after(resp: &HttpResponse) -> Option<u64> {
+    if !matches!(resp.status, 403 | 429) {
+        return None;
+    }
+    header(resp, "retry-after")?.trim().parse().ok()
+}
+
+/// Fail a response that is a secondary rate limit with an error naming its
+/// `Retry-After`. The module cannot sleep, so the sync stops and the next
+/// background tick tries again.
+fn reject_secondary_limit(resp: HttpResponse) -> Result<HttpResponse, String> {
+    match secondary_retry_after(&resp) {
+        Some(secs) => Err(alloc::format!(
+            "secondary rate limit (HTTP {}), retry after {}s",
+            resp.status,
+            secs
+        )),
+        None => Ok(resp),
+    }
+}
+
+/// GET a page, following repository redirects, and reporting transport,
+/// rate limit and HTTP failures as errors.
+fn http_get_page(url: &str, api_url: &str, github_token: Option<&str>) -> Result<Page, String> {
+    let resp = follow_redirects(url, api_url, |u| http_get_response(u, github_token))
+        .and_then(reject_secondary_limit)?;
+
+    if resp.status != 200 {
+        return Err(alloc::format!("HTTP {}", resp.status));
+    }
+    let next = header(&resp, "link").and_then(parse_next_link);
+    Ok(Page {
+        body: resp.body,
+        next,
+    })
+}
+
+/// Wall-clock budget for one sync. A host HTTP call cannot be interrupted
+/// from inside the module, so it is checked before each request: a slow
+/// GitHub stops the sync after the request in flight instead of stalling it
+/// page after page.
+struct Deadline<'a> {
+    at_ms: i64,
+    now_ms: &'a dyn Fn() -> i64,
+}
+
+impl<'a> Deadline<'a> {
+    fn after(budget_secs: i64, now_ms: &'a dyn Fn() -> i64) -> Self {
+        Self {
+            at_ms: now_ms().saturating_add(budget_secs.saturating_mul(1000)),
+            now_ms,
+        }
+    }
+
+    fn check(&self) -> Result<(), String> {
+        if (self.now_ms)() >= self.at_ms {
+            return Err(String::from("sync deadline exceeded"));
+        }
+        Ok(())
+    }
+}
+
+/// Follow `Link: rel="next"` from `first_url`, passing each body to
+/// `on_page` until it returns `false`, the last page is reached, or
+/// `MAX_PAGES` pages have been read. A failed request, a passed `deadline`
+/// or a next link outside `api_url` stops pagination with an error; pages
+/// already handed to `on_page` stay processed, so a transient failure only
+/// loses the remaining pages. Returns the number of pages read.
+fn paginate<F, H>(
+    first_url: String,
+    api_url: &str,
+    deadline: &Deadline,
+    mut fetch: F,
+    mut on_page: H,
+) -> Result<u32, String>
+where
+    F: FnMut(&str) -> Result<Page, String>,
+    H: FnMut(&[u8]) -> bool,
+{
+    let mut url = first_url;
+    let mut pages = 0u32;
+    while pages < MAX_PAGES {
+        let page = deadline
+            .check()
+            .and_then(|()| fetch(&url))
+            .map_err(|e| alloc::format!("page {}: {}", pages + 1, e))?;
+        pages += 1;
+        if !on_page(&page.body) {
+            break;
+        }
+        match page.next {
+            Some(next) if is_api_url(&next, api_url) => url = next,
+            Some(next) => {
+                return Err(alloc::format!(
+                    "page {}: next link to {} refused",
+                    pages,
+                    next
+                ))
+            }
+            None => break,
+        }
+    }
+    Ok(pages)
+}
 
+fn http_get(url: &str, github_token: Option<&str>) -> Option<Vec<u8>> {
     let req = HttpGetRequest {
         url: String::from(url),
-        headers,
+        headers: request_headers(github_token),
     };
 
     let req_bytes = bincode::serialize(&req).ok()?;
@@ -85,9 +409,52 @@ fn http_get(url: &str, github_token: Option<&str>) -> Option<Vec<u8>> {
     }
 }
 
-fn build_since_param() -> String {
-    let now_ms = platform_challenge_sdk_wasm::host_functions::host_get_timestamp();
-    let now = now_ms / 1000; // host_get_timestamp returns milliseconds
+/// GET `url` and return only the HTTP status.
+fn http_get_status(url: &str, github_token: Option<&str>) -> Option<u16> {
+    let req = HttpGetRequest {
+        url: String::from(url),
+        headers: request_headers(github_token),
+    };
+
+    let req_bytes = bincode::serialize(&req).ok()?;
+    let resp_bytes = platform_challenge_sdk_wasm::host_functions::host_http_get(&req_bytes).ok()?;
+    let result: Result<HttpResponse, String> = bincode::deserialize(&resp_bytes).ok()?;
+    result.ok().map(|resp| resp.status)
+}
+
+/// Map a `GET /users/{name}` status to whether the account exists. Anything
+/// other than 200 or 404 (rate limits, outages) is inconclusive.
+fn user_exists_from_status(status: Option<u16>) -> Option<bool> {
+    match status? {
+        200 => Some(true),
+        404 => Some(false),
+        _ => None,
+    }
+}
+
+/// Whether `github_username` is a GitHub account, or `None` if the lookup
+/// could not tell. Names GitHub could not have issued are not looked up.
+pub fn github_user_exists(github_username: &str, github_token: Option<&str>) -> Option<bool> {
+    if !crate::validation::is_valid_github_username(github_username) {
+        return Some(false);
+    }
+    let url = alloc::format!(
+        "{}/users/{}",
+        storage::get_github_api_url(),
+        github_username
+    );
+    user_exists_from_status(http_get_status(&url, github_token))
+}
+
+/// Whether GitHub answers a rate-limit query, which does not count
+/// against the quota.
+pub fn github_reachable(github_token: Option<&str>) -> bool {
+    let url = alloc::format!("{}/rate_limit", storage::get_github_api_url());
+    http_get_status(&url, github_token) == Some(200)
+}
+
+fn since_param(now_ms: i64) -> String {
+    let now = now_ms / 1000;
     let since_ts = now - SECONDS_24H;
     // Format as ISO 8601: YYYY-MM-DDTHH:MM:SSZ
     let secs_per_day: i64 = 86400;
@@ -163,111 +530,185 @@ fn parse_iso8601_to_ms(s: &str) -> Option<i64> {
     Some(ts * 1000)
 }
 
+/// First page of the repository's issues, newest first by `sort`.
+fn issues_url(api_url: &str, sort: &str, since: Option<&str>) -> String {
+    let mut url = alloc::format!(
+        "{}/repos/{}/{}/issues?state=all&sort={}&direction=desc&per_page={}",
+        api_url,
+        GITHUB_REPO_OWNER,
+        GITHUB_REPO_NAME,
+        sort,
+        ISSUES_PER_PAGE
+    );
+    if let Some(since) = since {
+        url.push_str("&since=");
+        url.push_str(since);
+    }
+    url
+}
+
+/// The record for a fetched issue, unclaimed, or `None` if it has no author
+/// or lacks the `ide` label and so is not tracked.
+fn issue_record(
+    issue: &GitHubIssue,
+    valid_label: &str,
+    epoch: u64,
+) -> Option<crate::types::IssueRecord> {
+    let author = issue.user.as_ref()?.login.to_lowercase();
+    let labels: Vec<String> = issue.labels.iter().map(|l| l.name.to_lowercase()).collect();
+    let has_label = |name: &str| labels.iter().any(|l| l == name);
+    if !has_label("ide") {
+        return None;
+    }
+    Some(crate::types::IssueRecord {
+        issue_number: issue.number,
+        repo_owner: GITHUB_REPO_OWNER.into(),
+        repo_name: GITHUB_REPO_NAME.into(),
+        author,
+        is_closed: issue.state == "closed",
+        has_valid_label: has_label(valid_label),
+        has_invalid_label: has_label("invalid"),
+        has_ide_label: true,
+        claimed_by_hotkey: None,
+        recorded_epoch: epoch,
+        has_duplicate_label: has_label("duplicate") || has_label("duplicated"),
+        has_malicious_label: has_label("malicious"),
+        created_at_ms: issue
+            .created_at
+            .as_ref()
+            .and_then(|c| parse_iso8601_to_ms(c))
+            .unwrap_or(0),
+        assignee: issue.assignee.as_ref().map(|a| a.login.to_lowercase()),
+        milestone: issue.milestone.as_ref().map(|m| m.title.clone()),
+        labels,
+    })
+}
+
 pub fn fetch_and_process_issues() -> SyncStats {
-    let env_token = platform_challenge_sdk_wasm::host_functions::host_env_get("GITHUB_TOKEN")
-        .and_then(|b| alloc::string::String::from_utf8(b).ok());
-    fetch_and_process_issues_with_token(env_token.as_deref())
+    fetch_and_process_issues_with_token(env_github_token().as_deref())
 }
 
 pub fn fetch_and_process_issues_with_token(github_token: Option<&str>) -> SyncStats {
+    fetch_and_process_issues_with_options(github_token, false)
+}
+
+/// Fetch and match issues against registered miners. With `dry_run` set, all
+/// matching still runs but nothing is written: the issues that would be
+/// credited are returned in `SyncStats::would_credit` instead.
+pub fn fetch_and_process_issues_with_options(
+    github_token: Option<&str>,
+    dry_run: bool,
+) -> SyncStats {
+    let clock = platform_challenge_sdk_wasm::host_functions::host_get_timestamp;
+    let api_url = storage::get_github_api_url();
+    let run = SyncRun {
+        api_url: &api_url,
+        now_ms: clock(),
+        epoch: platform_challenge_sdk_wasm::host_functions::host_consensus_get_epoch() as u64,
+        deadline: Deadline::after(i64::from(storage::get_sync_deadline_secs()), &clock),
+        authenticated: github_token.is_some(),
+        dry_run,
+    };
+    sync_issues(&run, |u| http_get_page(u, &api_url, github_token))
+}
+
+/// Host inputs to one issue sync, read up front so the sync itself runs
+/// against mocked pages and in-memory storage in tests.
+struct SyncRun<'a> {
+    api_url: &'a str,
+    now_ms: i64,
+    epoch: u64,
+    deadline: Deadline<'a>,
+    authenticated: bool,
+    dry_run: bool,
+}
+
+fn sync_issues<F>(run: &SyncRun, mut fetch: F) -> SyncStats
+where
+    F: FnMut(&str) -> Result<Page, String>,
+{
     let mut stats = SyncStats {
         fetched: 0,
         awarded: 0,
         penalized: 0,
         last_error: None,
+        would_credit: Vec::new(),
+        synced: 0,
+        skipped: 0,
+        failed: Vec::new(),
+        dead_lettered: 0,
+        authenticated: run.authenticated,
     };
 
-    let since = build_since_param();
+    let dead_letter: Vec<u32> = storage::get_dead_letter_issues()
+        .iter()
+        .map(|f| f.issue_number)
+        .collect();
+    stats.dead_lettered = dead_letter.len() as u32;
+
+    let since = since_param(run.now_ms);
+    let cutoff_ms = run.now_ms - (SECONDS_24H * 1000);
     let mut all_issues: Vec<GitHubIssue> = Vec::new();
-    use core::fmt::Write;
 
     // Pass 1: fetch by created date (newest first) to get new issues
-    let mut page = 1u32;
-    loop {
-        let mut url = String::from("https://api.github.com/repos/");
-        let _ = write!(
-            url,
-            "{}/{}/issues?state=all&sort=created&direction=desc&per_page={}&page={}",
-            GITHUB_REPO_OWNER, GITHUB_REPO_NAME, ISSUES_PER_PAGE, page
-        );
-
-        let body = match http_get(&url, github_token) {
-            Some(b) => b,
-            None => break,
-        };
-
-        let issues: Vec<GitHubIssue> = match serde_json::from_slice(&body) {
-            Ok(v) => v,
-            Err(e) => {
-                stats.last_error = Some(alloc::format!(
-                    "JSON parse error: {} (body_len={}, first_200={:?})",
-                    e,
-                    body.len(),
-                    core::str::from_utf8(&body[..body.len().min(200)]).unwrap_or("non-utf8")
-                ));
-                break;
-            }
-        };
-
-        let count = issues.len();
-
-        let now_ms_check = platform_challenge_sdk_wasm::host_functions::host_get_timestamp();
-        let cutoff_check = now_ms_check - (SECONDS_24H * 1000);
-        let mut all_old = false;
-        if let Some(last) = issues.last() {
-            if let Some(ref created) = last.created_at {
-                if let Some(created_ms) = parse_iso8601_to_ms(created) {
-                    if created_ms < cutoff_check {
-                        all_old = true;
-                    }
+    let mut parse_error: Option<String> = None;
+    let pass1 = paginate(
+        issues_url(run.api_url, "created", None),
+        run.api_url,
+        &run.deadline,
+        &mut fetch,
+        |body| {
+            let (issues, count) = match parse_issue_page(body, &dead_letter, &mut stats.failed) {
+                Some(v) => v,
+                None => {
+                    parse_error = Some(alloc::format!(
+                        "JSON parse error (body_len={}, first_200={:?})",
+                        body.len(),
+                        core::str::from_utf8(&body[..body.len().min(200)]).unwrap_or("non-utf8")
+                    ));
+                    return false;
                 }
-            }
-        }
+            };
 
-        all_issues.extend(issues);
+            // Stop once a page reaches issues created before the 24h window
+            let all_old = issues
+                .last()
+                .and_then(|last| last.created_at.as_ref())
+                .and_then(|created| parse_iso8601_to_ms(created))
+                .is_some_and(|created_ms| created_ms < cutoff_ms);
 
-        if all_old || count < ISSUES_PER_PAGE {
-            break;
-        }
-        page += 1;
-        if page > MAX_PAGES {
-            break;
-        }
+            all_issues.extend(issues);
+            !all_old && count >= ISSUES_PER_PAGE
+        },
+    );
+    if let Err(e) = pass1 {
+        stats.last_error = Some(alloc::format!("created pass stopped at {}", e));
+    }
+    if parse_error.is_some() {
+        stats.last_error = parse_error;
     }
 
     // Pass 2: fetch recently updated issues (sorted by updated_at) to
     // catch label changes on older issues still within the 24h window.
     // These override pass 1 entries so the freshest label state wins.
     let mut updated_issues: Vec<GitHubIssue> = Vec::new();
-    let mut updated_page = 1u32;
-    loop {
-        let mut url = String::from("https://api.github.com/repos/");
-        let _ = write!(
-            url,
-            "{}/{}/issues?state=all&sort=updated&direction=desc&per_page={}&page={}&since={}",
-            GITHUB_REPO_OWNER, GITHUB_REPO_NAME, ISSUES_PER_PAGE, updated_page, since
-        );
-
-        let body = match http_get(&url, github_token) {
-            Some(b) => b,
-            None => break,
-        };
-
-        let issues: Vec<GitHubIssue> = match serde_json::from_slice(&body) {
-            Ok(v) => v,
-            Err(_) => break,
-        };
-
-        let count = issues.len();
-        updated_issues.extend(issues);
-
-        if count < ISSUES_PER_PAGE {
-            break;
-        }
-        updated_page += 1;
-        if updated_page > MAX_PAGES {
-            break;
-        }
+    let pass2 = paginate(
+        issues_url(run.api_url, "updated", Some(&since)),
+        run.api_url,
+        &run.deadline,
+        &mut fetch,
+        |body| match parse_issue_page(body, &dead_letter, &mut stats.failed) {
+            Some((issues, count)) => {
+                updated_issues.extend(issues);
+                count >= ISSUES_PER_PAGE
+            }
+            None => false,
+        },
+    );
+    if let Err(e) = pass2 {
+        stats
+            .last_error
+            .get_or_insert_with(|| alloc::format!("updated pass stopped at {}", e));
     }
 
     // Merge: pass 2 overrides pass 1 for same issue number (fresher labels)
@@ -292,75 +733,71 @@ pub fn fetch_and_process_issues_with_token(github_token: Option<&str>) -> SyncSt
     stats.fetched = all_issues.len() as u32;
 
     // Ensure github:{username} -> hotkey index is populated
-    storage::rebuild_github_index();
+    if !run.dry_run {
+        storage::rebuild_github_index();
+    }
 
     // Build the complete issue list from the 24h fetch, then overwrite the
     // synced_issues blob in one shot so old issues never accumulate.
-    let epoch = platform_challenge_sdk_wasm::host_functions::host_consensus_get_epoch() as u64;
-    let now_ms = platform_challenge_sdk_wasm::host_functions::host_get_timestamp();
-    let cutoff_ms = now_ms - (SECONDS_24H * 1000);
     let mut records: Vec<crate::types::IssueRecord> = Vec::new();
+    let existing = storage::get_synced_issues();
+    let valid_label = storage::get_valid_label();
 
     for issue in &all_issues {
-        let issue_created_ms = issue
-            .created_at
-            .as_ref()
-            .and_then(|c| parse_iso8601_to_ms(c))
-            .unwrap_or(0);
-
-        // Filter out issues created more than 24h ago
-        if issue_created_ms > 0 && issue_created_ms < cutoff_ms {
-            continue;
-        }
-
-        let author = match &issue.user {
-            Some(u) => u.login.to_lowercase(),
+        let mut record = match issue_record(issue, &valid_label, run.epoch) {
+            Some(r) => r,
             None => continue,
         };
 
-        let label_names: Vec<String> = issue.labels.iter().map(|l| l.name.to_lowercase()).collect();
-        let has_ide = label_names.iter().any(|l| l == "ide");
-        if !has_ide {
+        // Filter out issues created more than 24h ago
+        if record.created_at_ms > 0 && record.created_at_ms < cutoff_ms {
             continue;
         }
-        let has_valid = label_names.iter().any(|l| l == "valid");
-        let has_invalid = label_names.iter().any(|l| l == "invalid");
-        let has_duplicate = label_names
-            .iter()
-            .any(|l| l == "duplicate" || l == "duplicated");
-        let has_malicious = label_names.iter().any(|l| l == "malicious");
-        let is_closed = issue.state == "closed";
 
-        // Find registered hotkey for this GitHub username
-        let hotkey = storage::get_hotkey_by_github(&author);
+        let stored = existing.iter().find(|e| {
+            e.repo_owner == record.repo_owner
+                && e.repo_name == record.repo_name
+                && e.issue_number == record.issue_number
+        });
+        record.claimed_by_hotkey = bound_hotkey(&record, stored, storage::get_hotkey_by_github);
 
-        if has_malicious || has_invalid || has_duplicate {
+        if record.has_malicious_label || record.has_invalid_label || record.has_duplicate_label {
             stats.penalized += 1;
-        } else if has_valid {
+        } else if record.has_valid_label {
             stats.awarded += 1;
+            if run.dry_run {
+                stats.would_credit.push(PendingCredit {
+                    issue_number: record.issue_number,
+                    github_username: record.author.clone(),
+                    hotkey: record.claimed_by_hotkey.clone(),
+                });
+            }
         }
 
-        records.push(crate::types::IssueRecord {
-            issue_number: issue.number,
-            repo_owner: GITHUB_REPO_OWNER.into(),
-            repo_name: GITHUB_REPO_NAME.into(),
-            author: author.clone(),
-            is_closed,
-            has_valid_label: has_valid,
-            has_invalid_label: has_invalid,
-            has_ide_label: has_ide,
-            claimed_by_hotkey: hotkey,
-            recorded_epoch: epoch,
-            has_duplicate_label: has_duplicate,
-            has_malicious_label: has_malicious,
-            created_at_ms: issue_created_ms,
-        });
+        if stored.is_some_and(|e| is_unchanged(&record, e)) {
+            stats.skipped += 1;
+        } else {
+            stats.synced += 1;
+        }
+
+        records.push(record);
+    }
+
+    if run.dry_run {
+        return stats;
     }
 
+    let failed: Vec<(u32, String)> = stats
+        .failed
+        .iter()
+        .filter_map(|f| Some((f.issue_number?, f.reason.clone())))
+        .collect();
+    let succeeded: Vec<u32> = all_issues.iter().map(|i| i.number).collect();
+    storage::record_issue_failures(&failed, &succeeded, run.now_ms);
+
     // Merge with existing issues: keep existing data that wasn't re-fetched
     // (e.g. due to rate limits) but update any that were re-fetched with
     // fresh label state. Purge issues older than 24h.
-    let existing = storage::get_synced_issues();
     let mut merged: Vec<crate::types::IssueRecord> = Vec::new();
 
     // Index new records by (repo_owner, repo_name, issue_number) for fast lookup
@@ -402,3 +839,521 @@ pub fn fetch_and_process_issues_with_token(github_token: Option<&str>) -> SyncSt
 
     stats
 }
+
+/// Count distinct star-bonus repos across the pages of a user's starred list.
+/// `fetch_page` returns the raw body for a 1-based page, or `None` when the
+/// request fails (e.g. a private star list), which ends pagination.
+fn count_starred_bonus_repos<F>(mut fetch_page: F) -> u32
+where
+    F: FnMut(u32) -> Option<Vec<u8>>,
+{
+    let mut found = alloc::collections::BTreeSet::new();
+
+    for page in 1..=MAX_STAR_PAGES {
+        let body = match fetch_page(page) {
+            Some(b) => b,
+            None => break,
+        };
+        let repos: Vec<GitHubRepo> = match serde_json::from_slice(&body) {
+            Ok(v) => v,
+            Err(_) => break,
+        };
+
+        let count = repos.len();
+        for repo in repos {
+            let name = repo.full_name.to_lowercase();
+            if STAR_BONUS_REPOS.contains(&name.as_str()) {
+                found.insert(name);
+            }
+        }
+
+        if count < ISSUES_PER_PAGE {
+            break;
+        }
+    }
+
+    found.len() as u32
+}
+
+/// Number of star-bonus repos starred by `github_username`. Users whose star
+/// list cannot be read are treated as having starred nothing.
+pub fn get_user_starred(github_username: &str, github_token: Option<&str>) -> u32 {
+    use core::fmt::Write;
+
+    let api_url = storage::get_github_api_url();
+    count_starred_bonus_repos(|page| {
+        let mut url = String::new();
+        let _ = write!(
+            url,
+            "{}/users/{}/starred?per_page={}&page={}",
+            api_url, github_username, ISSUES_PER_PAGE, page
+        );
+        http_get(&url, github_token)
+    })
+}
+
+/// Refresh the stored `star_count` of every registered miner.
+pub fn refresh_star_counts(github_token: Option<&str>) -> StarSyncStats {
+    let mut stats = StarSyncStats {
+        checked: 0,
+        updated: 0,
+    };
+
+    for hotkey in storage::get_registered_hotkeys() {
+        let github_username = match storage::get_github_by_hotkey(&hotkey) {
+            Some(u) if !u.is_empty() => u,
+            _ => continue,
+        };
+
+        let star_count = get_user_starred(&github_username, github_token);
+        stats.checked += 1;
+
+        let mut balance = storage::get_user_balance(&hotkey);
+        if balance.star_count != star_count {
+            balance.star_count = star_count;
+            storage::store_user_balance(&hotkey, &balance);
+            stats.updated += 1;
+        }
+    }
+
+    stats
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_issue_page_skips_malformed_entries() {
+        let body = br#"[
+            {"number": 1, "user": {"login": "alice"}, "labels": [], "state": "open"},
+            {"number": 2, "user": {"login": "bob"}, "labels": "oops", "state": "open"},
+            {"number": 3, "user": null, "labels": [{"name": "ide"}], "state": "closed"},
+            {"title": "no number"}
+        ]"#;
+        let mut failed = Vec::new();
+
+        let (issues, count) = parse_issue_page(body, &[], &mut failed).unwrap();
+
+        assert_eq!(count, 4);
+        assert_eq!(issues.len(), 2);
+        assert_eq!(issues[0].number, 1);
+        assert_eq!(issues[1].number, 3);
+        assert_eq!(failed.len(), 2);
+        assert_eq!(failed[0].issue_number, Some(2));
+        assert_eq!(failed[1].issue_number, None);
+        assert!(parse_issue_page(b"{\"message\": \"rate limited\"}", &[], &mut failed).is_none());
+    }
+
+    #[test]
+    fn test_issue_failing_repeatedly_is_dead_lettered() {
+        let body = br#"[
+            {"number": 7, "user": {"login": "alice"}, "labels": "oops", "state": "open"},
+            {"number": 8, "user": {"login": "bob"}, "labels": [], "state": "open"}
+        ]"#;
+        let mut failures: Vec<crate::types::IssueFailure> = Vec::new();
+
+        for scan in 1..=storage::MAX_ISSUE_FAILURES {
+            let dead: Vec<u32> = failures
+                .iter()
+                .filter(|f| storage::is_dead_letter(f))
+                .map(|f| f.issue_number)
+                .collect();
+            let mut failed = Vec::new();
+            let (issues, _) = parse_issue_page(body, &dead, &mut failed).unwrap();
+            assert_eq!(failed.len(), 1, "scan {}", scan);
+
+            let failed: Vec<(u32, String)> = failed
+                .iter()
+                .filter_map(|f| Some((f.issue_number?, f.reason.clone())))
+                .collect();
+            let succeeded: Vec<u32> = issues.iter().map(|i| i.number).collect();
+            storage::apply_issue_failures(&mut failures, &failed, &succeeded, scan as i64);
+        }
+
+        assert_eq!(failures.len(), 1);
+        assert_eq!(failures[0].issue_number, 7);
+        assert!(storage::is_dead_letter(&failures[0]));
+
+        // The next scan no longer considers #7 at all.
+        let mut failed = Vec::new();
+        let (issues, count) = parse_issue_page(body, &[7], &mut failed).unwrap();
+        assert!(failed.is_empty());
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].number, 8);
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn test_parse_next_link() {
+        let link = "<https://api.github.com/repositories/1/issues?page=2>; rel=\"next\", \
+                    <https://api.github.com/repositories/1/issues?page=5>; rel=\"last\"";
+        assert_eq!(
+            parse_next_link(link).as_deref(),
+            Some("https://api.github.com/repositories/1/issues?page=2")
+        );
+        assert_eq!(parse_next_link("<https://x/?page=1>; rel=\"prev\""), None);
+    }
+
+    #[test]
+    fn test_paginate_follows_link_header_across_pages() {
+        let mut requested: Vec<String> = Vec::new();
+        let mut bodies: Vec<Vec<u8>> = Vec::new();
+
+        let pages = paginate(
+            String::from("https://api.github.com/issues?page=1"),
+            DEFAULT_GITHUB_API_URL,
+            &Deadline::after(60, &|| 0),
+            |url| {
+                requested.push(String::from(url));
+                match url {
+                    "https://api.github.com/issues?page=1" => Ok(Page {
+                        body: b"[1]".to_vec(),
+                        next: Some(String::from("https://api.github.com/issues?page=2")),
+                    }),
+                    "https://api.github.com/issues?page=2" => Ok(Page {
+                        body: b"[2]".to_vec(),
+                        next: None,
+                    }),
+                    _ => Err(String::from("HTTP 404")),
+                }
+            },
+            |body| {
+                bodies.push(body.to_vec());
+                true
+            },
+        );
+
+        assert_eq!(pages, Ok(2));
+        assert_eq!(requested.len(), 2);
+        assert_eq!(bodies, alloc::vec![b"[1]".to_vec(), b"[2]".to_vec()]);
+    }
+
+    fn response(status: u16, location: Option<&str>, body: &[u8]) -> HttpResponse {
+        let mut headers = BTreeMap::new();
+        if let Some(location) = location {
+            headers.insert(String::from("Location"), String::from(location));
+        }
+        HttpResponse {
+            status,
+            headers,
+            body: body.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_follows_redirect_from_renamed_repo() {
+        let old = "https://api.github.com/repos/CortexLM/bounty-challenge/issues?state=all";
+        let new = "https://api.github.com/repositories/42/issues?state=all";
+        let body = br#"[{"number": 5, "user": {"login": "alice"},
+            "labels": [{"name": "ide"}, {"name": "valid"}], "state": "closed"}]"#;
+
+        let mut requested = Vec::new();
+        let resp = follow_redirects(old, DEFAULT_GITHUB_API_URL, |url| {
+            requested.push(String::from(url));
+            Ok(if url == old {
+                response(301, Some(new), b"")
+            } else {
+                response(200, None, body)
+            })
+        })
+        .unwrap();
+
+        assert_eq!(requested, [old, new]);
+        let mut failed = Vec::new();
+        let (issues, _) = parse_issue_page(&resp.body, &[], &mut failed).unwrap();
+        assert_eq!(issues[0].number, 5);
+        assert!(failed.is_empty());
+    }
+
+    #[test]
+    fn test_redirects_are_bounded_and_stay_on_the_api() {
+        let looping = follow_redirects("https://api.github.com/a", DEFAULT_GITHUB_API_URL, |_| {
+            Ok(response(301, Some("https://api.github.com/a"), b""))
+        });
+        assert_eq!(looping.err().as_deref(), Some("too many redirects"));
+
+        let offsite = follow_redirects("https://api.github.com/a", DEFAULT_GITHUB_API_URL, |_| {
+            Ok(response(302, Some("https://example.com/a"), b""))
+        });
+        assert!(offsite.err().unwrap().contains("refused"));
+
+        let lookalike =
+            follow_redirects("https://api.github.com/a", DEFAULT_GITHUB_API_URL, |_| {
+                Ok(response(
+                    302,
+                    Some("https://api.github.com.example.com/a"),
+                    b"",
+                ))
+            });
+        assert!(lookalike.err().unwrap().contains("refused"));
+    }
+
+    #[test]
+    fn test_secondary_rate_limit_fails_the_page() {
+        let mut limited = response(429, None, b"");
+        limited
+            .headers
+            .insert(String::from("Retry-After"), String::from("30"));
+        let err = reject_secondary_limit(limited).unwrap_err();
+        assert!(err.contains("retry after 30s"), "{}", err);
+
+        // A plain 403 is a real refusal: returned as is.
+        let resp = reject_secondary_limit(response(403, None, b"")).unwrap();
+        assert_eq!(resp.status, 403);
+    }
+
+    #[test]
+    fn test_mocked_issues_are_verified_against_policy() {
+        let api = "http://127.0.0.1:8089";
+        let first = issues_url(api, "created", None);
+        let body = br#"[
+            {"number": 10, "user": {"login": "Alice"}, "state": "closed",
+             "labels": [{"name": "IDE"}, {"name": "valid"}]},
+            {"number": 11, "user": {"login": "bob"}, "state": "open",
+             "labels": [{"name": "ide"}, {"name": "valid"}]},
+            {"number": 12, "user": {"login": "carol"}, "state": "closed",
+             "labels": [{"name": "valid"}]}
+        ]"#;
+
+        let mut issues = Vec::new();
+        paginate(
+            first.clone(),
+            api,
+            &Deadline::after(60, &|| 0),
+            |url| {
+                assert_eq!(url, first);
+                Ok(Page {
+                    body: body.to_vec(),
+                    next: None,
+                })
+            },
+            |page| {
+                issues.extend(parse_issue_page(page, &[], &mut Vec::new()).unwrap().0);
+                true
+            },
+        )
+        .unwrap();
+        let records: Vec<_> = issues
+            .iter()
+            .filter_map(|i| issue_record(i, "valid", 1))
+            .collect();
+        assert_eq!(records.len(), 2, "issue 12 lacks the ide label");
+
+        let policy = crate::validation::VerificationPolicy {
+            require_closed: true,
+            ..crate::validation::VerificationPolicy::default()
+        };
+        let closed = crate::validation::verify_issue_validity(&records[0], &policy);
+        assert!(closed.is_verified(), "{:?}", closed.failed);
+        assert_eq!(records[0].author, "alice");
+        assert_eq!(
+            crate::validation::verify_issue_validity(&records[1], &policy).failed,
+            ["Issue is not closed"]
+        );
+    }
+
+    #[test]
+    fn test_dry_run_sync_leaves_storage_untouched() {
+        use crate::storage::host::{host_storage_list_prefix, reset};
+
+        fn run<'a>(clock: &'a dyn Fn() -> i64, dry_run: bool) -> SyncRun<'a> {
+            SyncRun {
+                api_url: DEFAULT_GITHUB_API_URL,
+                now_ms: 0,
+                epoch: 1,
+                deadline: Deadline::after(60, clock),
+                authenticated: false,
+                dry_run,
+            }
+        }
+        let body = br#"[
+            {"number": 7, "user": {"login": "alice"}, "state": "closed",
+             "labels": [{"name": "ide"}, {"name": "valid"}]},
+            {"number": 8, "user": {"login": "alice"}, "state": "closed",
+             "labels": [{"name": "ide"}, {"name": "invalid"}]}
+        ]"#;
+        let fetch = |_: &str| {
+            Ok(Page {
+                body: body.to_vec(),
+                next: None,
+            })
+        };
+        let clock = || 0;
+
+        reset(1);
+        assert!(storage::register_user("alice", "hk-a"));
+        let before = host_storage_list_prefix(b"", u32::MAX).unwrap();
+        let stats = sync_issues(&run(&clock, true), fetch);
+        assert_eq!((stats.fetched, stats.awarded, stats.penalized), (2, 1, 1));
+        assert_eq!(stats.would_credit[0].hotkey.as_deref(), Some("hk-a"));
+        assert_eq!(host_storage_list_prefix(b"", u32::MAX).unwrap(), before);
+
+        // The same pages without dry_run do write.
+        sync_issues(&run(&clock, false), fetch);
+        assert_eq!(storage::get_synced_issues().len(), 2);
+    }
+
+    #[test]
+    fn test_synced_issue_keeps_its_first_hotkey() {
+        use crate::storage::fixtures::IssueBuilder;
+
+        let fresh = IssueBuilder::new(7).labels(&["ide", "invalid"]).build();
+        let linked = |_: &str| Some(String::from("hk-new"));
+        assert_eq!(
+            bound_hotkey(&fresh, None, linked).as_deref(),
+            Some("hk-new")
+        );
+
+        // The author relinked or unregistered after the first sync.
+        let stored = IssueBuilder::new(7).claimed_by("hk-a").build();
+        assert_eq!(
+            bound_hotkey(&fresh, Some(&stored), |_| None).as_deref(),
+            Some("hk-a")
+        );
+        assert_eq!(
+            bound_hotkey(&fresh, Some(&stored), linked).as_deref(),
+            Some("hk-a")
+        );
+    }
+
+    #[test]
+    fn test_paginate_keeps_pages_read_before_a_failure() {
+        let mut bodies = 0;
+        let result = paginate(
+            String::from("https://api.github.com/first"),
+            DEFAULT_GITHUB_API_URL,
+            &Deadline::after(60, &|| 0),
+            |url| match url {
+                "https://api.github.com/first" => Ok(Page {
+                    body: b"[]".to_vec(),
+                    next: Some(String::from("https://api.github.com/second")),
+                }),
+                _ => Err(String::from("HTTP 502")),
+            },
+            |_| {
+                bodies += 1;
+                true
+            },
+        );
+
+        assert_eq!(result, Err(String::from("page 2: HTTP 502")));
+        assert_eq!(bodies, 1);
+    }
+
+    #[test]
+    fn test_paginate_refuses_next_link_off_the_api() {
+        let mut requested: Vec<String> = Vec::new();
+        let result = paginate(
+            String::from("https://api.github.com/issues?page=1"),
+            DEFAULT_GITHUB_API_URL,
+            &Deadline::after(60, &|| 0),
+            |url| {
+                requested.push(String::from(url));
+                Ok(Page {
+                    body: b"[]".to_vec(),
+                    next: Some(String::from(
+                        "https://api.github.com.example.com/issues?page=2",
+                    )),
+                })
+            },
+            |_| true,
+        );
+
+        assert_eq!(
+            result,
+            Err(String::from(
+                "page 1: next link to https://api.github.com.example.com/issues?page=2 refused"
+            ))
+        );
+        assert_eq!(requested, ["https://api.github.com/issues?page=1"]);
+    }
+
+    #[test]
+    fn test_paginate_stops_at_deadline_when_github_is_slow() {
+        let clock = core::cell::Cell::new(0i64);
+        let now = || clock.get();
+        let deadline = Deadline::after(60, &now);
+        let mut bodies = 0;
+
+        // Every request takes 40s; the third would start past the deadline.
+        let result = paginate(
+            String::from("https://api.github.com/page"),
+            DEFAULT_GITHUB_API_URL,
+            &deadline,
+            |_| {
+                clock.set(clock.get() + 40_000);
+                Ok(Page {
+                    body: b"[]".to_vec(),
+                    next: Some(String::from("https://api.github.com/page")),
+                })
+            },
+            |_| {
+                bodies += 1;
+                true
+            },
+        );
+
+        assert_eq!(result, Err(String::from("page 3: sync deadline exceeded")));
+        assert_eq!(bodies, 2);
+        assert_eq!(clock.get(), 80_000);
+    }
+
+    #[test]
+    fn test_authorization_header_only_with_token() {
+        let headers = request_headers(Some("ghp_example"));
+        assert_eq!(
+            headers.get("Authorization").map(String::as_str),
+            Some("Bearer ghp_example")
+        );
+        assert!(headers.contains_key("User-Agent"));
+
+        let headers = request_headers(None);
+        assert!(!headers.contains_key("Authorization"));
+        assert!(headers.contains_key("User-Agent"));
+    }
+
+    #[test]
+    fn test_user_exists_from_status() {
+        assert_eq!(user_exists_from_status(Some(200)), Some(true));
+        assert_eq!(user_exists_from_status(Some(404)), Some(false));
+        assert_eq!(user_exists_from_status(Some(403)), None);
+        assert_eq!(user_exists_from_status(None), None);
+    }
+
+    fn page_of(names: &[&str]) -> Vec<u8> {
+        let repos: Vec<serde_json::Value> = names
+            .iter()
+            .map(|n| serde_json::json!({ "full_name": n }))
+            .collect();
+        serde_json::to_vec(&repos).unwrap()
+    }
+
+    #[test]
+    fn test_star_pagination_accumulates_across_pages() {
+        let mut first: Vec<&str> = alloc::vec!["someone/else"; ISSUES_PER_PAGE - 1];
+        first.push("CortexLM/cortex");
+        let second = [
+            "PlatformNetwork/platform",
+            "PlatformNetwork/bounty-challenge",
+        ];
+
+        let mut requested = Vec::new();
+        let count = count_starred_bonus_repos(|page| {
+            requested.push(page);
+            match page {
+                1 => Some(page_of(&first)),
+                2 => Some(page_of(&second)),
+                _ => None,
+            }
+        });
+
+        assert_eq!(count, 3);
+        assert_eq!(requested, alloc::vec![1, 2]);
+    }
+
+    #[test]
+    fn test_private_star_list_counts_zero() {
+        assert_eq!(count_starred_bonus_repos(|_| None), 0);
+    }
+}
@@ -1,15 +1,23 @@
 #![no_std]
 
 extern crate alloc;
+#[cfg(test)]
+extern crate std;
 
+mod abuse;
 mod api;
+mod build_info;
+pub mod error;
 mod github_sync;
+mod metrics;
 mod routes;
 mod scoring;
+mod selftest;
 pub mod ss58;
 pub mod storage;
 pub mod types;
 mod validation;
+pub mod version;
 
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -38,6 +46,77 @@ fn bincode_options_route_request() -> impl Options {
         .allow_trailing_bytes()
 }
 
+fn evaluate_submission(input: EvaluationInput) -> Result<EvaluationOutput, String> {
+    let submission: BountySubmission =
+        match bincode_options_submission().deserialize(&input.agent_data) {
+            Ok(s) => s,
+            Err(_) => return Err(String::from("failed to deserialize submission")),
+        };
+
+    if submission.hotkey.is_empty() {
+        return Err(String::from("missing hotkey"));
+    }
+
+    if submission.github_username.is_empty() {
+        return Err(String::from("missing github_username"));
+    }
+
+    if submission.issue_numbers.is_empty() {
+        return Err(String::from("no issues to claim"));
+    }
+
+    let check =
+        validation::check_issue_numbers(&submission.issue_numbers, storage::get_max_claim_batch());
+    if !check.errors.is_empty() {
+        return Err(check.errors.join("; "));
+    }
+
+    if submission.signature.is_empty() {
+        return Err(String::from("missing signature"));
+    }
+
+    let reg = match storage::get_user_by_hotkey(&submission.hotkey) {
+        Some(r) => r,
+        None => return Err(String::from("hotkey not registered")),
+    };
+
+    if reg.github_username.to_lowercase() != submission.github_username.to_lowercase() {
+        return Err(String::from("github username mismatch with registration"));
+    }
+
+    storage::ensure_hotkey_tracked(&submission.hotkey);
+
+    let synced_issues = storage::get_synced_issues();
+    let result = validation::process_claims(&submission, &synced_issues);
+
+    if !result.claimed.is_empty() {
+        scoring::rebuild_leaderboard();
+    }
+
+    let score = (result.score * 10_000.0) as i64;
+
+    let mut message = String::from("claimed=");
+    let claimed_count = result.claimed.len();
+    let rejected_count = result.rejected.len();
+    let _ = core::fmt::Write::write_fmt(
+        &mut message,
+        format_args!(
+            "{} rejected={} total_valid={} weight={:.4}",
+            claimed_count, rejected_count, result.total_valid, result.score
+        ),
+    );
+
+    let eval_metrics = bincode::serialize(&types::EvalMetrics {
+        claimed_count: claimed_count as u32,
+        rejected_count: rejected_count as u32,
+        total_valid: result.total_valid,
+        weight: result.score,
+    })
+    .unwrap_or_default();
+
+    Ok(EvaluationOutput::success(score, &message).with_metrics(eval_metrics))
+}
+
 pub struct BountyChallengeWasm;
 
 impl Default for BountyChallengeWasm {
@@ -58,72 +137,20 @@ impl Challenge for BountyChallengeWasm {
     }
 
     fn version(&self) -> &'static str {
-        "2.0.0"
+        version::VERSION
     }
 
     fn evaluate(&self, input: EvaluationInput) -> EvaluationOutput {
-        let submission: BountySubmission =
-            match bincode_options_submission().deserialize(&input.agent_data) {
-                Ok(s) => s,
-                Err(_) => return EvaluationOutput::failure("failed to deserialize submission"),
-            };
-
-        if submission.hotkey.is_empty() {
-            return EvaluationOutput::failure("missing hotkey");
-        }
-
-        if submission.github_username.is_empty() {
-            return EvaluationOutput::failure("missing github_username");
-        }
-
-        if submission.issue_numbers.is_empty() {
-            return EvaluationOutput::failure("no issues to claim");
-        }
-
-        if submission.signature.is_empty() {
-            return EvaluationOutput::failure("missing signature");
-        }
-
-        let reg = match storage::get_user_by_hotkey(&submission.hotkey) {
-            Some(r) => r,
-            None => return EvaluationOutput::failure("hotkey not registered"),
-        };
-
-        if reg.github_username.to_lowercase() != submission.github_username.to_lowercase() {
-            return EvaluationOutput::failure("github username mismatch with registration");
+        let start = platform_challenge_sdk_wasm::host_functions::host_get_timestamp();
+        let result = evaluate_submission(input);
+        let elapsed_ms =
+            platform_challenge_sdk_wasm::host_functions::host_get_timestamp().saturating_sub(start);
+        metrics::record_evaluation(result.is_ok(), elapsed_ms.max(0) as u64);
+
+        match result {
+            Ok(output) => output,
+            Err(msg) => EvaluationOutput::failure(&msg),
         }
-
-        storage::ensure_hotkey_tracked(&submission.hotkey);
-
-        let synced_issues = storage::get_synced_issues();
-        let result = validation::process_claims(&submission, &synced_issues);
-
-        if !result.claimed.is_empty() {
-            scoring::rebuild_leaderboard();
-        }
-
-        let score = (result.score * 10_000.0) as i64;
-
-        let mut message = String::from("claimed=");
-        let claimed_count = result.claimed.len();
-        let rejected_count = result.rejected.len();
-        let _ = core::fmt::Write::write_fmt(
-            &mut message,
-            format_args!(
-                "{} rejected={} total_valid={} weight={:.4}",
-                claimed_count, rejected_count, result.total_valid, result.score
-            ),
-        );
-
-        let metrics = bincode::serialize(&types::EvalMetrics {
-            claimed_count: claimed_count as u32,
-            rejected_count: rejected_count as u32,
-            total_valid: result.total_valid,
-            weight: result.score,
-        })
-        .unwrap_or_default();
-
-        EvaluationOutput::success(score, &message).with_metrics(metrics)
     }
 
     fn validate(&self, input: EvaluationInput) -> bool {
@@ -226,6 +253,7 @@ impl Challenge for BountyChallengeWasm {
                     last_epoch: agg_input.epoch,
                     duplicate_issues: 0,
                     malicious_issues: 0,
+                    suspicious: false,
                 }
             })
             .filter(|e| e.net_points > 0.0)
@@ -0,0 +1,148 @@
+//! In-memory counters exposed in Prometheus text format via `GET /metrics`.
+//!
+//! Counters live in the WASM instance and reset when the validator reloads the
+//! module. They are never written to host storage, so they cannot influence
+//! consensus state.
+
+use alloc::string::String;
+use core::fmt::Write;
+use core::sync::atomic::{AtomicU64, Ordering};
+
+/// Upper bounds (inclusive, in milliseconds) of the evaluation latency buckets.
+const LATENCY_BUCKETS_MS: [u64; 8] = [1, 5, 10, 50, 100, 500, 1_000, 5_000];
+
+static EVALUATIONS_SUCCESS: AtomicU64 = AtomicU64::new(0);
+static EVALUATIONS_FAILURE: AtomicU64 = AtomicU64::new(0);
+static CLAIMS_CLAIMED: AtomicU64 = AtomicU64::new(0);
+static CLAIMS_REJECTED: AtomicU64 = AtomicU64::new(0);
+static STORAGE_ERRORS: AtomicU64 = AtomicU64::new(0);
+static LATENCY_BUCKETS: [AtomicU64; LATENCY_BUCKETS_MS.len()] =
+    [const { AtomicU64::new(0) }; LATENCY_BUCKETS_MS.len()];
+static LATENCY_SUM_MS: AtomicU64 = AtomicU64::new(0);
+static LATENCY_COUNT: AtomicU64 = AtomicU64::new(0);
+
+pub fn record_evaluation(success: bool, elapsed_ms: u64) {
+    if success {
+        EVALUATIONS_SUCCESS.fetch_add(1, Ordering::Relaxed);
+    } else {
+        EVALUATIONS_FAILURE.fetch_add(1, Ordering::Relaxed);
+    }
+
+    for (bound, bucket) in LATENCY_BUCKETS_MS.iter().zip(LATENCY_BUCKETS.iter()) {
+        if elapsed_ms <= *bound {
+            bucket.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+    LATENCY_SUM_MS.fetch_add(elapsed_ms, Ordering::Relaxed);
+    LATENCY_COUNT.fetch_add(1, Ordering::Relaxed);
+}
+
+pub fn record_claims(claimed: usize, rejected: usize) {
+    CLAIMS_CLAIMED.fetch_add(claimed as u64, Ordering::Relaxed);
+    CLAIMS_REJECTED.fetch_add(rejected as u64, Ordering::Relaxed);
+}
+
+pub fn record_storage_error() {
+    STORAGE_ERRORS.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Render all counters in the Prometheus text exposition format.
+pub fn render() -> String {
+    let mut out = String::new();
+
+    let _ = writeln!(
+        out,
+        "# HELP bounty_evaluations_total Evaluations processed, by result."
+    );
+    let _ = writeln!(out, "# TYPE bounty_evaluations_total counter");
+    let _ = writeln!(
+        out,
+        "bounty_evaluations_total{{result=\"success\"}} {}",
+        EVALUATIONS_SUCCESS.load(Ordering::Relaxed)
+    );
+    let _ = writeln!(
+        out,
+        "bounty_evaluations_total{{result=\"failure\"}} {}",
+        EVALUATIONS_FAILURE.load(Ordering::Relaxed)
+    );
+
+    let _ = writeln!(
+        out,
+        "# HELP bounty_claims_total Issues processed by claim submissions, by outcome."
+    );
+    let _ = writeln!(out, "# TYPE bounty_claims_total counter");
+    let _ = writeln!(
+        out,
+        "bounty_claims_total{{outcome=\"claimed\"}} {}",
+        CLAIMS_CLAIMED.load(Ordering::Relaxed)
+    );
+    let _ = writeln!(
+        out,
+        "bounty_claims_total{{outcome=\"rejected\"}} {}",
+        CLAIMS_REJECTED.load(Ordering::Relaxed)
+    );
+
+    let _ = writeln!(
+        out,
+        "# HELP bounty_evaluation_latency_ms Evaluation latency in milliseconds."
+    );
+    let _ = writeln!(out, "# TYPE bounty_evaluation_latency_ms histogram");
+    for (bound, bucket) in LATENCY_BUCKETS_MS.iter().zip(LATENCY_BUCKETS.iter()) {
+        let _ = writeln!(
+            out,
+            "bounty_evaluation_latency_ms_bucket{{le=\"{}\"}} {}",
+            bound,
+            bucket.load(Ordering::Relaxed)
+        );
+    }
+    let count = LATENCY_COUNT.load(Ordering::Relaxed);
+    let _ = writeln!(
+        out,
+        "bounty_evaluation_latency_ms_bucket{{le=\"+Inf\"}} {}",
+        count
+    );
+    let _ = writeln!(
+        out,
+        "bounty_evaluation_latency_ms_sum {}",
+        LATENCY_SUM_MS.load(Ordering::Relaxed)
+    );
+    let _ = writeln!(out, "bounty_evaluation_latency_ms_count {}", count);
+
+    let _ = writeln!(
+        out,
+        "# HELP bounty_storage_errors_total Failed host storage writes."
+    );
+    let _ = writeln!(out, "# TYPE bounty_storage_errors_total counter");
+    let _ = writeln!(
+        out,
+        "bounty_storage_errors_total {}",
+        STORAGE_ERRORS.load(Ordering::Relaxed)
+    );
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counter_value(output: &str, series: &str) -> u64 {
+        output
+            .lines()
+            .find_map(|l| l.strip_prefix(series))
+            .and_then(|v| v.trim().parse().ok())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_record_evaluation_increments_scraped_counter() {
+        let series = "bounty_evaluations_total{result=\"success\"}";
+        let before = counter_value(&render(), series);
+
+        record_evaluation(true, 7);
+
+        let after = render();
+        assert_eq!(counter_value(&after, series), before + 1);
+        assert!(counter_value(&after, "bounty_evaluation_latency_ms_bucket{le=\"10\"}") >= 1);
+    }
+}
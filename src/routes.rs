@@ -16,6 +16,18 @@ pub fn get_route_definitions() -> Vec<WasmRouteDefinition> {
             description: String::from("Returns current leaderboard with scores and rankings"),
             requires_auth: false,
         },
+        WasmRouteDefinition {
+            method: String::from("GET"),
+            path: String::from("/leaderboard/:hotkey/rank"),
+            description: String::from("Rank, score and percentile of a single miner"),
+            requires_auth: false,
+        },
+        WasmRouteDefinition {
+            method: String::from("GET"),
+            path: String::from("/leaderboard/movers"),
+            description: String::from("Largest net-point gains and losses over a window"),
+            requires_auth: false,
+        },
         WasmRouteDefinition {
             method: String::from("GET"),
             path: String::from("/stats"),
@@ -34,12 +46,32 @@ pub fn get_route_definitions() -> Vec<WasmRouteDefinition> {
             description: String::from("Register GitHub username with hotkey (requires auth)"),
             requires_auth: true,
         },
+        WasmRouteDefinition {
+            method: String::from("POST"),
+            path: String::from("/register/update"),
+            description: String::from("Link your hotkey to a different GitHub username"),
+            requires_auth: true,
+        },
+        WasmRouteDefinition {
+            method: String::from("DELETE"),
+            path: String::from("/register"),
+            description: String::from("Unlink your hotkey from its GitHub username"),
+            requires_auth: true,
+        },
         WasmRouteDefinition {
             method: String::from("POST"),
             path: String::from("/claim"),
             description: String::from("Claim bounty for resolved issues (requires auth)"),
             requires_auth: true,
         },
+        WasmRouteDefinition {
+            method: String::from("POST"),
+            path: String::from("/validate"),
+            description: String::from(
+                "Check a submission and report errors and warnings (requires auth)",
+            ),
+            requires_auth: true,
+        },
         WasmRouteDefinition {
             method: String::from("GET"),
             path: String::from("/issues"),
@@ -52,6 +84,18 @@ pub fn get_route_definitions() -> Vec<WasmRouteDefinition> {
             description: String::from("List pending issues"),
             requires_auth: false,
         },
+        WasmRouteDefinition {
+            method: String::from("GET"),
+            path: String::from("/issues/failed"),
+            description: String::from("Issues skipped by syncs after repeatedly failing to parse"),
+            requires_auth: false,
+        },
+        WasmRouteDefinition {
+            method: String::from("GET"),
+            path: String::from("/issues/:number/claim"),
+            description: String::from("Check whether an issue is already claimed and by whom"),
+            requires_auth: false,
+        },
         WasmRouteDefinition {
             method: String::from("GET"),
             path: String::from("/hotkey/:hotkey"),
@@ -78,6 +122,32 @@ pub fn get_route_definitions() -> Vec<WasmRouteDefinition> {
             description: String::from("Returns normalized weight assignments for all miners"),
             requires_auth: false,
         },
+        WasmRouteDefinition {
+            method: String::from("GET"),
+            path: String::from("/version"),
+            description: String::from("Challenge name, API version and build metadata"),
+            requires_auth: false,
+        },
+        WasmRouteDefinition {
+            method: String::from("GET"),
+            path: String::from("/selftest"),
+            description: String::from(
+                "Check storage, GitHub reachability and sync freshness (cached 10s)",
+            ),
+            requires_auth: false,
+        },
+        WasmRouteDefinition {
+            method: String::from("GET"),
+            path: String::from("/audit"),
+            description: String::from("Recent register, claim and sync writes"),
+            requires_auth: true,
+        },
+        WasmRouteDefinition {
+            method: String::from("GET"),
+            path: String::from("/metrics"),
+            description: String::from("Evaluation and claim counters in Prometheus text format"),
+            requires_auth: false,
+        },
         WasmRouteDefinition {
             method: String::from("POST"),
             path: String::from("/sudo/bulk_migrate"),
@@ -92,18 +162,114 @@ pub fn get_route_definitions() -> Vec<WasmRouteDefinition> {
             description: String::from("Register a single user with hotkey (sudo owner only)"),
             requires_auth: true,
         },
+        WasmRouteDefinition {
+            method: String::from("POST"),
+            path: String::from("/sudo/export"),
+            description: String::from("Export all miners, balances, weights and bounties (sudo owner only)"),
+            requires_auth: true,
+        },
+        WasmRouteDefinition {
+            method: String::from("POST"),
+            path: String::from("/sudo/import"),
+            description: String::from("Restore a /sudo/export bundle (sudo owner only)"),
+            requires_auth: true,
+        },
         WasmRouteDefinition {
             method: String::from("POST"),
             path: String::from("/sudo/sync_github"),
             description: String::from("Trigger GitHub issue sync manually (sudo owner only)"),
             requires_auth: true,
         },
+        WasmRouteDefinition {
+            method: String::from("POST"),
+            path: String::from("/sudo/sync_stars"),
+            description: String::from(
+                "Refresh starred-repo counts for all miners (sudo owner only)",
+            ),
+            requires_auth: true,
+        },
         WasmRouteDefinition {
             method: String::from("POST"),
             path: String::from("/sudo/recount"),
             description: String::from("Recount all balances from stored issues (sudo owner only)"),
             requires_auth: true,
         },
+        WasmRouteDefinition {
+            method: String::from("POST"),
+            path: String::from("/sudo/valid_label"),
+            description: String::from(
+                "Set the label that marks issues as valid, applied from the next sync (sudo owner only)",
+            ),
+            requires_auth: true,
+        },
+        WasmRouteDefinition {
+            method: String::from("POST"),
+            path: String::from("/sudo/former_repo_names"),
+            description: String::from(
+                "Set former owner/name pairs credited to the current repository (sudo owner only)",
+            ),
+            requires_auth: true,
+        },
+        WasmRouteDefinition {
+            method: String::from("POST"),
+            path: String::from("/sudo/penalty_config"),
+            description: String::from("Set invalid/duplicate penalty weights and threshold (sudo owner only)"),
+            requires_auth: true,
+        },
+        WasmRouteDefinition {
+            method: String::from("POST"),
+            path: String::from("/sudo/max_claim_batch"),
+            description: String::from("Set how many issues one claim may carry (sudo owner only)"),
+            requires_auth: true,
+        },
+        WasmRouteDefinition {
+            method: String::from("POST"),
+            path: String::from("/sudo/github_api_url"),
+            description: String::from(
+                "Set the GitHub REST API root every validator syncs from (sudo owner only)",
+            ),
+            requires_auth: true,
+        },
+        WasmRouteDefinition {
+            method: String::from("POST"),
+            path: String::from("/sudo/sync_deadline"),
+            description: String::from(
+                "Set how many seconds one GitHub issue sync may run (sudo owner only)",
+            ),
+            requires_auth: true,
+        },
+        WasmRouteDefinition {
+            method: String::from("POST"),
+            path: String::from("/sudo/rate_limit"),
+            description: String::from(
+                "Set the requests per minute each hotkey may send to rate-limited routes (sudo owner only)",
+            ),
+            requires_auth: true,
+        },
+        WasmRouteDefinition {
+            method: String::from("POST"),
+            path: String::from("/sudo/suspicion_config"),
+            description: String::from(
+                "Set the thresholds that flag claim bursts for review (sudo owner only)",
+            ),
+            requires_auth: true,
+        },
+        WasmRouteDefinition {
+            method: String::from("POST"),
+            path: String::from("/sudo/verification_policy"),
+            description: String::from(
+                "Set the labels, state, assignee and milestone a claimable issue needs (sudo owner only)",
+            ),
+            requires_auth: true,
+        },
+        WasmRouteDefinition {
+            method: String::from("POST"),
+            path: String::from("/sudo/reset_issue_failures"),
+            description: String::from(
+                "Clear an issue's sync failures so it is scanned again (sudo owner only)",
+            ),
+            requires_auth: true,
+        },
         WasmRouteDefinition {
             method: String::from("POST"),
             path: String::from("/sudo/ban_user"),
@@ -120,22 +286,50 @@ pub fn get_route_definitions() -> Vec<WasmRouteDefinition> {
 }
 
 pub fn handle_route_request(request: &WasmRouteRequest) -> WasmRouteResponse {
-    let path = request.path.as_str();
+    let path = request.path.split('?').next().unwrap_or("");
     let method = request.method.as_str();
 
+    if let Some(resp) = handlers::check_body_size(&request.body) {
+        return resp;
+    }
+
     match (method, path) {
         ("GET", "/leaderboard") => handlers::handle_leaderboard(request),
+        ("GET", "/leaderboard/movers") => handlers::handle_leaderboard_movers(request),
         ("GET", "/stats") => handlers::handle_stats(request),
         ("POST", "/register") => handlers::handle_register(request),
+        ("POST", "/register/update") => handlers::handle_register_update(request),
+        ("DELETE", "/register") => handlers::handle_unregister(request),
         ("POST", "/claim") => handlers::handle_claim(request),
+        ("POST", "/validate") => handlers::handle_validate(request),
         ("GET", "/issues") => handlers::handle_issues(request),
         ("GET", "/issues/pending") => handlers::handle_issues_pending(request),
+        ("GET", "/issues/failed") => handlers::handle_issues_failed(request),
         ("GET", "/issues/stats") => handlers::handle_issues_stats(request),
         ("GET", "/get_weights") => handlers::handle_get_weights(request),
+        ("GET", "/version") => handlers::handle_version(request),
+        ("GET", "/selftest") => handlers::handle_selftest(request),
+        ("GET", "/audit") => handlers::handle_audit(request),
+        ("GET", "/metrics") => handlers::handle_metrics(request),
         ("POST", "/sudo/bulk_migrate") => handlers::handle_sudo_bulk_migrate(request),
         ("POST", "/sudo/register_user") => handlers::handle_sudo_register_user(request),
+        ("POST", "/sudo/export") => handlers::handle_sudo_export(request),
+        ("POST", "/sudo/import") => handlers::handle_sudo_import(request),
         ("POST", "/sudo/sync_github") => handlers::handle_sudo_sync_github(request),
+        ("POST", "/sudo/sync_stars") => handlers::handle_sudo_sync_stars(request),
         ("POST", "/sudo/recount") => handlers::handle_sudo_recount(request),
+        ("POST", "/sudo/valid_label") => handlers::handle_sudo_valid_label(request),
+        ("POST", "/sudo/former_repo_names") => handlers::handle_sudo_former_repo_names(request),
+        ("POST", "/sudo/penalty_config") => handlers::handle_sudo_penalty_config(request),
+        ("POST", "/sudo/max_claim_batch") => handlers::handle_sudo_max_claim_batch(request),
+        ("POST", "/sudo/github_api_url") => handlers::handle_sudo_github_api_url(request),
+        ("POST", "/sudo/sync_deadline") => handlers::handle_sudo_sync_deadline(request),
+        ("POST", "/sudo/rate_limit") => handlers::handle_sudo_rate_limit(request),
+        ("POST", "/sudo/suspicion_config") => handlers::handle_sudo_suspicion_config(request),
+        ("POST", "/sudo/verification_policy") => handlers::handle_sudo_verification_policy(request),
+        ("POST", "/sudo/reset_issue_failures") => {
+            handlers::handle_sudo_reset_issue_failures(request)
+        }
         ("POST", "/sudo/ban_user") => handlers::handle_sudo_ban_user(request),
         ("POST", "/sudo/unban_user") => handlers::handle_sudo_unban_user(request),
         _ => {
@@ -149,6 +343,12 @@ pub fn handle_route_request(request: &WasmRouteRequest) -> WasmRouteResponse {
                 if path.starts_with("/github/") {
                     return handlers::handle_github_user(request);
                 }
+                if path.starts_with("/leaderboard/") && path.ends_with("/rank") {
+                    return handlers::handle_leaderboard_rank(request);
+                }
+                if path.starts_with("/issues/") && path.ends_with("/claim") {
+                    return handlers::handle_issue_claim_status(request);
+                }
             }
             WasmRouteResponse {
                 status: 404,
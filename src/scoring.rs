@@ -3,10 +3,21 @@ use alloc::vec::Vec;
 use serde::{Deserialize, Serialize};
 
 use crate::storage;
-use crate::types::{LeaderboardEntry, SyncResult};
+use crate::types::{
+    LeaderboardEntry, LeaderboardSnapshot, SnapshotEntry, SyncResult, UserBalance, WeightBreakdown,
+};
 
 pub const WEIGHT_PER_POINT: f64 = 0.02;
 pub const STAR_BONUS_PER_REPO: f64 = 0.25;
+pub const INVALID_PENALTY: f64 = 1.0;
+pub const DUPLICATE_PENALTY: f64 = 0.5;
+pub const MALICIOUS_PENALTY: f64 = 5.0;
+
+const MS_PER_DAY: i64 = 86_400_000;
+/// Daily leaderboard snapshots kept for `/leaderboard/movers`.
+pub const MAX_SNAPSHOT_DAYS: u32 = 30;
+/// Gainers and losers returned by `/leaderboard/movers`, each.
+pub const MAX_MOVERS: usize = 10;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct WeightAssignment {
@@ -14,6 +25,42 @@ pub struct WeightAssignment {
     pub weight: f64,
 }
 
+/// Canonical `GET /get_weights` body.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WeightsExport {
+    pub epoch: u64,
+    /// Fraction of emission going to miners before normalization; the rest
+    /// is burned while the network is below the maturity threshold.
+    pub emission_scale: f64,
+    /// Miner shares, normalized to sum to 1.0 (empty if no miner has weight).
+    pub weights: Vec<WeightAssignment>,
+}
+
+/// Scale `weights` so they sum to 1.0 and put them in [`sort_weights`]
+/// order. Returns the sum before scaling. All-zero input stays all zero:
+/// when no miner earned weight, the whole emission is burned rather than
+/// split evenly.
+pub fn normalize_weights(weights: &mut [WeightAssignment]) -> f64 {
+    let total: f64 = weights.iter().map(|w| w.weight).sum();
+    if total > 0.0 {
+        for w in weights.iter_mut() {
+            w.weight /= total;
+        }
+    }
+    sort_weights(weights);
+    total
+}
+
+/// Sort descending by weight, breaking ties by hotkey, so validators with
+/// the same data publish byte-identical weight vectors.
+pub fn sort_weights(weights: &mut [WeightAssignment]) {
+    weights.sort_by(|a, b| {
+        b.weight
+            .total_cmp(&a.weight)
+            .then_with(|| a.hotkey.cmp(&b.hotkey))
+    });
+}
+
 pub fn calculate_weight_from_points(valid_count: u32, star_count: u32) -> f64 {
     let issue_points = valid_count as f64;
     let star_points = star_count as f64 * STAR_BONUS_PER_REPO;
@@ -21,19 +68,109 @@ pub fn calculate_weight_from_points(valid_count: u32, star_count: u32) -> f64 {
     total_points * WEIGHT_PER_POINT
 }
 
+/// Penalty weights for invalid and duplicate issues, and the net-points
+/// level at or below which a penalized miner is flagged. Set by the sudo
+/// owner through `/sudo/penalty_config`; the defaults are the historical
+/// constants.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PenaltyConfig {
+    pub invalid_weight: f64,
+    pub duplicate_weight: f64,
+    pub penalty_threshold: f64,
+}
+
+impl Default for PenaltyConfig {
+    fn default() -> Self {
+        Self {
+            invalid_weight: INVALID_PENALTY,
+            duplicate_weight: DUPLICATE_PENALTY,
+            penalty_threshold: 0.0,
+        }
+    }
+}
+
+impl PenaltyConfig {
+    /// Weights must be finite and non-negative; the threshold finite.
+    pub fn is_valid(&self) -> bool {
+        self.invalid_weight.is_finite()
+            && self.invalid_weight >= 0.0
+            && self.duplicate_weight.is_finite()
+            && self.duplicate_weight >= 0.0
+            && self.penalty_threshold.is_finite()
+    }
+
+    fn penalty_points(&self, balance: &UserBalance) -> f64 {
+        balance.invalid_count as f64 * self.invalid_weight
+            + balance.duplicate_count as f64 * self.duplicate_weight
+            + balance.malicious_count as f64 * MALICIOUS_PENALTY
+    }
+
+    /// Net points before clamping at zero.
+    fn raw_net_points(&self, balance: &UserBalance) -> f64 {
+        balance.valid_count as f64 + balance.star_count as f64 * STAR_BONUS_PER_REPO
+            - self.penalty_points(balance)
+    }
+
+    /// A miner is penalized once penalties push their net points to or below
+    /// `penalty_threshold`.
+    pub fn is_penalized(&self, balance: &UserBalance) -> bool {
+        self.penalty_points(balance) > 0.0 && self.raw_net_points(balance) <= self.penalty_threshold
+    }
+}
+
 pub fn calculate_net_points(
+    config: &PenaltyConfig,
     valid_count: u32,
     invalid_count: u32,
     duplicate_count: u32,
     malicious_count: u32,
     star_count: u32,
 ) -> f64 {
-    let valid = valid_count as f64;
-    let invalid = invalid_count as f64;
-    let duplicate = duplicate_count as f64 * 0.5;
-    let malicious = malicious_count as f64 * 5.0;
-    let star_points = star_count as f64 * STAR_BONUS_PER_REPO;
-    (valid - invalid - duplicate - malicious + star_points).max(0.0)
+    let balance = UserBalance {
+        valid_count,
+        invalid_count,
+        duplicate_count,
+        star_count,
+        is_penalized: false,
+        malicious_count,
+    };
+    config.raw_net_points(&balance).max(0.0)
+}
+
+/// Break a balance down into the terms of `calculate_net_points`.
+/// `normalized_weight` is the miner's share from the weight assignments; it is
+/// forced to 0 for banned miners or when penalties consume all points.
+/// `miners` is left at 0 for the caller to fill in.
+pub fn weight_breakdown(
+    config: &PenaltyConfig,
+    balance: &UserBalance,
+    is_banned: bool,
+    normalized_weight: f64,
+) -> WeightBreakdown {
+    let net_points = calculate_net_points(
+        config,
+        balance.valid_count,
+        balance.invalid_count,
+        balance.duplicate_count,
+        balance.malicious_count,
+        balance.star_count,
+    );
+    let normalized_weight = if is_banned || net_points <= 0.0 {
+        0.0
+    } else {
+        normalized_weight
+    };
+
+    WeightBreakdown {
+        issue_points: balance.valid_count as f64,
+        invalid_penalty: balance.invalid_count as f64 * config.invalid_weight,
+        duplicate_penalty: balance.duplicate_count as f64 * config.duplicate_weight,
+        malicious_penalty: balance.malicious_count as f64 * MALICIOUS_PENALTY,
+        star_bonus: balance.star_count as f64 * STAR_BONUS_PER_REPO,
+        net_points,
+        normalized_weight,
+        miners: 0,
+    }
 }
 
 /// Compute weights deterministically from committed issues in P2P storage.
@@ -73,6 +210,7 @@ pub fn compute_weights_from_issues() -> Vec<WeightAssignment> {
     }
 
     // Build leaderboard entries in-memory
+    let config = storage::get_penalty_config();
     let mut entries = Vec::with_capacity(hotkeys.len());
     for hotkey in &hotkeys {
         let hk = crate::ss58::normalize_hotkey(hotkey).unwrap_or_else(|| hotkey.clone());
@@ -81,7 +219,7 @@ pub fn compute_weights_from_issues() -> Vec<WeightAssignment> {
         let duplicate = duplicate_counts.get(&hk).copied().unwrap_or(0);
         let malicious = malicious_counts.get(&hk).copied().unwrap_or(0);
 
-        let net_points = calculate_net_points(valid, invalid, duplicate, malicious, 0);
+        let net_points = calculate_net_points(&config, valid, invalid, duplicate, malicious, 0);
         entries.push(LeaderboardEntry {
             rank: 0,
             hotkey: hk,
@@ -97,6 +235,7 @@ pub fn compute_weights_from_issues() -> Vec<WeightAssignment> {
             last_epoch: 0,
             duplicate_issues: duplicate,
             malicious_issues: malicious,
+            suspicious: false,
         });
     }
 
@@ -139,13 +278,56 @@ pub fn calculate_weights_from_leaderboard(entries: &[LeaderboardEntry]) -> Vec<W
         }
     }
 
-    weights.sort_by(|a, b| {
-        b.weight
-            .partial_cmp(&a.weight)
-            .unwrap_or(core::cmp::Ordering::Equal)
+    sort_weights(&mut weights);
+    weights
+}
+
+/// Column used to order `GET /leaderboard`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LeaderboardSort {
+    Score,
+    Valid,
+    Invalid,
+    Stars,
+    Net,
+}
+
+impl LeaderboardSort {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "score" => Some(Self::Score),
+            "valid" => Some(Self::Valid),
+            "invalid" => Some(Self::Invalid),
+            "stars" => Some(Self::Stars),
+            "net" => Some(Self::Net),
+            _ => None,
+        }
+    }
+}
+
+/// Sort entries descending by `sort`, breaking ties by hotkey, and reassign
+/// ranks to match the new order.
+pub fn sort_leaderboard(entries: &mut [LeaderboardEntry], sort: LeaderboardSort) {
+    entries.sort_by(|a, b| {
+        let primary = match sort {
+            LeaderboardSort::Score => b
+                .score
+                .partial_cmp(&a.score)
+                .unwrap_or(core::cmp::Ordering::Equal),
+            LeaderboardSort::Net => b
+                .net_points
+                .partial_cmp(&a.net_points)
+                .unwrap_or(core::cmp::Ordering::Equal),
+            LeaderboardSort::Valid => b.valid_issues.cmp(&a.valid_issues),
+            LeaderboardSort::Invalid => b.invalid_issues.cmp(&a.invalid_issues),
+            LeaderboardSort::Stars => b.star_count.cmp(&a.star_count),
+        };
+        primary.then_with(|| a.hotkey.cmp(&b.hotkey))
     });
 
-    weights
+    for (i, entry) in entries.iter_mut().enumerate() {
+        entry.rank = (i + 1) as u32;
+    }
 }
 
 /// Rebuild the leaderboard from registered hotkeys and their balances.
@@ -155,6 +337,8 @@ pub fn calculate_weights_from_leaderboard(entries: &[LeaderboardEntry]) -> Vec<W
 /// write may not have landed yet.
 pub fn rebuild_leaderboard() -> Vec<LeaderboardEntry> {
     let hotkeys = storage::get_registered_hotkeys();
+    let config = storage::get_penalty_config();
+    let flagged = storage::get_suspicious_hotkeys();
     let mut entries = Vec::with_capacity(hotkeys.len());
 
     for hotkey in &hotkeys {
@@ -162,6 +346,7 @@ pub fn rebuild_leaderboard() -> Vec<LeaderboardEntry> {
         let github_username = storage::get_github_by_hotkey(hotkey).unwrap_or_default();
 
         let net_points = calculate_net_points(
+            &config,
             balance.valid_count,
             balance.invalid_count,
             balance.duplicate_count,
@@ -172,10 +357,12 @@ pub fn rebuild_leaderboard() -> Vec<LeaderboardEntry> {
 
         let epoch = platform_challenge_sdk_wasm::host_functions::host_consensus_get_epoch();
         let current_epoch = if epoch >= 0 { epoch as u64 } else { 0 };
+        let hotkey = crate::ss58::normalize_hotkey(hotkey).unwrap_or_else(|| hotkey.clone());
+        let suspicious = flagged.contains(&hotkey);
 
         entries.push(LeaderboardEntry {
             rank: 0,
-            hotkey: crate::ss58::normalize_hotkey(hotkey).unwrap_or_else(|| hotkey.clone()),
+            hotkey,
             github_username,
             score,
             valid_issues: balance.valid_count,
@@ -184,22 +371,15 @@ pub fn rebuild_leaderboard() -> Vec<LeaderboardEntry> {
             star_count: balance.star_count,
             star_bonus: balance.star_count as f64 * STAR_BONUS_PER_REPO,
             net_points,
-            is_penalized: balance.is_penalized,
+            is_penalized: config.is_penalized(&balance),
             last_epoch: current_epoch,
             duplicate_issues: balance.duplicate_count,
             malicious_issues: balance.malicious_count,
+            suspicious,
         });
     }
 
-    entries.sort_by(|a, b| {
-        b.net_points
-            .partial_cmp(&a.net_points)
-            .unwrap_or(core::cmp::Ordering::Equal)
-    });
-
-    for (i, entry) in entries.iter_mut().enumerate() {
-        entry.rank = (i + 1) as u32;
-    }
+    sort_leaderboard(&mut entries, LeaderboardSort::Net);
 
     storage::store_leaderboard(&entries);
     entries
@@ -221,11 +401,154 @@ pub fn maybe_refresh() {
     }
 }
 
+/// Net-point change of one miner between two leaderboard snapshots.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Mover {
+    pub hotkey: String,
+    pub github_username: String,
+    pub net_points_before: f64,
+    pub net_points_now: f64,
+    pub change: f64,
+}
+
+/// `GET /leaderboard/movers` body.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct MoversReport {
+    pub window_days: u32,
+    /// When the compared snapshots were taken; `None` until two exist.
+    pub from_ms: Option<i64>,
+    pub to_ms: Option<i64>,
+    /// Largest gains first.
+    pub gainers: Vec<Mover>,
+    /// Largest losses first.
+    pub losers: Vec<Mover>,
+}
+
+/// Parse a movers window such as `7d` or `7` into days (1 to `MAX_SNAPSHOT_DAYS`).
+pub fn parse_window_days(value: &str) -> Option<u32> {
+    let days: u32 = value.strip_suffix('d').unwrap_or(value).parse().ok()?;
+    (1..=MAX_SNAPSHOT_DAYS).contains(&days).then_some(days)
+}
+
+/// Append a snapshot of `entries` unless one was already taken on the UTC
+/// day of `now_ms`, keeping only the last `MAX_SNAPSHOT_DAYS`. Returns
+/// whether a snapshot was added.
+fn push_daily_snapshot(
+    snapshots: &mut Vec<LeaderboardSnapshot>,
+    entries: &[LeaderboardEntry],
+    now_ms: i64,
+) -> bool {
+    let day = now_ms.div_euclid(MS_PER_DAY);
+    if snapshots.last().is_some_and(|s| s.day >= day) {
+        return false;
+    }
+    snapshots.push(LeaderboardSnapshot {
+        day,
+        taken_at_ms: now_ms,
+        entries: entries
+            .iter()
+            .map(|e| SnapshotEntry {
+                hotkey: e.hotkey.clone(),
+                github_username: e.github_username.clone(),
+                net_points: e.net_points,
+            })
+            .collect(),
+    });
+    let max = MAX_SNAPSHOT_DAYS as usize + 1;
+    if snapshots.len() > max {
+        let excess = snapshots.len() - max;
+        snapshots.drain(..excess);
+    }
+    true
+}
+
+/// Store today's leaderboard snapshot if it hasn't been taken yet. The
+/// snapshots themselves are only read once a day.
+pub fn record_daily_snapshot(entries: &[LeaderboardEntry], now_ms: i64) {
+    let day = now_ms.div_euclid(MS_PER_DAY);
+    if storage::get_last_snapshot_day().is_some_and(|last| last >= day) {
+        return;
+    }
+    let mut snapshots = storage::get_leaderboard_snapshots();
+    if push_daily_snapshot(&mut snapshots, entries, now_ms) {
+        storage::store_leaderboard_snapshots(&snapshots);
+    } else if let Some(last) = snapshots.last() {
+        // Snapshots taken before the day key existed.
+        storage::store_last_snapshot_day(last.day);
+    }
+}
+
+/// Compare the latest snapshot with the oldest one inside the window.
+/// Miners missing from the window-start snapshot count from zero; miners
+/// missing from the latest one (deregistered) are left out.
+pub fn compute_movers(
+    snapshots: &[LeaderboardSnapshot],
+    window_days: u32,
+    limit: usize,
+) -> MoversReport {
+    let mut report = MoversReport {
+        window_days,
+        from_ms: None,
+        to_ms: None,
+        gainers: Vec::new(),
+        losers: Vec::new(),
+    };
+    let latest = match snapshots.last() {
+        Some(s) => s,
+        None => return report,
+    };
+    let start_day = latest.day - window_days as i64;
+    let start = match snapshots.iter().find(|s| s.day >= start_day) {
+        Some(s) if s.day < latest.day => s,
+        _ => return report,
+    };
+    report.from_ms = Some(start.taken_at_ms);
+    report.to_ms = Some(latest.taken_at_ms);
+
+    for entry in &latest.entries {
+        let before = start
+            .entries
+            .iter()
+            .find(|e| e.hotkey == entry.hotkey)
+            .map(|e| e.net_points)
+            .unwrap_or(0.0);
+        let mover = Mover {
+            hotkey: entry.hotkey.clone(),
+            github_username: entry.github_username.clone(),
+            net_points_before: before,
+            net_points_now: entry.net_points,
+            change: entry.net_points - before,
+        };
+        if mover.change > 0.0 {
+            report.gainers.push(mover);
+        } else if mover.change < 0.0 {
+            report.losers.push(mover);
+        }
+    }
+
+    report.gainers.sort_by(|a, b| {
+        b.change
+            .partial_cmp(&a.change)
+            .unwrap_or(core::cmp::Ordering::Equal)
+            .then_with(|| a.hotkey.cmp(&b.hotkey))
+    });
+    report.losers.sort_by(|a, b| {
+        a.change
+            .partial_cmp(&b.change)
+            .unwrap_or(core::cmp::Ordering::Equal)
+            .then_with(|| a.hotkey.cmp(&b.hotkey))
+    });
+    report.gainers.truncate(limit);
+    report.losers.truncate(limit);
+    report
+}
+
 /// Called every ~12s on the persistent WASM instance.
 /// Recount balances and rebuild leaderboard on every tick.
 /// Every ~30 min, fetch fresh issues from GitHub (throttled by timestamp).
 pub fn background_tick() {
     const GITHUB_FETCH_INTERVAL_MS: i64 = 30 * 60 * 1000;
+    const STAR_REFRESH_INTERVAL_MS: i64 = 6 * 60 * 60 * 1000;
 
     let now = platform_challenge_sdk_wasm::host_functions::host_get_timestamp();
     let last = storage::get_last_refreshed();
@@ -235,8 +558,17 @@ pub fn background_tick() {
         storage::store_last_refreshed(now);
     }
 
+    // Star lists change rarely and cost one request per miner: refresh less often
+    let last_stars = storage::get_last_star_refresh();
+    if last_stars == 0 || (now - last_stars) >= STAR_REFRESH_INTERVAL_MS {
+        crate::github_sync::refresh_star_counts(crate::github_sync::env_github_token().as_deref());
+        storage::store_last_star_refresh(now);
+    }
+
     storage::recount_all_balances();
-    rebuild_leaderboard();
+    crate::abuse::maybe_refresh_suspicious_hotkeys(now);
+    let entries = rebuild_leaderboard();
+    record_daily_snapshot(&entries, now);
 }
 
 /// Perform a full sync: rebuild leaderboard and return sync result for consensus
@@ -252,6 +584,7 @@ pub fn perform_sync() -> SyncResult {
     }
 
     storage::recount_all_balances();
+    crate::abuse::maybe_refresh_suspicious_hotkeys(now);
 
     let entries = rebuild_leaderboard();
     let hotkeys = storage::get_registered_hotkeys();
@@ -292,3 +625,294 @@ pub fn perform_sync() -> SyncResult {
         sync_timestamp: epoch,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weight_breakdown_penalized_miner_has_zero_weight() {
+        let balance = UserBalance {
+            valid_count: 3,
+            invalid_count: 2,
+            duplicate_count: 2,
+            star_count: 0,
+            is_penalized: true,
+            malicious_count: 0,
+        };
+
+        let breakdown = weight_breakdown(&PenaltyConfig::default(), &balance, false, 0.4);
+
+        assert_eq!(breakdown.issue_points, 3.0);
+        assert_eq!(breakdown.invalid_penalty, 2.0);
+        assert_eq!(breakdown.duplicate_penalty, 1.0);
+        assert_eq!(breakdown.net_points, 0.0);
+        assert_eq!(breakdown.normalized_weight, 0.0);
+    }
+
+    fn entry(hotkey: &str, valid: u32, invalid: u32) -> LeaderboardEntry {
+        LeaderboardEntry {
+            rank: 0,
+            hotkey: String::from(hotkey),
+            github_username: String::new(),
+            score: 0.0,
+            valid_issues: valid,
+            invalid_issues: invalid,
+            pending_issues: 0,
+            star_count: 0,
+            star_bonus: 0.0,
+            net_points: calculate_net_points(&PenaltyConfig::default(), valid, invalid, 0, 0, 0),
+            is_penalized: invalid > valid,
+            last_epoch: 0,
+            duplicate_issues: 0,
+            malicious_issues: 0,
+            suspicious: false,
+        }
+    }
+
+    #[test]
+    fn test_sort_leaderboard_by_invalid() {
+        let mut entries = alloc::vec![entry("a", 10, 1), entry("b", 2, 7), entry("c", 5, 3)];
+
+        sort_leaderboard(&mut entries, LeaderboardSort::Invalid);
+
+        assert_eq!(entries[0].hotkey, "b");
+        assert_eq!(entries[0].rank, 1);
+        assert_eq!(entries[1].hotkey, "c");
+        assert_eq!(entries[2].hotkey, "a");
+    }
+
+    #[test]
+    fn test_sort_leaderboard_ties_break_by_hotkey() {
+        let mut entries = alloc::vec![entry("z", 1, 2), entry("m", 4, 2), entry("a", 0, 2)];
+
+        sort_leaderboard(&mut entries, LeaderboardSort::Invalid);
+
+        let order: Vec<&str> = entries.iter().map(|e| e.hotkey.as_str()).collect();
+        assert_eq!(order, ["a", "m", "z"]);
+        assert_eq!(LeaderboardSort::parse("bogus"), None);
+    }
+
+    #[test]
+    fn test_normalize_weights_sums_to_one() {
+        let mut weights = alloc::vec![
+            WeightAssignment {
+                hotkey: String::from("a"),
+                weight: 0.3,
+            },
+            WeightAssignment {
+                hotkey: String::from("b"),
+                weight: 0.1,
+            },
+        ];
+
+        let total = normalize_weights(&mut weights);
+
+        assert!((total - 0.4).abs() < 1e-12);
+        assert!((weights[0].weight - 0.75).abs() < 1e-12);
+        let sum: f64 = weights.iter().map(|w| w.weight).sum();
+        assert!((sum - 1.0).abs() < 1e-12);
+
+        let mut empty: Vec<WeightAssignment> = Vec::new();
+        assert_eq!(normalize_weights(&mut empty), 0.0);
+    }
+
+    fn weights(pairs: &[(&str, f64)]) -> Vec<WeightAssignment> {
+        pairs
+            .iter()
+            .map(|(hotkey, weight)| WeightAssignment {
+                hotkey: String::from(*hotkey),
+                weight: *weight,
+            })
+            .collect()
+    }
+
+    fn pairs(weights: &[WeightAssignment]) -> Vec<(&str, f64)> {
+        weights
+            .iter()
+            .map(|w| (w.hotkey.as_str(), w.weight))
+            .collect()
+    }
+
+    #[test]
+    fn test_normalize_all_zero_weights_stay_zero() {
+        let mut all_zero = weights(&[("b", 0.0), ("a", 0.0)]);
+
+        assert_eq!(normalize_weights(&mut all_zero), 0.0);
+        assert_eq!(pairs(&all_zero), [("a", 0.0), ("b", 0.0)]);
+    }
+
+    #[test]
+    fn test_normalize_single_miner_gets_everything() {
+        let mut single = weights(&[("a", 0.02)]);
+
+        assert_eq!(normalize_weights(&mut single), 0.02);
+        assert_eq!(pairs(&single), [("a", 1.0)]);
+    }
+
+    #[test]
+    fn test_normalize_ties_break_by_hotkey() {
+        let mut tied = weights(&[("c", 1.0), ("b", 2.0), ("a", 1.0), ("d", 2.0)]);
+        let mut shuffled = weights(&[("d", 2.0), ("a", 1.0), ("c", 1.0), ("b", 2.0)]);
+
+        normalize_weights(&mut tied);
+        normalize_weights(&mut shuffled);
+
+        let order: Vec<&str> = tied.iter().map(|w| w.hotkey.as_str()).collect();
+        assert_eq!(order, ["b", "d", "a", "c"]);
+        assert_eq!(pairs(&tied), pairs(&shuffled));
+        assert!((tied[0].weight - 1.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_weight_breakdown_keeps_normalized_weight() {
+        let balance = UserBalance {
+            valid_count: 4,
+            star_count: 2,
+            ..UserBalance::default()
+        };
+
+        let breakdown = weight_breakdown(&PenaltyConfig::default(), &balance, false, 0.25);
+
+        assert_eq!(breakdown.star_bonus, 0.5);
+        assert_eq!(breakdown.net_points, 4.5);
+        assert_eq!(breakdown.normalized_weight, 0.25);
+        assert_eq!(
+            weight_breakdown(&PenaltyConfig::default(), &balance, true, 0.25).normalized_weight,
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_invalid_issues_reduce_net_points() {
+        let config = PenaltyConfig {
+            invalid_weight: 2.0,
+            duplicate_weight: 0.5,
+            penalty_threshold: 0.0,
+        };
+        let net: Vec<f64> = (0..4)
+            .map(|invalid| calculate_net_points(&config, 5, invalid, 2, 0, 0))
+            .collect();
+        assert_eq!(net, [4.0, 2.0, 0.0, 0.0]);
+        assert_eq!(
+            calculate_net_points(&PenaltyConfig::default(), 5, 1, 2, 0, 0),
+            3.0
+        );
+    }
+
+    #[test]
+    fn test_crossing_penalty_threshold_sets_is_penalized() {
+        let config = PenaltyConfig {
+            invalid_weight: 1.0,
+            duplicate_weight: 1.0,
+            penalty_threshold: 1.0,
+        };
+        let mut balance = UserBalance {
+            valid_count: 4,
+            invalid_count: 2,
+            ..UserBalance::default()
+        };
+        assert!(!config.is_penalized(&balance));
+
+        balance.duplicate_count = 1;
+        assert!(config.is_penalized(&balance));
+
+        // A new miner with no penalties is never flagged.
+        assert!(!config.is_penalized(&UserBalance::default()));
+    }
+
+    #[test]
+    fn test_penalty_config_validation() {
+        assert!(PenaltyConfig::default().is_valid());
+        let negative = PenaltyConfig {
+            invalid_weight: -1.0,
+            ..PenaltyConfig::default()
+        };
+        assert!(!negative.is_valid());
+        let nan = PenaltyConfig {
+            penalty_threshold: f64::NAN,
+            ..PenaltyConfig::default()
+        };
+        assert!(!nan.is_valid());
+    }
+
+    fn snapshot(day: i64, points: &[(&str, f64)]) -> LeaderboardSnapshot {
+        LeaderboardSnapshot {
+            day,
+            taken_at_ms: day * MS_PER_DAY,
+            entries: points
+                .iter()
+                .map(|(hotkey, net_points)| SnapshotEntry {
+                    hotkey: String::from(*hotkey),
+                    github_username: String::new(),
+                    net_points: *net_points,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_compute_movers_over_window() {
+        let snapshots = alloc::vec![
+            snapshot(100, &[("a", 50.0), ("b", 1.0)]),
+            snapshot(103, &[("a", 10.0), ("b", 2.0), ("c", 4.0)]),
+            snapshot(110, &[("a", 12.0), ("b", 1.0), ("c", 9.0), ("d", 3.0)]),
+        ];
+
+        let report = compute_movers(&snapshots, 7, MAX_MOVERS);
+
+        assert_eq!(report.from_ms, Some(103 * MS_PER_DAY));
+        let gainers: Vec<(&str, f64)> = report
+            .gainers
+            .iter()
+            .map(|m| (m.hotkey.as_str(), m.change))
+            .collect();
+        assert_eq!(gainers, [("c", 5.0), ("d", 3.0), ("a", 2.0)]);
+        assert_eq!(report.losers.len(), 1);
+        assert_eq!(report.losers[0].hotkey, "b");
+
+        assert!(compute_movers(&snapshots[..1], 7, MAX_MOVERS)
+            .gainers
+            .is_empty());
+        assert_eq!(compute_movers(&snapshots, 7, 1).gainers.len(), 1);
+    }
+
+    #[test]
+    fn test_daily_snapshot_taken_once_per_day() {
+        let mut snapshots = Vec::new();
+        assert!(push_daily_snapshot(&mut snapshots, &[], 5 * MS_PER_DAY));
+        assert!(!push_daily_snapshot(
+            &mut snapshots,
+            &[],
+            5 * MS_PER_DAY + 1_000
+        ));
+        assert!(push_daily_snapshot(&mut snapshots, &[], 6 * MS_PER_DAY));
+        assert_eq!(snapshots.len(), 2);
+
+        assert_eq!(parse_window_days("7d"), Some(7));
+        assert_eq!(parse_window_days("0d"), None);
+        assert_eq!(parse_window_days("31"), None);
+    }
+
+    #[test]
+    fn test_tick_skips_snapshot_blob_once_today_is_taken() {
+        storage::host::reset(1);
+        record_daily_snapshot(&[], 5 * MS_PER_DAY);
+        assert_eq!(storage::get_last_snapshot_day(), Some(5));
+
+        // Later ticks the same day never read the blob, so even an
+        // undecodable one is left alone.
+        storage::host::host_storage_set(b"leaderboard_snapshots", b"x").unwrap();
+        record_daily_snapshot(&[], 5 * MS_PER_DAY + 12_000);
+        assert_eq!(
+            storage::host::host_storage_get(b"leaderboard_snapshots").unwrap(),
+            b"x"
+        );
+
+        storage::host::reset(1);
+        record_daily_snapshot(&[], 5 * MS_PER_DAY);
+        record_daily_snapshot(&[], 6 * MS_PER_DAY);
+        assert_eq!(storage::get_leaderboard_snapshots().len(), 2);
+        assert_eq!(storage::get_last_snapshot_day(), Some(6));
+    }
+}
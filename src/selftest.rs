@@ -0,0 +1,124 @@
+//! Active checks behind `GET /selftest`, for orchestrators that gate on more
+//! than liveness. Results are cached briefly so the endpoint cannot be used
+//! to hammer storage or GitHub.
+
+use serde::{Deserialize, Serialize};
+
+use crate::storage;
+
+/// How long a report is served from the cache.
+pub const SELFTEST_CACHE_MS: i64 = 10 * 1000;
+/// Issue data older than this is stale: two missed 30-minute refreshes.
+pub const SYNC_STALE_AFTER_MS: i64 = 60 * 60 * 1000;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SelfTestComponents {
+    /// A value written to storage reads back unchanged.
+    pub db: bool,
+    /// GitHub answered a rate-limit query.
+    pub github: bool,
+    /// Issue data was refreshed within `SYNC_STALE_AFTER_MS`.
+    pub sync_fresh: bool,
+}
+
+/// `GET /selftest` body.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SelfTestReport {
+    /// Every component passed.
+    pub ok: bool,
+    pub components: SelfTestComponents,
+    pub checked_at_ms: i64,
+}
+
+/// Run every check now. `github_reachable` queries GitHub.
+pub fn run_checks(now_ms: i64, github_reachable: &dyn Fn() -> bool) -> SelfTestReport {
+    let last_refreshed = storage::get_last_refreshed();
+    let components = SelfTestComponents {
+        db: storage::storage_round_trip(now_ms),
+        github: github_reachable(),
+        sync_fresh: last_refreshed > 0 && now_ms - last_refreshed < SYNC_STALE_AFTER_MS,
+    };
+    SelfTestReport {
+        ok: components.db && components.github && components.sync_fresh,
+        components,
+        checked_at_ms: now_ms,
+    }
+}
+
+/// The cached report if it is younger than `SELFTEST_CACHE_MS`, otherwise
+/// a fresh one.
+pub fn cached_report(now_ms: i64, github_reachable: &dyn Fn() -> bool) -> SelfTestReport {
+    if let Some(cached) = storage::get_selftest_cache() {
+        let age = now_ms - cached.checked_at_ms;
+        if (0..SELFTEST_CACHE_MS).contains(&age) {
+            return cached;
+        }
+    }
+    let report = run_checks(now_ms, github_reachable);
+    storage::store_selftest_cache(&report);
+    report
+}
+
+/// [`cached_report`] against the live GitHub API.
+pub fn current_report() -> SelfTestReport {
+    let token = crate::github_sync::env_github_token();
+    cached_report(
+        platform_challenge_sdk_wasm::host_functions::host_get_timestamp(),
+        &|| crate::github_sync::github_reachable(token.as_deref()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: i64 = 1_705_590_000_000;
+
+    #[test]
+    fn test_failing_github_keeps_db_healthy() {
+        storage::host::reset(1);
+        storage::store_last_refreshed(NOW - 60_000);
+
+        let report = run_checks(NOW, &|| false);
+
+        assert_eq!(
+            report.components,
+            SelfTestComponents {
+                db: true,
+                github: false,
+                sync_fresh: true,
+            }
+        );
+        assert!(!report.ok);
+        assert!(run_checks(NOW, &|| true).ok);
+    }
+
+    #[test]
+    fn test_stale_sync_fails() {
+        storage::host::reset(1);
+        assert!(!run_checks(NOW, &|| true).components.sync_fresh);
+
+        storage::store_last_refreshed(NOW - SYNC_STALE_AFTER_MS);
+        assert!(!run_checks(NOW, &|| true).ok);
+    }
+
+    #[test]
+    fn test_report_is_cached_briefly() {
+        storage::host::reset(1);
+        storage::store_last_refreshed(NOW);
+        let calls = core::cell::Cell::new(0);
+        let github = || {
+            calls.set(calls.get() + 1);
+            true
+        };
+
+        let first = cached_report(NOW, &github);
+        let again = cached_report(NOW + SELFTEST_CACHE_MS - 1, &github);
+        assert_eq!(first, again);
+        assert_eq!(calls.get(), 1);
+
+        let later = cached_report(NOW + SELFTEST_CACHE_MS, &github);
+        assert_eq!(later.checked_at_ms, NOW + SELFTEST_CACHE_MS);
+        assert_eq!(calls.get(), 2);
+    }
+}
@@ -3,7 +3,7 @@ use alloc::vec::Vec;
 use blake2::{Blake2b512, Digest};
 
 const SS58_PREFIX: &[u8] = b"SS58PRE";
-const SUBSTRATE_PREFIX: u16 = 42; // Generic Substrate
+const SUBSTRATE_PREFIX: u16 = 42; // Generic Substrate; the storage form
 
 /// Convert hex hotkey to SS58 format
 pub fn hex_to_ss58(hex: &str) -> Option<String> {
@@ -35,9 +35,12 @@ pub fn ss58_to_hex(ss58: &str) -> Option<String> {
     Some(hex)
 }
 
-/// Check if string is SS58 format (starts with 5 for Substrate)
+/// Check if string looks like SS58 (base58, the length of a 32-byte key
+/// under a one- or two-byte network prefix)
 pub fn is_ss58(s: &str) -> bool {
-    s.starts_with('5') && s.len() >= 47 && s.len() <= 48
+    (46..=50).contains(&s.len())
+        && s.bytes()
+            .all(|b| b.is_ascii_alphanumeric() && !matches!(b, b'0' | b'O' | b'I' | b'l'))
 }
 
 /// Check if string is hex format (64 hex chars)
@@ -46,19 +49,22 @@ pub fn is_hex(s: &str) -> bool {
     s.len() == 64 && s.chars().all(|c| c.is_ascii_hexdigit())
 }
 
-/// Normalize hotkey to SS58 format
+/// Normalize hotkey to SS58 format under the generic prefix. SS58 input is
+/// accepted under any network prefix, so every validator maps an address to
+/// the same key whatever its environment.
 pub fn normalize_hotkey(hotkey: &str) -> Option<String> {
-    if is_ss58(hotkey) {
-        // Validate it's a real SS58
-        if decode_ss58(hotkey).is_some() {
-            return Some(hotkey.to_string());
-        }
-        return None;
-    }
     if is_hex(hotkey) {
         return hex_to_ss58(hotkey);
     }
-    None
+    if !is_ss58(hotkey) {
+        return None;
+    }
+    // Validate it's a real SS58
+    let (prefix, pubkey) = decode_ss58_with_prefix(hotkey)?;
+    if prefix == SUBSTRATE_PREFIX {
+        return Some(hotkey.to_string());
+    }
+    Some(encode_ss58(&pubkey, SUBSTRATE_PREFIX))
 }
 
 /// Get canonical storage key for a hotkey (always SS58)
@@ -85,13 +91,17 @@ fn encode_ss58(pubkey: &[u8; 32], prefix: u16) -> String {
 }
 
 fn decode_ss58(ss58: &str) -> Option<[u8; 32]> {
+    decode_ss58_with_prefix(ss58).map(|(_, pubkey)| pubkey)
+}
+
+fn decode_ss58_with_prefix(ss58: &str) -> Option<(u16, [u8; 32])> {
     let data = bs58::decode(ss58).into_vec().ok()?;
 
     if data.len() < 35 {
         return None;
     }
 
-    let (prefix_len, _prefix) = if data[0] & 0x40 != 0 {
+    let (prefix_len, prefix) = if data[0] & 0x40 != 0 {
         (
             2,
             ((data[0] as u16 & 0x3F) << 2)
@@ -117,7 +127,7 @@ fn decode_ss58(ss58: &str) -> Option<[u8; 32]> {
 
     let mut pubkey = [0u8; 32];
     pubkey.copy_from_slice(&data[pubkey_start..pubkey_end]);
-    Some(pubkey)
+    Some((prefix, pubkey))
 }
 
 fn ss58_checksum(data: &[u8]) -> [u8; 64] {
@@ -154,4 +164,31 @@ mod tests {
         let ss58_2 = normalize_hotkey(&ss58).unwrap();
         assert_eq!(ss58, ss58_2);
     }
+
+    #[test]
+    fn test_any_prefix_normalizes_to_generic() {
+        let pubkey: [u8; 32] = core::array::from_fn(|i| (i as u8).wrapping_mul(37));
+        let generic = encode_ss58(&pubkey, SUBSTRATE_PREFIX);
+        // Polkadot's one-byte prefix and a two-byte subnet prefix.
+        for prefix in [0u16, 1024] {
+            let address = encode_ss58(&pubkey, prefix);
+            assert_ne!(address, generic);
+            assert_eq!(decode_ss58_with_prefix(&address), Some((prefix, pubkey)));
+
+            assert_eq!(
+                normalize_hotkey(&address).as_deref(),
+                Some(generic.as_str())
+            );
+        }
+        assert_eq!(
+            normalize_hotkey(&generic).as_deref(),
+            Some(generic.as_str())
+        );
+
+        // A corrupted checksum is still rejected.
+        let mut bad = generic.clone().into_bytes();
+        let last = bad.len() - 1;
+        bad[last] = if bad[last] == b'2' { b'3' } else { b'2' };
+        assert_eq!(normalize_hotkey(core::str::from_utf8(&bad).unwrap()), None);
+    }
 }
@@ -1,16 +1,34 @@
+use alloc::collections::BTreeSet;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
-use platform_challenge_sdk_wasm::host_functions::{
-    host_consensus_get_epoch, host_storage_get, host_storage_list_prefix, host_storage_set,
-};
 use serde::Deserialize;
 
+use super::host::{
+    host_consensus_get_epoch, host_storage_get, host_storage_list_prefix, host_storage_set,
+};
+use crate::abuse::SuspicionConfig;
+use crate::scoring::PenaltyConfig;
+use crate::selftest::SelfTestReport;
 use crate::ss58;
 use crate::types::{
-    InvalidIssueRecord, IssueRecord, LeaderboardEntry, UserBalance, UserRegistration,
+    AuditEntry, CachedClaim, ClaimResult, ExportBundle, ExportMiner, ExportRow, ImportReport,
+    InvalidIssueRecord, IssueFailure, IssueRecord, LeaderboardEntry, LeaderboardSnapshot,
+    TokenBucket, UserBalance, UserRegistration,
 };
+use crate::validation::VerificationPolicy;
 
 const MAX_SYNCED_ISSUES: usize = 500_000;
+/// How long a claim result is replayed for a repeated idempotency key.
+pub const CLAIM_IDEMPOTENCY_TTL_MS: i64 = 24 * 60 * 60 * 1000;
+/// Oldest audit entries are dropped beyond this many.
+const MAX_AUDIT_ENTRIES: usize = 10_000;
+/// Requests per minute each hotkey may send to each rate-limited route
+/// until the sudo owner sets a limit.
+pub const DEFAULT_RATE_LIMIT_PER_MINUTE: u32 = 10;
+/// Minimum time between two GitHub username changes of one hotkey.
+pub const RELINK_COOLDOWN_MS: i64 = 7 * 24 * 60 * 60 * 1000;
+/// Consecutive sync failures after which an issue is dead-lettered.
+pub const MAX_ISSUE_FAILURES: u32 = 3;
 
 fn make_key(prefix: &[u8], suffix: &str) -> Vec<u8> {
     let mut key = Vec::from(prefix);
@@ -50,12 +68,27 @@ pub fn register_user(github_username: &str, hotkey: &str) -> bool {
         }
     }
 
-    write_registration(github_username, &hotkey_ss58)
+    write_registration(github_username, &hotkey_ss58, current_epoch())
+}
+
+fn current_epoch() -> u64 {
+    let epoch = host_consensus_get_epoch();
+    if epoch >= 0 {
+        epoch as u64
+    } else {
+        0
+    }
 }
 
 /// Force-register a user, overwriting any existing mapping.
 /// Used by sudo to reassign github usernames to new hotkeys.
 pub fn force_register_user(github_username: &str, hotkey: &str) -> bool {
+    force_register_user_at(github_username, hotkey, current_epoch())
+}
+
+/// [`force_register_user`] with an explicit registration epoch, for restoring
+/// registrations from an export.
+fn force_register_user_at(github_username: &str, hotkey: &str, registered_epoch: u64) -> bool {
     let hotkey_ss58 = normalize_hotkey_for_storage(hotkey);
 
     // Clear old github->hotkey mapping if it exists
@@ -75,17 +108,14 @@ pub fn force_register_user(github_username: &str, hotkey: &str) -> bool {
         }
     }
 
-    write_registration(github_username, &hotkey_ss58)
+    write_registration(github_username, &hotkey_ss58, registered_epoch)
 }
 
-fn write_registration(github_username: &str, hotkey_ss58: &str) -> bool {
-    let epoch = host_consensus_get_epoch();
-    let current_epoch = if epoch >= 0 { epoch as u64 } else { 0 };
-
+fn write_registration(github_username: &str, hotkey_ss58: &str, registered_epoch: u64) -> bool {
     let registration = UserRegistration {
         hotkey: String::from(hotkey_ss58),
         github_username: String::from(github_username),
-        registered_epoch: current_epoch,
+        registered_epoch,
     };
 
     let data = match bincode::serialize(&registration) {
@@ -95,17 +125,117 @@ fn write_registration(github_username: &str, hotkey_ss58: &str) -> bool {
 
     let user_key = make_key(b"user:", hotkey_ss58);
     if host_storage_set(&user_key, &data).is_err() {
+        crate::metrics::record_storage_error();
         return false;
     }
 
     let github_key = make_key(b"github:", &github_username.to_lowercase());
     if host_storage_set(&github_key, hotkey_ss58.as_bytes()).is_err() {
+        crate::metrics::record_storage_error();
         return false;
     }
 
     true
 }
 
+/// Outcome of [`update_github`].
+#[derive(Debug, PartialEq)]
+pub enum RelinkOutcome {
+    /// Carries the previously linked username.
+    Relinked(String),
+    NotRegistered,
+    /// The new username is registered to another hotkey, which is carried.
+    GithubTaken(String),
+    /// The hotkey changed its username recently; carries when (ms) it may
+    /// change it again.
+    CoolingDown(i64),
+    StorageError,
+}
+
+/// Why `hotkey_ss58` (currently `current`) may not switch to a username
+/// that is registered to `owner_of_new`, if anything.
+fn relink_rejection(
+    hotkey_ss58: &str,
+    current: Option<&UserRegistration>,
+    owner_of_new: Option<&str>,
+) -> Option<RelinkOutcome> {
+    if current.is_none() {
+        return Some(RelinkOutcome::NotRegistered);
+    }
+    match owner_of_new {
+        Some(owner) if owner != hotkey_ss58 => Some(RelinkOutcome::GithubTaken(owner.to_string())),
+        _ => None,
+    }
+}
+
+/// When (ms) `hotkey` may next change its GitHub username, if it relinked
+/// or unregistered within `RELINK_COOLDOWN_MS` of `now_ms`.
+pub fn relink_cooldown_until(hotkey: &str, now_ms: i64) -> Option<i64> {
+    let hotkey_ss58 = normalize_hotkey_for_storage(hotkey);
+    let data = host_storage_get(&make_key(b"relinked_at:", &hotkey_ss58)).ok()?;
+    let last = i64::from_le_bytes(data.as_slice().try_into().ok()?);
+    let until = last.saturating_add(RELINK_COOLDOWN_MS);
+    (now_ms < until).then_some(until)
+}
+
+fn store_relinked_at(hotkey_ss58: &str, now_ms: i64) {
+    let _ = host_storage_set(
+        &make_key(b"relinked_at:", hotkey_ss58),
+        &now_ms.to_le_bytes(),
+    );
+}
+
+/// Link a registered hotkey to a different GitHub username, e.g. after a
+/// rename. The registration epoch is kept, and issues already recorded for
+/// the hotkey stay with it. A hotkey may change its username once per
+/// `RELINK_COOLDOWN_MS`.
+pub fn update_github(hotkey: &str, new_username: &str, now_ms: i64) -> RelinkOutcome {
+    let hotkey_ss58 = normalize_hotkey_for_storage(hotkey);
+    let current = get_user_by_hotkey(&hotkey_ss58);
+    let owner_of_new = get_hotkey_by_github(new_username);
+    if let Some(rejection) =
+        relink_rejection(&hotkey_ss58, current.as_ref(), owner_of_new.as_deref())
+    {
+        return rejection;
+    }
+    let current = match current {
+        Some(reg) => reg,
+        None => return RelinkOutcome::NotRegistered,
+    };
+
+    let renamed = current.github_username.to_lowercase() != new_username.to_lowercase();
+    if renamed {
+        if let Some(until) = relink_cooldown_until(&hotkey_ss58, now_ms) {
+            return RelinkOutcome::CoolingDown(until);
+        }
+        let old_github_key = make_key(b"github:", &current.github_username.to_lowercase());
+        let _ = host_storage_set(&old_github_key, &[]);
+    }
+    if !write_registration(new_username, &hotkey_ss58, current.registered_epoch) {
+        return RelinkOutcome::StorageError;
+    }
+    if renamed {
+        store_relinked_at(&hotkey_ss58, now_ms);
+    }
+    RelinkOutcome::Relinked(current.github_username)
+}
+
+/// Remove a hotkey's registration. Returns the unlinked GitHub username, or
+/// `None` if the hotkey was not registered. Balances and issue records are
+/// kept, but the hotkey no longer appears on the leaderboard. Registering
+/// again counts as a relink, so it waits out `RELINK_COOLDOWN_MS`.
+pub fn unregister_user(hotkey: &str, now_ms: i64) -> Option<String> {
+    let hotkey_ss58 = normalize_hotkey_for_storage(hotkey);
+    let registration = get_user_by_hotkey(&hotkey_ss58)?;
+
+    store_relinked_at(&hotkey_ss58, now_ms);
+    let github_key = make_key(b"github:", &registration.github_username.to_lowercase());
+    let _ = host_storage_set(&github_key, &[]);
+    let _ = host_storage_set(&make_key(b"user:", &hotkey_ss58), &[]);
+    let _ = host_storage_set(&make_key(b"hotkey_idx:", &hotkey_ss58), &[]);
+    Some(registration.github_username)
+}
+
 pub fn get_user_by_hotkey(hotkey: &str) -> Option<UserRegistration> {
     let hotkey_ss58 = normalize_hotkey_for_storage(hotkey);
 
@@ -171,6 +301,9 @@ pub fn record_valid_issue(
         has_duplicate_label: false,
         has_malicious_label: false,
         created_at_ms: 0,
+        labels: Vec::new(),
+        assignee: None,
+        milestone: None,
     };
 
     let data = match bincode::serialize(&record) {
@@ -179,12 +312,58 @@ pub fn record_valid_issue(
     };
 
     if host_storage_set(&key, &data).is_err() {
+        crate::metrics::record_storage_error();
         return false;
     }
 
     true
 }
 
+/// Outcome of [`claim_issue`].
+pub enum ClaimOutcome {
+    Claimed,
+    /// The issue already had a record; carries it when it could be decoded.
+    AlreadyClaimed(Option<IssueRecord>),
+    StorageError,
+}
+
+/// Check that an issue is unclaimed and record it for `hotkey` in one step,
+/// so callers never act on a stale `is_issue_recorded` answer.
+pub fn claim_issue(
+    issue_number: u32,
+    repo_owner: &str,
+    repo_name: &str,
+    author: &str,
+    hotkey: &str,
+) -> ClaimOutcome {
+    if let Ok(data) = host_storage_get(&issue_key(repo_owner, repo_name, issue_number)) {
+        if !data.is_empty() {
+            return ClaimOutcome::AlreadyClaimed(deserialize_issue_record(&data));
+        }
+    }
+
+    if record_valid_issue(issue_number, repo_owner, repo_name, author, hotkey) {
+        ClaimOutcome::Claimed
+    } else {
+        ClaimOutcome::StorageError
+    }
+}
+
+/// The hotkey an issue being penalized is charged to: the one already
+/// recorded on it, or else whoever `github_username` is linked to now. An
+/// author cannot move a penalty by relinking or unregistering first.
+fn bound_hotkey(
+    repo_owner: &str,
+    repo_name: &str,
+    issue_number: u32,
+    github_username: &str,
+) -> Option<String> {
+    get_issue_record(repo_owner, repo_name, issue_number)
+        .and_then(|r| r.claimed_by_hotkey)
+        .filter(|h| !h.is_empty())
+        .or_else(|| get_hotkey_by_github(github_username))
+}
+
 pub fn record_invalid_issue(
     issue_number: u32,
     repo_owner: &str,
@@ -217,12 +396,13 @@ pub fn record_invalid_issue(
     };
 
     if host_storage_set(&inv_key, &inv_data).is_err() {
+        crate::metrics::record_storage_error();
         return false;
     }
 
     // Also write an IssueRecord under the canonical issue: key so
     // get_issue_record/is_issue_recorded can detect label changes
-    let hotkey = get_hotkey_by_github(github_username);
+    let hotkey = bound_hotkey(repo_owner, repo_name, issue_number, github_username);
     let issue_record = IssueRecord {
         issue_number,
         repo_owner: String::from(repo_owner),
@@ -237,6 +417,9 @@ pub fn record_invalid_issue(
         has_duplicate_label: false,
         has_malicious_label: false,
         created_at_ms: 0,
+        labels: Vec::new(),
+        assignee: None,
+        milestone: None,
     };
     let key = issue_key(repo_owner, repo_name, issue_number);
     if let Ok(data) = bincode::serialize(&issue_record) {
@@ -273,6 +456,9 @@ pub fn record_duplicate_issue(
         has_duplicate_label: true,
         has_malicious_label: false,
         created_at_ms: 0,
+        labels: Vec::new(),
+        assignee: None,
+        milestone: None,
     };
 
     let data = match bincode::serialize(&record) {
@@ -281,6 +467,7 @@ pub fn record_duplicate_issue(
     };
 
     if host_storage_set(&key, &data).is_err() {
+        crate::metrics::record_storage_error();
         return false;
     }
 
@@ -315,7 +502,7 @@ pub fn record_malicious_issue(
     let epoch = host_consensus_get_epoch();
     let current_epoch = if epoch >= 0 { epoch as u64 } else { 0 };
 
-    let hotkey = get_hotkey_by_github(github_username);
+    let hotkey = bound_hotkey(repo_owner, repo_name, issue_number, github_username);
     let record = IssueRecord {
         issue_number,
         repo_owner: String::from(repo_owner),
@@ -330,6 +517,9 @@ pub fn record_malicious_issue(
         has_duplicate_label: false,
         has_malicious_label: true,
         created_at_ms: 0,
+        labels: Vec::new(),
+        assignee: None,
+        milestone: None,
     };
     let key = issue_key(repo_owner, repo_name, issue_number);
     if let Ok(data) = bincode::serialize(&record) {
@@ -471,12 +661,207 @@ pub fn store_user_balance(hotkey: &str, balance: &UserBalance) {
 pub fn increment_duplicate_count(hotkey: &str) {
     let hotkey_ss58 = normalize_hotkey_for_storage(hotkey);
     let mut balance = get_user_balance(&hotkey_ss58);
-    balance.duplicate_count = balance.duplicate_count.saturating_add(1);
-    let penalty_points = balance.invalid_count as f64 + balance.duplicate_count as f64 * 0.5;
-    balance.is_penalized = penalty_points > 0.0;
+    apply_duplicate_attempt(&mut balance, &get_penalty_config());
     store_user_balance(&hotkey_ss58, &balance);
 }
 
+/// Count one more duplicate against `balance` and refresh its penalty flag.
+pub fn apply_duplicate_attempt(balance: &mut UserBalance, config: &PenaltyConfig) {
+    balance.duplicate_count = balance.duplicate_count.saturating_add(1);
+    balance.is_penalized = config.is_penalized(balance);
+}
+
+/// The sudo-configured penalty weights, or the defaults if none were set.
+pub fn get_penalty_config() -> PenaltyConfig {
+    host_storage_get(b"penalty_config")
+        .ok()
+        .filter(|d| !d.is_empty())
+        .and_then(|d| bincode::deserialize(&d).ok())
+        .unwrap_or_default()
+}
+
+pub fn store_penalty_config(config: &PenaltyConfig) -> bool {
+    if let Ok(data) = bincode::serialize(config) {
+        if host_storage_set(b"penalty_config", &data).is_ok() {
+            return true;
+        }
+    }
+    crate::metrics::record_storage_error();
+    false
+}
+
+/// The sudo-configured requests per minute per hotkey and route, or
+/// `DEFAULT_RATE_LIMIT_PER_MINUTE`. 0 turns rate limiting off.
+pub fn get_rate_limit_per_minute() -> u32 {
+    host_storage_get(b"rate_limit_per_minute")
+        .ok()
+        .and_then(|d| d.as_slice().try_into().ok())
+        .map(u32::from_le_bytes)
+        .unwrap_or(DEFAULT_RATE_LIMIT_PER_MINUTE)
+}
+
+pub fn store_rate_limit_per_minute(per_minute: u32) -> bool {
+    if host_storage_set(b"rate_limit_per_minute", &per_minute.to_le_bytes()).is_ok() {
+        return true;
+    }
+    crate::metrics::record_storage_error();
+    false
+}
+
+/// The sudo-configured number of issues one claim may carry, or
+/// `MAX_CLAIM_BATCH`.
+pub fn get_max_claim_batch() -> usize {
+    host_storage_get(b"max_claim_batch")
+        .ok()
+        .and_then(|d| d.as_slice().try_into().ok())
+        .map(u32::from_le_bytes)
+        .filter(|max| *max > 0)
+        .map_or(crate::validation::MAX_CLAIM_BATCH, |max| max as usize)
+}
+
+pub fn store_max_claim_batch(max: u32) -> bool {
+    if host_storage_set(b"max_claim_batch", &max.to_le_bytes()).is_ok() {
+        return true;
+    }
+    crate::metrics::record_storage_error();
+    false
+}
+
+/// The sudo-configured GitHub REST API root, without a trailing `/`, or
+/// `https://api.github.com`. Stored rather than read from each validator's
+/// environment, so every validator syncs from the same endpoint.
+pub fn get_github_api_url() -> String {
+    host_storage_get(b"github_api_url")
+        .ok()
+        .and_then(|d| String::from_utf8(d).ok())
+        .filter(|u| !u.is_empty())
+        .unwrap_or_else(|| String::from(crate::github_sync::DEFAULT_GITHUB_API_URL))
+}
+
+pub fn store_github_api_url(url: &str) -> bool {
+    let url = url.trim().trim_end_matches('/');
+    if host_storage_set(b"github_api_url", url.as_bytes()).is_ok() {
+        return true;
+    }
+    crate::metrics::record_storage_error();
+    false
+}
+
+/// The sudo-configured time budget of one issue sync in seconds, or
+/// `DEFAULT_SYNC_DEADLINE_SECS`. Stored so every validator stops paging at
+/// the same point and commits the same issues.
+pub fn get_sync_deadline_secs() -> u32 {
+    host_storage_get(b"sync_deadline_secs")
+        .ok()
+        .and_then(|d| d.as_slice().try_into().ok())
+        .map(u32::from_le_bytes)
+        .filter(|secs| *secs > 0)
+        .unwrap_or(crate::github_sync::DEFAULT_SYNC_DEADLINE_SECS)
+}
+
+pub fn store_sync_deadline_secs(secs: u32) -> bool {
+    if host_storage_set(b"sync_deadline_secs", &secs.to_le_bytes()).is_ok() {
+        return true;
+    }
+    crate::metrics::record_storage_error();
+    false
+}
+
+/// The sudo-configured suspicion thresholds, or the defaults if none were set.
+pub fn get_suspicion_config() -> SuspicionConfig {
+    host_storage_get(b"suspicion_config")
+        .ok()
+        .filter(|d| !d.is_empty())
+        .and_then(|d| bincode::deserialize(&d).ok())
+        .unwrap_or_default()
+}
+
+pub fn store_suspicion_config(config: &SuspicionConfig) -> bool {
+    if let Ok(data) = bincode::serialize(config) {
+        if host_storage_set(b"suspicion_config", &data).is_ok() {
+            return true;
+        }
+    }
+    crate::metrics::record_storage_error();
+    false
+}
+
+/// Hotkeys flagged by the last run of the suspicion heuristics, see
+/// `abuse::refresh_suspicious_hotkeys`.
+pub fn get_suspicious_hotkeys() -> BTreeSet<String> {
+    host_storage_get(b"suspicious_hotkeys")
+        .ok()
+        .filter(|d| !d.is_empty())
+        .and_then(|d| bincode::deserialize(&d).ok())
+        .unwrap_or_default()
+}
+
+/// Also records `now_ms` as the time of the run, see
+/// [`get_suspicion_refreshed_at`].
+pub fn store_suspicious_hotkeys(flagged: &BTreeSet<String>, now_ms: i64) -> bool {
+    if let Ok(data) = bincode::serialize(flagged) {
+        if host_storage_set(b"suspicious_hotkeys", &data).is_ok()
+            && host_storage_set(b"suspicion_refreshed_at", &now_ms.to_le_bytes()).is_ok()
+        {
+            return true;
+        }
+    }
+    crate::metrics::record_storage_error();
+    false
+}
+
+/// When the suspicion heuristics last ran, or 0 if they never have.
+pub fn get_suspicion_refreshed_at() -> i64 {
+    host_storage_get(b"suspicion_refreshed_at")
+        .ok()
+        .and_then(|d| d.as_slice().try_into().ok())
+        .map(i64::from_le_bytes)
+        .unwrap_or(0)
+}
+
+fn dup_attempt_prefix(hotkey_ss58: &str) -> Vec<u8> {
+    let mut key = make_key(b"dup_attempt:", hotkey_ss58);
+    key.push(b':');
+    key
+}
+
+/// Record that `hotkey` tried to claim an issue already claimed by another
+/// miner. Each (hotkey, issue) pair counts once towards `duplicate_count`;
+/// returns false if this attempt was already recorded.
+pub fn record_duplicate_attempt(
+    hotkey: &str,
+    repo_owner: &str,
+    repo_name: &str,
+    issue_number: u32,
+) -> bool {
+    let hotkey_ss58 = normalize_hotkey_for_storage(hotkey);
+    let mut key = dup_attempt_prefix(&hotkey_ss58);
+    key.extend_from_slice(&issue_key(repo_owner, repo_name, issue_number));
+
+    if let Ok(data) = host_storage_get(&key) {
+        if !data.is_empty() {
+            return false;
+        }
+    }
+    let epoch = host_consensus_get_epoch();
+    let epoch = if epoch >= 0 { epoch as u64 } else { 0 };
+    if host_storage_set(&key, &epoch.to_le_bytes()).is_err() {
+        crate::metrics::record_storage_error();
+        return false;
+    }
+
+    increment_duplicate_count(&hotkey_ss58);
+    true
+}
+
+/// Number of recorded duplicate claim attempts for `hotkey`.
+pub fn get_duplicate_attempt_count(hotkey: &str) -> u32 {
+    let prefix = dup_attempt_prefix(&normalize_hotkey_for_storage(hotkey));
+    host_storage_list_prefix(&prefix, 10_000)
+        .map(|data| decode_list_prefix(&data).len() as u32)
+        .unwrap_or(0)
+}
+
 /// Rebuild github:{username} -> hotkey index from user:{hotkey} records.
 /// Called at recount time to recover from missing github: keys.
 pub fn rebuild_github_index() {
@@ -491,6 +876,18 @@ pub fn rebuild_github_index() {
     }
 }
 
+/// The hotkey an issue counts towards: the one recorded on the issue, or
+/// else whoever the author's GitHub username is linked to now.
+fn issue_hotkey<F>(issue: &IssueRecord, hotkey_by_github: F) -> Option<String>
+where
+    F: FnOnce(&str) -> Option<String>,
+{
+    match &issue.claimed_by_hotkey {
+        Some(h) if !h.is_empty() => Some(h.clone()),
+        _ => hotkey_by_github(&issue.author),
+    }
+}
+
 /// Recount all balances by scanning stored issues. Returns JSON summary.
 pub fn recount_all_balances() -> serde_json::Value {
     use alloc::collections::BTreeMap;
@@ -502,12 +899,9 @@ pub fn recount_all_balances() -> serde_json::Value {
     let mut malicious_counts: BTreeMap<String, u32> = BTreeMap::new();
 
     for issue in &all_issues {
-        let hotkey = match &issue.claimed_by_hotkey {
-            Some(h) if !h.is_empty() => h.clone(),
-            _ => match get_hotkey_by_github(&issue.author) {
-                Some(h) => h,
-                None => continue,
-            },
+        let hotkey = match issue_hotkey(issue, get_hotkey_by_github) {
+            Some(h) => h,
+            None => continue,
         };
 
         if issue.has_malicious_label {
@@ -538,6 +932,7 @@ pub fn recount_all_balances() -> serde_json::Value {
         all_hotkeys.insert(hk, true);
     }
 
+    let config = get_penalty_config();
     let mut updated = 0u32;
     for hotkey in all_hotkeys.keys() {
         let mut balance = UserBalance::default();
@@ -546,12 +941,12 @@ pub fn recount_all_balances() -> serde_json::Value {
 
         balance.valid_count = valid_counts.get(hotkey).copied().unwrap_or(0);
         balance.invalid_count = invalid_counts.get(hotkey).copied().unwrap_or(0);
-        balance.duplicate_count = duplicate_counts.get(hotkey).copied().unwrap_or(0);
+        // Duplicate claim attempts are not visible in the issue labels, so
+        // add them back from their own records.
+        balance.duplicate_count = duplicate_counts.get(hotkey).copied().unwrap_or(0)
+            + get_duplicate_attempt_count(hotkey);
         balance.malicious_count = malicious_counts.get(hotkey).copied().unwrap_or(0);
-        let penalty_points = balance.invalid_count as f64
-            + balance.duplicate_count as f64 * 0.5
-            + balance.malicious_count as f64 * 5.0;
-        balance.is_penalized = penalty_points > 0.0;
+        balance.is_penalized = config.is_penalized(&balance);
         store_user_balance(hotkey, &balance);
         updated += 1;
     }
@@ -566,6 +961,47 @@ pub fn recount_all_balances() -> serde_json::Value {
     })
 }
 
+/// Leaderboard entry without `suspicious` for bincode compat
+#[derive(Clone, Debug, Deserialize)]
+struct PreSuspicionLeaderboardEntry {
+    pub rank: u32,
+    pub hotkey: String,
+    pub github_username: String,
+    pub score: f64,
+    pub valid_issues: u32,
+    pub invalid_issues: u32,
+    pub pending_issues: u32,
+    pub star_count: u32,
+    pub star_bonus: f64,
+    pub net_points: f64,
+    pub is_penalized: bool,
+    pub last_epoch: u64,
+    pub duplicate_issues: u32,
+    pub malicious_issues: u32,
+}
+
+impl PreSuspicionLeaderboardEntry {
+    fn into_current(self) -> LeaderboardEntry {
+        LeaderboardEntry {
+            rank: self.rank,
+            hotkey: self.hotkey,
+            github_username: self.github_username,
+            score: self.score,
+            valid_issues: self.valid_issues,
+            invalid_issues: self.invalid_issues,
+            pending_issues: self.pending_issues,
+            star_count: self.star_count,
+            star_bonus: self.star_bonus,
+            net_points: self.net_points,
+            is_penalized: self.is_penalized,
+            last_epoch: self.last_epoch,
+            duplicate_issues: self.duplicate_issues,
+            malicious_issues: self.malicious_issues,
+            suspicious: false,
+        }
+    }
+}
+
 pub fn get_leaderboard() -> Vec<LeaderboardEntry> {
     host_storage_get(b"leaderboard")
         .ok()
@@ -573,7 +1009,13 @@ pub fn get_leaderboard() -> Vec<LeaderboardEntry> {
             if d.is_empty() {
                 None
             } else {
-                bincode::deserialize(&d).ok()
+                bincode::deserialize::<Vec<LeaderboardEntry>>(&d)
+                    .ok()
+                    .or_else(|| {
+                        bincode::deserialize::<Vec<PreSuspicionLeaderboardEntry>>(&d)
+                            .ok()
+                            .map(|v| v.into_iter().map(|e| e.into_current()).collect())
+                    })
             }
         })
         .unwrap_or_default()
@@ -586,6 +1028,46 @@ pub fn store_leaderboard(entries: &[LeaderboardEntry]) -> bool {
     false
 }
 
+pub fn get_leaderboard_snapshots() -> Vec<LeaderboardSnapshot> {
+    host_storage_get(b"leaderboard_snapshots")
+        .ok()
+        .filter(|d| !d.is_empty())
+        .and_then(|d| bincode::deserialize(&d).ok())
+        .unwrap_or_default()
+}
+
+/// Also records the day of the newest snapshot, see [`get_last_snapshot_day`].
+pub fn store_leaderboard_snapshots(snapshots: &[LeaderboardSnapshot]) -> bool {
+    if let Ok(data) = bincode::serialize(snapshots) {
+        if host_storage_set(b"leaderboard_snapshots", &data).is_ok() {
+            if let Some(last) = snapshots.last() {
+                store_last_snapshot_day(last.day);
+            }
+            return true;
+        }
+    }
+    crate::metrics::record_storage_error();
+    false
+}
+
+/// UTC day of the newest leaderboard snapshot, kept apart from the
+/// snapshots so the background tick can skip reading them on days that
+/// already have one.
+pub fn get_last_snapshot_day() -> Option<i64> {
+    host_storage_get(b"last_snapshot_day")
+        .ok()
+        .filter(|d| d.len() >= 8)
+        .map(|d| {
+            let mut buf = [0u8; 8];
+            buf.copy_from_slice(&d[..8]);
+            i64::from_le_bytes(buf)
+        })
+}
+
+pub fn store_last_snapshot_day(day: i64) {
+    let _ = host_storage_set(b"last_snapshot_day", &day.to_le_bytes());
+}
+
 /// Deserialize the bincode Vec<(Vec<u8>, Vec<u8>)> returned by list_prefix
 fn decode_list_prefix(data: &[u8]) -> Vec<(Vec<u8>, Vec<u8>)> {
     bincode::deserialize(data).unwrap_or_default()
@@ -600,6 +1082,7 @@ pub fn get_registered_hotkeys() -> Vec<String> {
                 return pairs
                     .into_iter()
                     .filter_map(|(_k, v)| String::from_utf8(v).ok())
+                    .filter(|hotkey| !hotkey.is_empty())
                     .collect();
             }
         }
@@ -640,6 +1123,25 @@ pub fn store_issue_data(issues: &[IssueRecord]) -> bool {
     false
 }
 
+/// Labels implied by the flags of a record stored before labels were kept.
+/// `valid` was matched against the configured valid label at sync time.
+fn labels_from_flags(ide: bool, valid: bool, invalid: bool, duplicate: bool) -> Vec<String> {
+    let mut labels = Vec::new();
+    if ide {
+        labels.push(String::from("ide"));
+    }
+    if valid {
+        labels.push(get_valid_label());
+    }
+    if invalid {
+        labels.push(String::from("invalid"));
+    }
+    if duplicate {
+        labels.push(String::from("duplicate"));
+    }
+    labels
+}
+
 /// Legacy struct without has_malicious_label for bincode compat
 #[derive(Clone, Debug, Deserialize)]
 struct LegacyIssueRecord {
@@ -659,6 +1161,12 @@ struct LegacyIssueRecord {
 impl LegacyIssueRecord {
     fn into_current(self) -> IssueRecord {
         IssueRecord {
+            labels: labels_from_flags(
+                self.has_ide_label,
+                self.has_valid_label,
+                self.has_invalid_label,
+                self.has_duplicate_label,
+            ),
             issue_number: self.issue_number,
             repo_owner: self.repo_owner,
             repo_name: self.repo_name,
@@ -672,16 +1180,75 @@ impl LegacyIssueRecord {
             has_duplicate_label: self.has_duplicate_label,
             has_malicious_label: false,
             created_at_ms: 0,
+            assignee: None,
+            milestone: None,
+        }
+    }
+}
+
+/// Struct without labels, assignee and milestone for bincode compat
+#[derive(Clone, Debug, Deserialize)]
+struct PrePolicyIssueRecord {
+    pub issue_number: u32,
+    pub repo_owner: String,
+    pub repo_name: String,
+    pub author: String,
+    pub is_closed: bool,
+    pub has_valid_label: bool,
+    pub has_invalid_label: bool,
+    pub has_ide_label: bool,
+    pub claimed_by_hotkey: Option<String>,
+    pub recorded_epoch: u64,
+    pub has_duplicate_label: bool,
+    pub has_malicious_label: bool,
+    pub created_at_ms: i64,
+}
+
+impl PrePolicyIssueRecord {
+    fn into_current(self) -> IssueRecord {
+        let mut labels = labels_from_flags(
+            self.has_ide_label,
+            self.has_valid_label,
+            self.has_invalid_label,
+            self.has_duplicate_label,
+        );
+        if self.has_malicious_label {
+            labels.push(String::from("malicious"));
+        }
+        IssueRecord {
+            labels,
+            issue_number: self.issue_number,
+            repo_owner: self.repo_owner,
+            repo_name: self.repo_name,
+            author: self.author,
+            is_closed: self.is_closed,
+            has_valid_label: self.has_valid_label,
+            has_invalid_label: self.has_invalid_label,
+            has_ide_label: self.has_ide_label,
+            claimed_by_hotkey: self.claimed_by_hotkey,
+            recorded_epoch: self.recorded_epoch,
+            has_duplicate_label: self.has_duplicate_label,
+            has_malicious_label: self.has_malicious_label,
+            created_at_ms: self.created_at_ms,
+            assignee: None,
+            milestone: None,
         }
     }
 }
 
 fn deserialize_issue_record(data: &[u8]) -> Option<IssueRecord> {
-    bincode::deserialize::<IssueRecord>(data).ok().or_else(|| {
-        bincode::deserialize::<LegacyIssueRecord>(data)
-            .ok()
-            .map(|l| l.into_current())
-    })
+    bincode::deserialize::<IssueRecord>(data)
+        .ok()
+        .or_else(|| {
+            bincode::deserialize::<PrePolicyIssueRecord>(data)
+                .ok()
+                .map(|r| r.into_current())
+        })
+        .or_else(|| {
+            bincode::deserialize::<LegacyIssueRecord>(data)
+                .ok()
+                .map(|l| l.into_current())
+        })
 }
 
 pub fn get_synced_issues() -> Vec<IssueRecord> {
@@ -696,6 +1263,11 @@ pub fn get_synced_issues() -> Vec<IssueRecord> {
             } else {
                 bincode::deserialize::<Vec<IssueRecord>>(&d)
                     .ok()
+                    .or_else(|| {
+                        bincode::deserialize::<Vec<PrePolicyIssueRecord>>(&d)
+                            .ok()
+                            .map(|v| v.into_iter().map(|r| r.into_current()).collect())
+                    })
                     .or_else(|| {
                         // Try legacy format
                         bincode::deserialize::<Vec<LegacyIssueRecord>>(&d)
@@ -772,6 +1344,403 @@ pub fn get_last_refreshed() -> i64 {
         .unwrap_or(0)
 }
 
+/// Write `value` to a probe key and check it reads back unchanged.
+pub fn storage_round_trip(value: i64) -> bool {
+    let bytes = value.to_le_bytes();
+    host_storage_set(b"selftest_probe", &bytes).is_ok()
+        && host_storage_get(b"selftest_probe").is_ok_and(|d| d == bytes)
+}
+
+pub fn get_selftest_cache() -> Option<SelfTestReport> {
+    host_storage_get(b"selftest_cache")
+        .ok()
+        .filter(|d| !d.is_empty())
+        .and_then(|d| bincode::deserialize(&d).ok())
+}
+
+pub fn store_selftest_cache(report: &SelfTestReport) {
+    if let Ok(data) = bincode::serialize(report) {
+        let _ = host_storage_set(b"selftest_cache", &data);
+    }
+}
+
+pub fn store_last_star_refresh(ts: i64) {
+    let _ = host_storage_set(b"last_star_refresh", &ts.to_le_bytes());
+}
+
+pub fn get_last_star_refresh() -> i64 {
+    host_storage_get(b"last_star_refresh")
+        .ok()
+        .and_then(|d| {
+            if d.len() >= 8 {
+                let mut buf = [0u8; 8];
+                buf.copy_from_slice(&d[..8]);
+                Some(i64::from_le_bytes(buf))
+            } else {
+                None
+            }
+        })
+        .unwrap_or(0)
+}
+
+fn claim_idempotency_prefix(hotkey: &str) -> Vec<u8> {
+    let mut prefix = make_key(b"claim_idem:", &normalize_hotkey_for_storage(hotkey));
+    prefix.push(b':');
+    prefix
+}
+
+fn claim_idempotency_key(hotkey: &str, key: &str) -> Vec<u8> {
+    let mut storage_key = claim_idempotency_prefix(hotkey);
+    storage_key.extend_from_slice(key.as_bytes());
+    storage_key
+}
+
+/// Decode a stored claim, returning it only while it is within the TTL.
+/// A record that no longer decodes (e.g. written before a `ClaimResult`
+/// field was added) is deliberately treated as a miss: the claim runs again
+/// and reports already-claimed issues as such, which is safe to repeat.
+fn fresh_cached_claim(data: &[u8], now_ms: i64) -> Option<ClaimResult> {
+    let cached: CachedClaim = bincode::deserialize(data).ok()?;
+    if now_ms.saturating_sub(cached.stored_at_ms) > CLAIM_IDEMPOTENCY_TTL_MS {
+        return None;
+    }
+    Some(cached.result)
+}
+
+/// Result of an earlier claim by `hotkey` with the same idempotency `key`.
+/// An expired or unreadable record is deleted.
+pub fn get_cached_claim(hotkey: &str, key: &str, now_ms: i64) -> Option<ClaimResult> {
+    let storage_key = claim_idempotency_key(hotkey, key);
+    let data = host_storage_get(&storage_key).ok()?;
+    if data.is_empty() {
+        return None;
+    }
+    let result = fresh_cached_claim(&data, now_ms);
+    if result.is_none() {
+        let _ = host_storage_set(&storage_key, &[]);
+    }
+    result
+}
+
+/// Remember a claim result, first dropping the hotkey's expired entries so
+/// keys that are never retried do not pile up.
+pub fn store_cached_claim(hotkey: &str, key: &str, now_ms: i64, result: &ClaimResult) -> bool {
+    if let Ok(data) = host_storage_list_prefix(&claim_idempotency_prefix(hotkey), 10_000) {
+        for (stale, value) in decode_list_prefix(&data) {
+            if fresh_cached_claim(&value, now_ms).is_none() {
+                let _ = host_storage_set(&stale, &[]);
+            }
+        }
+    }
+    let cached = CachedClaim {
+        stored_at_ms: now_ms,
+        result: result.clone(),
+    };
+    if let Ok(data) = bincode::serialize(&cached) {
+        if host_storage_set(&claim_idempotency_key(hotkey, key), &data).is_ok() {
+            return true;
+        }
+    }
+    crate::metrics::record_storage_error();
+    false
+}
+
+/// Each audit entry lives under its own `audit:` key, suffixed with a
+/// big-endian sequence number so prefix listings come back oldest first.
+fn audit_key(seq: u64) -> Vec<u8> {
+    let mut key = b"audit:".to_vec();
+    key.extend_from_slice(&seq.to_be_bytes());
+    key
+}
+
+fn get_audit_seq() -> Option<u64> {
+    host_storage_get(b"audit_seq")
+        .ok()
+        .and_then(|d| d.as_slice().try_into().ok())
+        .map(u64::from_le_bytes)
+}
+
+/// Entries from the single `audit_log` blob written before per-entry keys.
+fn legacy_audit_log() -> Vec<AuditEntry> {
+    host_storage_get(b"audit_log")
+        .ok()
+        .filter(|d| !d.is_empty())
+        .and_then(|d| bincode::deserialize(&d).ok())
+        .unwrap_or_default()
+}
+
+/// Move the legacy blob into per-entry keys and return the next sequence
+/// number. Runs once, on the first append after upgrading.
+fn migrate_audit_log() -> u64 {
+    let mut seq = 0u64;
+    for entry in legacy_audit_log() {
+        if let Ok(data) = bincode::serialize(&entry) {
+            if host_storage_set(&audit_key(seq), &data).is_ok() {
+                seq += 1;
+            }
+        }
+    }
+    let _ = host_storage_set(b"audit_seq", &seq.to_le_bytes());
+    let _ = host_storage_set(b"audit_log", &[]);
+    seq
+}
+
+/// Oldest first, at most `MAX_AUDIT_ENTRIES`.
+pub fn get_audit_log() -> Vec<AuditEntry> {
+    if get_audit_seq().is_none() {
+        return legacy_audit_log();
+    }
+    let mut entries: Vec<(Vec<u8>, AuditEntry)> =
+        host_storage_list_prefix(b"audit:", MAX_AUDIT_ENTRIES as u32)
+            .map(|data| decode_list_prefix(&data))
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|(k, v)| bincode::deserialize(&v).ok().map(|e| (k, e)))
+            .collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    entries.into_iter().map(|(_, e)| e).collect()
+}
+
+/// Append an entry to the audit log. Call only after the state change it
+/// describes has been written, so the log never records a write that failed.
+/// Writes one new key and drops the entry that falls off the cap; earlier
+/// entries are never rewritten.
+pub fn append_audit(
+    actor: &str,
+    action: &str,
+    detail: &serde_json::Value,
+    timestamp_ms: i64,
+) -> bool {
+    let epoch = host_consensus_get_epoch();
+    let entry = AuditEntry {
+        timestamp_ms,
+        epoch: if epoch >= 0 { epoch as u64 } else { 0 },
+        actor: normalize_hotkey_for_storage(actor),
+        action: action.to_string(),
+        detail: detail.to_string(),
+    };
+    let seq = get_audit_seq().unwrap_or_else(migrate_audit_log);
+    if let Ok(data) = bincode::serialize(&entry) {
+        if host_storage_set(&audit_key(seq), &data).is_ok()
+            && host_storage_set(b"audit_seq", &(seq + 1).to_le_bytes()).is_ok()
+        {
+            if let Some(expired) = seq.checked_sub(MAX_AUDIT_ENTRIES as u64) {
+                let _ = host_storage_set(&audit_key(expired), &[]);
+            }
+            return true;
+        }
+    }
+    crate::metrics::record_storage_error();
+    false
+}
+
+/// Newest entries first, optionally only those made by `actor`.
+pub fn recent_audit_entries(
+    log: &[AuditEntry],
+    actor: Option<&str>,
+    limit: usize,
+) -> Vec<AuditEntry> {
+    let actor = actor.map(normalize_hotkey_for_storage);
+    log.iter()
+        .rev()
+        .filter(|e| actor.as_deref().is_none_or(|a| e.actor == a))
+        .take(limit)
+        .cloned()
+        .collect()
+}
+
+/// Label that marks an issue as valid. Stored in consensus storage (rather
+/// than read from the environment) so every validator classifies issues the
+/// same way.
+pub const DEFAULT_VALID_LABEL: &str = "valid";
+
+pub fn store_valid_label(label: &str) -> bool {
+    host_storage_set(b"valid_label", label.to_lowercase().as_bytes()).is_ok()
+}
+
+pub fn get_valid_label() -> String {
+    host_storage_get(b"valid_label")
+        .ok()
+        .and_then(|d| String::from_utf8(d).ok())
+        .filter(|l| !l.is_empty())
+        .unwrap_or_else(|| String::from(DEFAULT_VALID_LABEL))
+}
+
+/// The sudo-configured verification policy, or the `ide` label plus the
+/// configured valid label if none was set.
+pub fn get_verification_policy() -> VerificationPolicy {
+    host_storage_get(b"verification_policy")
+        .ok()
+        .filter(|d| !d.is_empty())
+        .and_then(|d| bincode::deserialize(&d).ok())
+        .unwrap_or_else(|| VerificationPolicy::for_valid_label(&get_valid_label()))
+}
+
+pub fn store_verification_policy(policy: &VerificationPolicy) -> bool {
+    if let Ok(data) = bincode::serialize(policy) {
+        if host_storage_set(b"verification_policy", &data).is_ok() {
+            return true;
+        }
+    }
+    crate::metrics::record_storage_error();
+    false
+}
+
+/// Names the repository was previously known by, as lowercase
+/// `owner/name`. Claims naming one are treated as claims on the current
+/// repository after a rename or transfer.
+pub fn get_former_repo_names() -> Vec<String> {
+    host_storage_get(b"former_repo_names")
+        .ok()
+        .filter(|d| !d.is_empty())
+        .and_then(|d| bincode::deserialize(&d).ok())
+        .unwrap_or_default()
+}
+
+pub fn store_former_repo_names(names: &[String]) -> bool {
+    let names: Vec<String> = names.iter().map(|n| n.to_lowercase()).collect();
+    let data = match bincode::serialize(&names) {
+        Ok(d) => d,
+        Err(_) => return false,
+    };
+    if host_storage_set(b"former_repo_names", &data).is_err() {
+        crate::metrics::record_storage_error();
+        return false;
+    }
+    true
+}
+
+pub fn get_issue_failures() -> Vec<IssueFailure> {
+    host_storage_get(b"issue_failures")
+        .ok()
+        .filter(|d| !d.is_empty())
+        .and_then(|d| bincode::deserialize(&d).ok())
+        .unwrap_or_default()
+}
+
+fn store_issue_failures(failures: &[IssueFailure]) -> bool {
+    if let Ok(data) = bincode::serialize(failures) {
+        if host_storage_set(b"issue_failures", &data).is_ok() {
+            return true;
+        }
+    }
+    crate::metrics::record_storage_error();
+    false
+}
+
+pub fn is_dead_letter(failure: &IssueFailure) -> bool {
+    failure.consecutive_failures >= MAX_ISSUE_FAILURES
+}
+
+/// Issues that failed [`MAX_ISSUE_FAILURES`] syncs in a row.
+pub fn get_dead_letter_issues() -> Vec<IssueFailure> {
+    get_issue_failures()
+        .into_iter()
+        .filter(is_dead_letter)
+        .collect()
+}
+
+/// Count one more failure for each of `failed` (number and reason), and
+/// clear the count of every issue in `succeeded`, so only consecutive
+/// failures add up.
+pub fn apply_issue_failures(
+    failures: &mut Vec<IssueFailure>,
+    failed: &[(u32, String)],
+    succeeded: &[u32],
+    now_ms: i64,
+) {
+    failures.retain(|f| !succeeded.contains(&f.issue_number));
+    for (issue_number, reason) in failed {
+        let existing = failures
+            .iter_mut()
+            .find(|f| f.issue_number == *issue_number);
+        match existing {
+            Some(entry) => {
+                entry.consecutive_failures = entry.consecutive_failures.saturating_add(1);
+                entry.last_reason = reason.clone();
+                entry.last_failed_ms = now_ms;
+            }
+            None => failures.push(IssueFailure {
+                issue_number: *issue_number,
+                consecutive_failures: 1,
+                last_reason: reason.clone(),
+                last_failed_ms: now_ms,
+            }),
+        }
+    }
+}
+
+/// Record the outcome of a sync in the stored failure counts.
+pub fn record_issue_failures(failed: &[(u32, String)], succeeded: &[u32], now_ms: i64) {
+    let mut failures = get_issue_failures();
+    if failures.is_empty() && failed.is_empty() {
+        return;
+    }
+    apply_issue_failures(&mut failures, failed, succeeded, now_ms);
+    store_issue_failures(&failures);
+}
+
+/// Forget an issue's failures so the next sync scans it again. Returns
+/// whether the issue had any.
+pub fn reset_issue_failures(issue_number: u32) -> bool {
+    let mut failures = get_issue_failures();
+    let before = failures.len();
+    failures.retain(|f| f.issue_number != issue_number);
+    if failures.len() == before {
+        return false;
+    }
+    store_issue_failures(&failures)
+}
+
+/// Refill `bucket` at `per_minute` tokens a minute, up to `per_minute`, and
+/// take one token. When none is left, returns the seconds until one is.
+fn take_bucket_token(bucket: &mut TokenBucket, per_minute: u32, now_ms: i64) -> Result<(), u64> {
+    const TOKEN: u64 = 60_000;
+    let per_ms = u64::from(per_minute);
+    let elapsed = now_ms.saturating_sub(bucket.updated_ms).max(0) as u64;
+    bucket.credit = bucket
+        .credit
+        .saturating_add(elapsed.saturating_mul(per_ms))
+        .min(per_ms * TOKEN);
+    bucket.updated_ms = now_ms;
+    if bucket.credit >= TOKEN {
+        bucket.credit -= TOKEN;
+        return Ok(());
+    }
+    let wait_ms = (TOKEN - bucket.credit).div_ceil(per_ms.max(1));
+    Err(wait_ms.div_ceil(1000))
+}
+
+/// Charge one `action` request to `hotkey`, allowing `per_minute` a minute
+/// with bursts up to the same amount. `Err` carries the seconds to wait.
+pub fn take_rate_token(
+    action: &str,
+    hotkey: &str,
+    per_minute: u32,
+    now_ms: i64,
+) -> Result<(), u64> {
+    let mut key = make_key(b"rate:", action);
+    key.push(b':');
+    key.extend_from_slice(normalize_hotkey_for_storage(hotkey).as_bytes());
+
+    let mut bucket = host_storage_get(&key)
+        .ok()
+        .filter(|d| !d.is_empty())
+        .and_then(|d| bincode::deserialize::<TokenBucket>(&d).ok())
+        .unwrap_or(TokenBucket {
+            credit: u64::from(per_minute) * 60_000,
+            updated_ms: now_ms,
+        });
+    let taken = take_bucket_token(&mut bucket, per_minute, now_ms);
+    let stored = bincode::serialize(&bucket)
+        .map(|data| host_storage_set(&key, &data).is_ok())
+        .unwrap_or(false);
+    if !stored {
+        crate::metrics::record_storage_error();
+    }
+    taken
+}
+
 pub fn ensure_hotkey_tracked(hotkey: &str) {
     add_registered_hotkey(hotkey);
 }
@@ -861,3 +1830,711 @@ pub fn is_banned(hotkey: &str) -> bool {
     }
     false
 }
+
+/// Stream every registered miner, then every claimed issue, to `emit`.
+/// Miners are read one at a time; `weight_of` supplies each miner's current
+/// weight by SS58 hotkey.
+pub fn export_all<W, E>(weight_of: W, emit: E)
+where
+    W: Fn(&str) -> f64,
+    E: FnMut(ExportRow),
+{
+    let hotkeys = get_registered_hotkeys();
+    let issues = get_synced_issues();
+    export_rows(
+        &hotkeys,
+        |hotkey| {
+            let reg = get_user_by_hotkey(hotkey)?;
+            Some(ExportMiner {
+                weight: weight_of(&reg.hotkey),
+                balance: get_user_balance(&reg.hotkey),
+                is_banned: is_banned(&reg.hotkey),
+                hotkey: reg.hotkey,
+                github_username: reg.github_username,
+                registered_epoch: reg.registered_epoch,
+            })
+        },
+        &issues,
+        emit,
+    );
+}
+
+fn export_rows<M, E>(hotkeys: &[String], miner: M, issues: &[IssueRecord], mut emit: E)
+where
+    M: Fn(&str) -> Option<ExportMiner>,
+    E: FnMut(ExportRow),
+{
+    let mut seen = BTreeSet::new();
+    for hotkey in hotkeys {
+        if !seen.insert(hotkey.as_str()) {
+            continue;
+        }
+        if let Some(m) = miner(hotkey) {
+            emit(ExportRow::Miner(m));
+        }
+    }
+    for issue in issues.iter().filter(|i| i.claimed_by_hotkey.is_some()) {
+        emit(ExportRow::Bounty(issue.clone()));
+    }
+}
+
+/// Split an export into the miners and bounties to write. Miners without a
+/// hotkey or GitHub username, and bounties whose `claimed_by_hotkey` is not a
+/// miner in the bundle, are skipped.
+fn check_import(bundle: &ExportBundle) -> (Vec<&ExportMiner>, Vec<&IssueRecord>, ImportReport) {
+    let mut report = ImportReport::default();
+    let mut known = BTreeSet::new();
+    let mut miners = Vec::new();
+    for miner in &bundle.miners {
+        if miner.hotkey.is_empty() || miner.github_username.is_empty() {
+            report.miners_skipped += 1;
+            continue;
+        }
+        known.insert(normalize_hotkey_for_storage(&miner.hotkey));
+        miners.push(miner);
+    }
+
+    let mut bounties = Vec::new();
+    for issue in &bundle.bounties {
+        match &issue.claimed_by_hotkey {
+            Some(hotkey) if known.contains(&normalize_hotkey_for_storage(hotkey)) => {
+                bounties.push(issue)
+            }
+            _ => report.bounties_skipped += 1,
+        }
+    }
+    (miners, bounties, report)
+}
+
+/// Insert or replace `bounties` in `issues` by repository and issue number,
+/// so importing the same bounty twice keeps a single record.
+fn upsert_issues(issues: &mut Vec<IssueRecord>, bounties: &[&IssueRecord]) {
+    for bounty in bounties {
+        let existing = issues.iter_mut().find(|i| {
+            i.issue_number == bounty.issue_number
+                && i.repo_owner.eq_ignore_ascii_case(&bounty.repo_owner)
+                && i.repo_name.eq_ignore_ascii_case(&bounty.repo_name)
+        });
+        match existing {
+            Some(issue) => *issue = (*bounty).clone(),
+            None => issues.push((*bounty).clone()),
+        }
+    }
+}
+
+/// Write `record` under its `issue:` key, replacing any record there.
+fn store_issue_record(record: &IssueRecord) -> bool {
+    let key = issue_key(&record.repo_owner, &record.repo_name, record.issue_number);
+    let stored = bincode::serialize(record)
+        .ok()
+        .is_some_and(|data| host_storage_set(&key, &data).is_ok());
+    if !stored {
+        crate::metrics::record_storage_error();
+    }
+    stored
+}
+
+/// Restore a bundle produced by [`export_all`]. Registrations, balances and
+/// bans are overwritten and bounties are upserted into the synced issues and
+/// their `issue:` keys, so importing the same bundle again changes nothing.
+/// Weights are not imported; they are recomputed from the restored balances.
+pub fn import_all(bundle: &ExportBundle) -> ImportReport {
+    let (miners, bounties, mut report) = check_import(bundle);
+
+    for miner in miners {
+        if !force_register_user_at(
+            &miner.github_username,
+            &miner.hotkey,
+            miner.registered_epoch,
+        ) {
+            report.miners_skipped += 1;
+            continue;
+        }
+        ensure_hotkey_tracked(&miner.hotkey);
+        store_user_balance(&miner.hotkey, &miner.balance);
+        if miner.is_banned {
+            ban_user(&miner.hotkey);
+        } else {
+            unban_user(&miner.hotkey);
+        }
+        report.miners_imported += 1;
+    }
+
+    let mut issues = get_synced_issues();
+    upsert_issues(&mut issues, &bounties);
+    if !store_issue_data(&issues) {
+        crate::metrics::record_storage_error();
+        report.bounties_skipped += bounties.len() as u32;
+        return report;
+    }
+    // Claims are checked against the issue: keys, so restore those too or
+    // an imported bounty could be claimed a second time.
+    for bounty in bounties {
+        if store_issue_record(bounty) {
+            report.bounties_imported += 1;
+        } else {
+            report.bounties_skipped += 1;
+        }
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github_sync::{GITHUB_REPO_NAME, GITHUB_REPO_OWNER};
+    use crate::storage::fixtures::IssueBuilder;
+    use crate::types::{ClaimSummary, ClaimedIssue, RejectedIssue};
+
+    #[test]
+    fn test_cached_claim_replays_within_ttl() {
+        let result = ClaimResult {
+            claimed: alloc::vec![ClaimedIssue { issue_number: 12 }],
+            rejected: alloc::vec![RejectedIssue {
+                issue_number: 13,
+                reason: String::from("Issue already claimed"),
+            }],
+            total_valid: 4,
+            score: 0.08,
+            summary: ClaimSummary {
+                newly_claimed: 1,
+                rejected: 1,
+                already_had: 0,
+            },
+            message: String::from("Claimed 1 new issue; 1 rejected (1 already claimed)"),
+        };
+        let data = bincode::serialize(&CachedClaim {
+            stored_at_ms: 1_000,
+            result: result.clone(),
+        })
+        .unwrap();
+
+        let replayed = fresh_cached_claim(&data, 1_000 + CLAIM_IDEMPOTENCY_TTL_MS).unwrap();
+        assert_eq!(
+            serde_json::to_string(&replayed).unwrap(),
+            serde_json::to_string(&result).unwrap()
+        );
+        assert!(fresh_cached_claim(&data, 1_001 + CLAIM_IDEMPOTENCY_TTL_MS).is_none());
+        assert!(fresh_cached_claim(b"garbage", 1_000).is_none());
+    }
+
+    #[test]
+    fn test_expired_cached_claims_are_deleted() {
+        crate::storage::host::reset(1);
+        let result = ClaimResult {
+            claimed: Vec::new(),
+            rejected: Vec::new(),
+            total_valid: 0,
+            score: 0.0,
+            summary: ClaimSummary::default(),
+            message: String::new(),
+        };
+        let later = 1_001 + CLAIM_IDEMPOTENCY_TTL_MS;
+        let count = || {
+            decode_list_prefix(&host_storage_list_prefix(b"claim_idem:", u32::MAX).unwrap()).len()
+        };
+        assert!(store_cached_claim("hk", "old", 1_000, &result));
+        assert!(store_cached_claim("hk", "read", 1_000, &result));
+        assert!(host_storage_set(&claim_idempotency_key("hk", "bad"), b"garbage").is_ok());
+
+        assert!(get_cached_claim("hk", "read", later).is_none());
+        assert!(get_cached_claim("hk", "bad", later).is_none());
+        assert_eq!(count(), 1);
+        assert!(store_cached_claim("hk", "new", later, &result));
+        assert_eq!(count(), 1);
+        assert!(get_cached_claim("hk", "new", later).is_some());
+    }
+
+    fn audit(actor: &str, action: &str, timestamp_ms: i64) -> AuditEntry {
+        AuditEntry {
+            timestamp_ms,
+            epoch: 0,
+            actor: String::from(actor),
+            action: String::from(action),
+            detail: String::from("{}"),
+        }
+    }
+
+    fn registration(hotkey: &str, github_username: &str) -> UserRegistration {
+        UserRegistration {
+            hotkey: String::from(hotkey),
+            github_username: String::from(github_username),
+            registered_epoch: 1,
+        }
+    }
+
+    #[test]
+    fn test_relink_to_new_or_same_username() {
+        let current = registration("hk-a", "old-name");
+        assert_eq!(relink_rejection("hk-a", Some(&current), None), None);
+        // A username the hotkey already owns (e.g. a case change) is fine.
+        assert_eq!(relink_rejection("hk-a", Some(&current), Some("hk-a")), None);
+        assert_eq!(
+            relink_rejection("hk-a", None, None),
+            Some(RelinkOutcome::NotRegistered)
+        );
+    }
+
+    #[test]
+    fn test_relink_rejects_username_of_another_hotkey() {
+        let current = registration("hk-a", "alice");
+        assert_eq!(
+            relink_rejection("hk-a", Some(&current), Some("hk-b")),
+            Some(RelinkOutcome::GithubTaken(String::from("hk-b")))
+        );
+    }
+
+    #[test]
+    fn test_register_relink_and_unregister_in_memory() {
+        crate::storage::host::reset(5);
+        assert!(register_user("Alice", "hk-a"));
+        ensure_hotkey_tracked("hk-a");
+        assert!(!register_user("alice", "hk-b"));
+        assert_eq!(get_hotkey_by_github("ALICE").as_deref(), Some("hk-a"));
+        assert_eq!(get_user_by_hotkey("hk-a").unwrap().registered_epoch, 5);
+
+        assert_eq!(
+            update_github("hk-a", "alice2", 1_000),
+            RelinkOutcome::Relinked(String::from("alice"))
+        );
+        assert_eq!(get_hotkey_by_github("alice"), None);
+        assert_eq!(get_registered_hotkeys(), ["hk-a"]);
+
+        assert_eq!(unregister_user("hk-a", 2_000).as_deref(), Some("alice2"));
+        assert!(get_user_by_hotkey("hk-a").is_none());
+        assert!(get_registered_hotkeys().is_empty());
+    }
+
+    #[test]
+    fn test_relink_waits_out_the_cooldown() {
+        crate::storage::host::reset(5);
+        assert!(register_user("alice", "hk-a"));
+
+        assert_eq!(
+            update_github("hk-a", "alice2", 1_000),
+            RelinkOutcome::Relinked(String::from("alice"))
+        );
+        let until = 1_000 + RELINK_COOLDOWN_MS;
+        assert_eq!(
+            update_github("hk-a", "alice3", until - 1),
+            RelinkOutcome::CoolingDown(until)
+        );
+        // A case change is not a new username.
+        assert_eq!(
+            update_github("hk-a", "Alice2", until - 1),
+            RelinkOutcome::Relinked(String::from("alice2"))
+        );
+        assert_eq!(
+            update_github("hk-a", "alice3", until),
+            RelinkOutcome::Relinked(String::from("Alice2"))
+        );
+
+        assert!(unregister_user("hk-a", until).is_some());
+        assert_eq!(
+            relink_cooldown_until("hk-a", until),
+            Some(until + RELINK_COOLDOWN_MS)
+        );
+    }
+
+    #[test]
+    fn test_penalty_stays_with_hotkey_after_relink_or_unregister() {
+        crate::storage::host::reset(5);
+        assert!(register_user("alice", "hk-a"));
+        let (owner, name) = (GITHUB_REPO_OWNER, GITHUB_REPO_NAME);
+        let issue = IssueBuilder::new(7)
+            .claimed_by("hk-a")
+            .labels(&["ide", "invalid"])
+            .build();
+        assert!(store_issue_data(&[issue]));
+        assert!(record_invalid_issue(7, owner, name, "alice", None));
+
+        assert_eq!(
+            update_github("hk-a", "alice2", 1_000),
+            RelinkOutcome::Relinked(String::from("alice"))
+        );
+        assert!(record_malicious_issue(7, owner, name, "alice"));
+        assert_eq!(
+            get_issue_record(owner, name, 7)
+                .unwrap()
+                .claimed_by_hotkey
+                .as_deref(),
+            Some("hk-a")
+        );
+
+        assert!(unregister_user("hk-a", 2_000).is_some());
+        recount_all_balances();
+        assert_eq!(get_user_balance("hk-a").invalid_count, 1);
+    }
+
+    #[test]
+    fn test_rate_limit_defaults_until_set() {
+        crate::storage::host::reset(1);
+        assert_eq!(get_rate_limit_per_minute(), DEFAULT_RATE_LIMIT_PER_MINUTE);
+        assert!(store_rate_limit_per_minute(0));
+        assert_eq!(get_rate_limit_per_minute(), 0);
+        assert!(store_rate_limit_per_minute(25));
+        assert_eq!(get_rate_limit_per_minute(), 25);
+    }
+
+    #[test]
+    fn test_github_api_url_defaults_until_set() {
+        crate::storage::host::reset(1);
+        assert_eq!(get_github_api_url(), "https://api.github.com");
+        assert!(store_github_api_url(" https://ghe.example.com/api/v3/ "));
+        assert_eq!(get_github_api_url(), "https://ghe.example.com/api/v3");
+    }
+
+    #[test]
+    fn test_sync_deadline_defaults_until_set() {
+        crate::storage::host::reset(1);
+        assert_eq!(get_sync_deadline_secs(), 120);
+        assert!(store_sync_deadline_secs(45));
+        assert_eq!(get_sync_deadline_secs(), 45);
+    }
+
+    #[test]
+    fn test_claim_issue_outcomes() {
+        crate::storage::host::reset(4);
+        assert!(!is_issue_recorded("PlatformNetwork", "bounty-challenge", 7));
+
+        let first = claim_issue(7, "PlatformNetwork", "bounty-challenge", "alice", "hk-a");
+        assert!(matches!(first, ClaimOutcome::Claimed));
+        let record = get_issue_record("PlatformNetwork", "bounty-challenge", 7).unwrap();
+        assert_eq!(record.claimed_by_hotkey.as_deref(), Some("hk-a"));
+        assert_eq!(record.recorded_epoch, 4);
+
+        // Neither the owner nor anyone else can record it again, and the
+        // first record is left as it was.
+        for hotkey in ["hk-a", "hk-b"] {
+            match claim_issue(7, "PlatformNetwork", "bounty-challenge", "alice", hotkey) {
+                ClaimOutcome::AlreadyClaimed(Some(existing)) => {
+                    assert_eq!(existing.claimed_by_hotkey.as_deref(), Some("hk-a"))
+                }
+                _ => panic!("issue 7 claimed twice by {}", hotkey),
+            }
+        }
+
+        // An undecodable record still blocks the claim.
+        let key = issue_key("PlatformNetwork", "bounty-challenge", 8);
+        host_storage_set(&key, b"garbage").unwrap();
+        assert!(matches!(
+            claim_issue(8, "PlatformNetwork", "bounty-challenge", "alice", "hk-a"),
+            ClaimOutcome::AlreadyClaimed(None)
+        ));
+    }
+
+    #[test]
+    fn test_token_bucket_rejects_request_over_limit() {
+        let mut bucket = TokenBucket {
+            credit: 3 * 60_000,
+            updated_ms: 0,
+        };
+        for _ in 0..3 {
+            assert_eq!(take_bucket_token(&mut bucket, 3, 1_000), Ok(()));
+        }
+        // One token comes back every 20s at 3 a minute.
+        assert_eq!(take_bucket_token(&mut bucket, 3, 1_000), Err(20));
+        assert_eq!(take_bucket_token(&mut bucket, 3, 11_000), Err(10));
+        assert_eq!(take_bucket_token(&mut bucket, 3, 21_000), Ok(()));
+
+        // A long pause refills to the burst size, not beyond.
+        take_bucket_token(&mut bucket, 3, 600_000).unwrap();
+        assert_eq!(bucket.credit, 2 * 60_000);
+    }
+
+    #[test]
+    fn test_rate_tokens_are_per_hotkey_and_action() {
+        crate::storage::host::reset(1);
+        for _ in 0..2 {
+            assert!(take_rate_token("claim", "hk-a", 2, 0).is_ok());
+        }
+        assert_eq!(take_rate_token("claim", "hk-a", 2, 0), Err(30));
+        assert!(take_rate_token("claim", "hk-b", 2, 0).is_ok());
+        assert!(take_rate_token("register", "hk-a", 2, 0).is_ok());
+        assert!(take_rate_token("claim", "hk-a", 2, 30_000).is_ok());
+    }
+
+    #[test]
+    fn test_recorded_issues_stay_with_hotkey_after_rename() {
+        let mut record = IssueBuilder::new(1).claimed_by("hk-a").build();
+        record.author = String::from("old-name");
+        // After the rename only the new username resolves.
+        let lookup = |github: &str| (github == "new-name").then(|| String::from("hk-a"));
+
+        assert_eq!(issue_hotkey(&record, lookup).as_deref(), Some("hk-a"));
+
+        record.claimed_by_hotkey = None;
+        record.author = String::from("new-name");
+        assert_eq!(issue_hotkey(&record, lookup).as_deref(), Some("hk-a"));
+        record.author = String::from("old-name");
+        assert_eq!(issue_hotkey(&record, lookup), None);
+    }
+
+    #[test]
+    fn test_issue_failures_count_only_consecutive_runs() {
+        let mut failures = Vec::new();
+        let failed = [(7, String::from("bad labels"))];
+
+        apply_issue_failures(&mut failures, &failed, &[], 1);
+        apply_issue_failures(&mut failures, &failed, &[], 2);
+        assert_eq!(failures[0].consecutive_failures, 2);
+        assert_eq!(failures[0].last_failed_ms, 2);
+
+        // One clean parse starts the count over.
+        apply_issue_failures(&mut failures, &[], &[7], 3);
+        assert!(failures.is_empty());
+        for now in 4..4 + MAX_ISSUE_FAILURES as i64 {
+            assert!(failures.iter().all(|f| !is_dead_letter(f)));
+            apply_issue_failures(&mut failures, &failed, &[], now);
+        }
+        assert!(is_dead_letter(&failures[0]));
+    }
+
+    fn audit_keys() -> Vec<(Vec<u8>, Vec<u8>)> {
+        decode_list_prefix(&host_storage_list_prefix(b"audit:", u32::MAX).unwrap())
+    }
+
+    #[test]
+    fn test_audit_log_is_capped_and_newest_first() {
+        crate::storage::host::reset(1);
+        for i in 0..(MAX_AUDIT_ENTRIES as i64 + 5) {
+            let actor = if i % 2 == 0 { "alice" } else { "bob" };
+            assert!(append_audit(actor, "claim", &serde_json::json!({}), i));
+        }
+        assert_eq!(audit_keys().len(), MAX_AUDIT_ENTRIES);
+        let log = get_audit_log();
+        assert_eq!(log.len(), MAX_AUDIT_ENTRIES);
+        assert_eq!(log[0].timestamp_ms, 5);
+
+        let recent = recent_audit_entries(&log, Some("bob"), 2);
+        let times: Vec<i64> = recent.iter().map(|e| e.timestamp_ms).collect();
+        assert_eq!(
+            times,
+            [MAX_AUDIT_ENTRIES as i64 + 3, MAX_AUDIT_ENTRIES as i64 + 1]
+        );
+        assert_eq!(recent_audit_entries(&log, None, 1)[0].actor, "alice");
+    }
+
+    #[test]
+    fn test_register_writes_one_audit_row() {
+        crate::storage::host::reset(1);
+        let detail = serde_json::json!({ "github_username": "alice" });
+        assert!(register_user("alice", "hk1"));
+        assert!(append_audit("hk1", "register", &detail, 1));
+        let first = audit_keys();
+        assert_eq!(first.len(), 1);
+
+        assert!(register_user("bob", "hk2"));
+        assert!(append_audit("hk2", "register", &detail, 2));
+        let both = audit_keys();
+        assert_eq!(both.len(), 2);
+        // The earlier row is left exactly as it was written.
+        assert_eq!(both[0], first[0]);
+    }
+
+    #[test]
+    fn test_legacy_audit_blob_moves_to_entry_keys() {
+        crate::storage::host::reset(1);
+        let legacy = alloc::vec![audit("alice", "register", 1), audit("bob", "claim", 2)];
+        assert!(host_storage_set(b"audit_log", &bincode::serialize(&legacy).unwrap()).is_ok());
+        assert_eq!(get_audit_log().len(), 2);
+
+        assert!(append_audit("carol", "register", &serde_json::json!({}), 3));
+        let times: Vec<i64> = get_audit_log().iter().map(|e| e.timestamp_ms).collect();
+        assert_eq!(times, [1, 2, 3]);
+        assert_eq!(audit_keys().len(), 3);
+        assert!(host_storage_get(b"audit_log").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_pre_suspicion_leaderboard_still_decodes() {
+        crate::storage::host::reset(1);
+        let old = alloc::vec![(
+            1u32,
+            String::from("hk-a"),
+            String::from("alice"),
+            0.4f64,
+            5u32,
+            1u32,
+            0u32,
+            2u32,
+            0.5f64,
+            4.0f64,
+            false,
+            7u64,
+            0u32,
+            0u32,
+        )];
+        assert!(host_storage_set(b"leaderboard", &bincode::serialize(&old).unwrap()).is_ok());
+
+        let entries = get_leaderboard();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].github_username, "alice");
+        assert_eq!(entries[0].last_epoch, 7);
+        assert!(!entries[0].suspicious);
+    }
+
+    #[test]
+    fn test_pre_policy_issue_blob_still_decodes() {
+        crate::storage::host::reset(1);
+        assert!(store_valid_label("Accepted"));
+        // Field order of an IssueRecord before labels, assignee and
+        // milestone were kept.
+        let old = alloc::vec![(
+            7u32,
+            String::from("PlatformNetwork"),
+            String::from("bounty-challenge"),
+            String::from("alice"),
+            true,
+            true,
+            false,
+            true,
+            None::<String>,
+            1u64,
+            false,
+            false,
+            1_700_000_000_000i64,
+        )];
+        assert!(host_storage_set(b"synced_issues", &bincode::serialize(&old).unwrap()).is_ok());
+
+        let issues = get_synced_issues();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].labels, ["ide", "accepted"]);
+        assert_eq!(issues[0].created_at_ms, 1_700_000_000_000);
+        assert!(
+            crate::validation::verify_issue_validity(&issues[0], &get_verification_policy())
+                .is_verified()
+        );
+    }
+
+    fn miner(hotkey: &str, github: &str, valid: u32) -> ExportMiner {
+        ExportMiner {
+            hotkey: String::from(hotkey),
+            github_username: String::from(github),
+            registered_epoch: 7,
+            balance: UserBalance {
+                valid_count: valid,
+                duplicate_count: 1,
+                star_count: 2,
+                ..Default::default()
+            },
+            is_banned: false,
+            weight: valid as f64 / 4.0,
+        }
+    }
+
+    fn export_from(
+        hotkeys: &[String],
+        db: &alloc::collections::BTreeMap<String, ExportMiner>,
+        issues: &[IssueRecord],
+    ) -> ExportBundle {
+        let mut bundle = ExportBundle::default();
+        export_rows(
+            hotkeys,
+            |h| db.get(h).cloned(),
+            issues,
+            |row| bundle.push(row),
+        );
+        bundle
+    }
+
+    fn seeded_export() -> ExportBundle {
+        let db: alloc::collections::BTreeMap<String, ExportMiner> =
+            [miner("hk-a", "alice", 3), miner("hk-b", "bob", 1)]
+                .into_iter()
+                .map(|m| (m.hotkey.clone(), m))
+                .collect();
+        // "hk-gone" is indexed but has no registration; "hk-a" is indexed twice.
+        let hotkeys: Vec<String> = ["hk-a", "hk-b", "hk-gone", "hk-a"]
+            .iter()
+            .map(|h| String::from(*h))
+            .collect();
+        let issues = [
+            IssueBuilder::new(1).claimed_by("hk-a").build(),
+            IssueBuilder::new(2).build(),
+            IssueBuilder::new(3).claimed_by("hk-a").build(),
+            IssueBuilder::new(4).claimed_by("hk-b").build(),
+        ];
+        export_from(&hotkeys, &db, &issues)
+    }
+
+    #[test]
+    fn test_export_round_trips_seeded_data() {
+        let bundle = seeded_export();
+
+        let json = serde_json::to_string(&bundle).unwrap();
+        let restored: ExportBundle = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.miners.len(), 2);
+        assert_eq!(restored.bounties.len(), 3);
+        let valid: u32 = restored.miners.iter().map(|m| m.balance.valid_count).sum();
+        assert_eq!(valid, 4);
+        assert_eq!(restored.miners[1].github_username, "bob");
+        assert_eq!(restored.miners[1].balance.duplicate_count, 1);
+        assert_eq!(restored.miners[1].balance.star_count, 2);
+        assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+    }
+
+    #[test]
+    fn test_export_import_export_is_identical() {
+        let bundle = seeded_export();
+        let (miners, bounties, report) = check_import(&bundle);
+        assert_eq!(report, ImportReport::default());
+
+        // Restore into a fresh store, importing the bounties twice.
+        let db: alloc::collections::BTreeMap<String, ExportMiner> = miners
+            .into_iter()
+            .map(|m| (m.hotkey.clone(), m.clone()))
+            .collect();
+        let mut issues = Vec::new();
+        upsert_issues(&mut issues, &bounties);
+        upsert_issues(&mut issues, &bounties);
+        assert_eq!(issues.len(), 3);
+
+        let hotkeys: Vec<String> = db.keys().cloned().collect();
+        let again = export_from(&hotkeys, &db, &issues);
+        assert_eq!(
+            serde_json::to_string(&again).unwrap(),
+            serde_json::to_string(&bundle).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_import_skips_orphan_bounties_and_incomplete_miners() {
+        let mut bundle = seeded_export();
+        bundle.miners.push(miner("hk-c", "", 0));
+        bundle
+            .bounties
+            .push(IssueBuilder::new(9).claimed_by("hk-unknown").build());
+        bundle
+            .bounties
+            .push(IssueBuilder::new(10).claimed_by("hk-c").build());
+
+        let (miners, bounties, report) = check_import(&bundle);
+        assert_eq!(miners.len(), 2);
+        assert_eq!(bounties.len(), 3);
+        assert_eq!(report.miners_skipped, 1);
+        assert_eq!(report.bounties_skipped, 2);
+    }
+
+    #[test]
+    fn test_imported_bounties_cannot_be_claimed_again() {
+        crate::storage::host::reset(9);
+        let report = import_all(&seeded_export());
+        assert_eq!(report.bounties_imported, 3);
+        assert_eq!(report.bounties_skipped, 0);
+        assert_eq!(get_synced_issues().len(), 3);
+
+        let (owner, name) = (GITHUB_REPO_OWNER, GITHUB_REPO_NAME);
+        match claim_issue(3, owner, name, "alice", "hk-b") {
+            ClaimOutcome::AlreadyClaimed(Some(existing)) => {
+                assert_eq!(existing.claimed_by_hotkey.as_deref(), Some("hk-a"))
+            }
+            _ => panic!("imported bounty 3 was claimed again"),
+        }
+        // The unclaimed issue 2 was not exported, so it is still free.
+        assert!(matches!(
+            claim_issue(2, owner, name, "alice", "hk-a"),
+            ClaimOutcome::Claimed
+        ));
+    }
+}
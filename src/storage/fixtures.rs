@@ -0,0 +1,72 @@
+//! Test-only builder for `IssueRecord`s, shared by every module's unit tests
+//! so a new record field is filled in one place.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use crate::github_sync::{GITHUB_REPO_NAME, GITHUB_REPO_OWNER};
+use crate::types::IssueRecord;
+
+/// Starts from a closed, unclaimed issue by `alice` in the synced
+/// repository, labelled `ide` and `valid`.
+pub(crate) struct IssueBuilder(IssueRecord);
+
+impl IssueBuilder {
+    pub(crate) fn new(issue_number: u32) -> Self {
+        Self(IssueRecord {
+            issue_number,
+            repo_owner: String::from(GITHUB_REPO_OWNER),
+            repo_name: String::from(GITHUB_REPO_NAME),
+            author: String::from("alice"),
+            is_closed: true,
+            has_valid_label: true,
+            has_invalid_label: false,
+            has_ide_label: true,
+            claimed_by_hotkey: None,
+            recorded_epoch: 1,
+            has_duplicate_label: false,
+            has_malicious_label: false,
+            created_at_ms: 0,
+            labels: alloc::vec![String::from("ide"), String::from("valid")],
+            assignee: None,
+            milestone: None,
+        })
+    }
+
+    pub(crate) fn author(mut self, author: &str) -> Self {
+        self.0.author = String::from(author);
+        self
+    }
+
+    pub(crate) fn claimed_by(mut self, hotkey: &str) -> Self {
+        self.0.claimed_by_hotkey = Some(String::from(hotkey));
+        self
+    }
+
+    pub(crate) fn open(mut self) -> Self {
+        self.0.is_closed = false;
+        self
+    }
+
+    /// Replace the labels, setting the label flags to match.
+    pub(crate) fn labels(mut self, labels: &[&str]) -> Self {
+        let labels: Vec<String> = labels.iter().map(|l| String::from(*l)).collect();
+        let has = |name: &str| labels.iter().any(|l| l == name);
+        self.0.has_ide_label = has("ide");
+        self.0.has_valid_label = has("valid");
+        self.0.has_invalid_label = has("invalid");
+        self.0.has_duplicate_label = has("duplicate");
+        self.0.has_malicious_label = has("malicious");
+        self.0.labels = labels;
+        self
+    }
+
+    pub(crate) fn created_at(mut self, created_at_ms: i64) -> Self {
+        self.0.created_at_ms = created_at_ms;
+        self
+    }
+
+    pub(crate) fn build(self) -> IssueRecord {
+        self.0
+    }
+}
@@ -0,0 +1,64 @@
+//! Host functions behind `bounty_storage`. Unit tests swap them for a
+//! per-thread in-memory key-value map, so storage and claim logic can be
+//! exercised without a validator.
+
+#[cfg(not(test))]
+pub(crate) use platform_challenge_sdk_wasm::host_functions::{
+    host_consensus_get_epoch, host_storage_get, host_storage_list_prefix, host_storage_set,
+};
+
+#[cfg(test)]
+pub(crate) use memory::*;
+
+#[cfg(test)]
+mod memory {
+    use alloc::collections::BTreeMap;
+    use alloc::vec::Vec;
+    use core::cell::{Cell, RefCell};
+
+    std::thread_local! {
+        static STORE: RefCell<BTreeMap<Vec<u8>, Vec<u8>>> = const { RefCell::new(BTreeMap::new()) };
+        static EPOCH: Cell<i64> = const { Cell::new(0) };
+    }
+
+    /// Missing keys read as empty, like on the host.
+    pub(crate) fn host_storage_get(key: &[u8]) -> Result<Vec<u8>, i32> {
+        Ok(STORE.with(|s| s.borrow().get(key).cloned().unwrap_or_default()))
+    }
+
+    /// Writing an empty value deletes the key.
+    pub(crate) fn host_storage_set(key: &[u8], value: &[u8]) -> Result<(), i32> {
+        STORE.with(|s| {
+            let mut store = s.borrow_mut();
+            if value.is_empty() {
+                store.remove(key);
+            } else {
+                store.insert(key.to_vec(), value.to_vec());
+            }
+        });
+        Ok(())
+    }
+
+    /// Bincode-encoded `(key, value)` pairs, in key order.
+    pub(crate) fn host_storage_list_prefix(prefix: &[u8], limit: u32) -> Result<Vec<u8>, i32> {
+        let pairs: Vec<(Vec<u8>, Vec<u8>)> = STORE.with(|s| {
+            s.borrow()
+                .range(prefix.to_vec()..)
+                .take_while(|(k, _)| k.starts_with(prefix))
+                .take(limit as usize)
+                .map(|(k, v)| (k.clone(), v.clone()))
+                .collect()
+        });
+        bincode::serialize(&pairs).map_err(|_| -1)
+    }
+
+    pub(crate) fn host_consensus_get_epoch() -> i64 {
+        EPOCH.with(Cell::get)
+    }
+
+    /// Start the current test from empty storage at `epoch`.
+    pub(crate) fn reset(epoch: i64) {
+        STORE.with(|s| s.borrow_mut().clear());
+        EPOCH.with(|e| e.set(epoch));
+    }
+}
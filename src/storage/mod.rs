@@ -1,3 +1,6 @@
 pub mod bounty_storage;
+#[cfg(test)]
+pub(crate) mod fixtures;
+pub(crate) mod host;
 
 pub use bounty_storage::*;
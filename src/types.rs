@@ -24,10 +24,18 @@ pub struct RegisterRequest {
     pub timestamp: i64,
 }
 
-/// Simplified claim request - authentication done via headers
+/// Simplified claim request - authentication done via headers.
+/// Accepts either a single `issue_url` or a batch in `issue_urls`.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ClaimRequest {
+    #[serde(default)]
     pub issue_url: String,
+    #[serde(default)]
+    pub issue_urls: Vec<String>,
+    /// Client-chosen key; a retry with the same key (per hotkey) returns the
+    /// first result instead of re-running the claim.
+    #[serde(default)]
+    pub idempotency_key: String,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -56,6 +64,15 @@ pub struct IssueRecord {
     pub has_malicious_label: bool,
     #[serde(default)]
     pub created_at_ms: i64,
+    /// All labels on the issue, lowercase.
+    #[serde(default)]
+    pub labels: Vec<String>,
+    /// Login of the assignee, lowercase.
+    #[serde(default)]
+    pub assignee: Option<String>,
+    /// Title of the milestone the issue is filed under.
+    #[serde(default)]
+    pub milestone: Option<String>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -97,6 +114,10 @@ pub struct LeaderboardEntry {
     pub duplicate_issues: u32,
     #[serde(default)]
     pub malicious_issues: u32,
+    /// Flagged by the claim heuristics for operator review (see
+    /// `abuse::SuspicionConfig`); has no effect on points.
+    #[serde(default)]
+    pub suspicious: bool,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -105,6 +126,18 @@ pub struct LeaderboardResponse {
     pub entries: Vec<LeaderboardEntry>,
 }
 
+/// One miner's position on the leaderboard, from `GET /leaderboard/:hotkey/rank`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RankResponse {
+    pub hotkey: String,
+    pub rank: u32,
+    pub score: f64,
+    pub total_miners: u32,
+    /// Share of miners ranked at or above this one, in percent: rank 12 of
+    /// 340 is 3.53, i.e. the top 4%.
+    pub percentile: f64,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct StatsResponse {
     pub total_bounties: u64,
@@ -122,7 +155,16 @@ pub struct StatusResponse {
     pub valid_issues_count: u32,
     pub invalid_issues_count: u32,
     pub balance: UserBalance,
+    /// Net points after penalties (see `scoring::PenaltyConfig`).
+    #[serde(default)]
+    pub net_points: f64,
     pub weight: f64,
+    /// Flagged for operator review; has no effect on points.
+    #[serde(default)]
+    pub suspicious: bool,
+    /// When the scores were last refreshed (Unix ms, 0 if never).
+    #[serde(default)]
+    pub last_refreshed: i64,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -131,6 +173,87 @@ pub struct ClaimResult {
     pub rejected: Vec<RejectedIssue>,
     pub total_valid: u32,
     pub score: f64,
+    #[serde(default)]
+    pub summary: ClaimSummary,
+    /// One-line description of the outcome, e.g. "Claimed 3 new issues; 2
+    /// rejected (1 already claimed, 1 author mismatch)".
+    #[serde(default)]
+    pub message: String,
+}
+
+/// Counts behind a [`ClaimResult`].
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClaimSummary {
+    pub newly_claimed: u32,
+    /// Rejected issues, not counting `already_had`.
+    pub rejected: u32,
+    /// Issues the claiming hotkey had already been credited with. They are
+    /// listed under `rejected` as "Issue already claimed".
+    pub already_had: u32,
+}
+
+/// Result of `POST /validate`. Errors make the submission invalid; warnings
+/// are advisory and do not block a claim.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ValidationResponse {
+    pub valid: bool,
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+/// A claim result kept for idempotent retries.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CachedClaim {
+    pub stored_at_ms: i64,
+    pub result: ClaimResult,
+}
+
+/// A hotkey's remaining request allowance for one rate-limited action.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct TokenBucket {
+    /// Tokens times 60 000, so refilling at N a minute adds N per millisecond.
+    pub credit: u64,
+    pub updated_ms: i64,
+}
+
+/// A miner's net points in a daily leaderboard snapshot.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SnapshotEntry {
+    pub hotkey: String,
+    pub github_username: String,
+    pub net_points: f64,
+}
+
+/// Leaderboard state recorded once per UTC day for `/leaderboard/movers`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LeaderboardSnapshot {
+    /// Days since the Unix epoch (UTC).
+    pub day: i64,
+    pub taken_at_ms: i64,
+    pub entries: Vec<SnapshotEntry>,
+}
+
+/// Consecutive sync failures for one issue. Issues that reach
+/// `storage::MAX_ISSUE_FAILURES` are dead-lettered and skipped by later syncs
+/// until the sudo owner resets them.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IssueFailure {
+    pub issue_number: u32,
+    pub consecutive_failures: u32,
+    pub last_reason: String,
+    pub last_failed_ms: i64,
+}
+
+/// One state-changing request, kept for dispute resolution.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub timestamp_ms: i64,
+    pub epoch: u64,
+    /// SS58 hotkey that made the request.
+    pub actor: String,
+    pub action: String,
+    /// JSON-encoded details (bincode cannot store a `serde_json::Value`).
+    pub detail: String,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -183,6 +306,21 @@ pub struct IssuesStatsResponse {
     pub valid: u64,
     pub invalid: u64,
     pub pending: u64,
+    /// Issues recorded as claimed by a hotkey.
+    pub credited: u64,
+    pub uncredited: u64,
+    /// Closed, valid issues whose author has no registered hotkey: bounties
+    /// nobody can claim yet.
+    pub unclaimed: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct IssueClaimStatusResponse {
+    pub claimed: bool,
+    pub miner_hotkey: Option<String>,
+    pub github_username: Option<String>,
+    /// Epoch the claim was recorded in.
+    pub validated_epoch: Option<u64>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -222,6 +360,52 @@ pub struct BulkMigrationRequest {
     pub entries: Vec<MigrationEntry>,
 }
 
+/// One miner in an [`ExportBundle`].
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ExportMiner {
+    pub hotkey: String,
+    pub github_username: String,
+    pub registered_epoch: u64,
+    pub balance: UserBalance,
+    pub is_banned: bool,
+    /// Normalized weight at export time.
+    pub weight: f64,
+}
+
+/// A single row produced by `storage::export_all`.
+pub enum ExportRow {
+    Miner(ExportMiner),
+    /// A synced issue credited to a miner.
+    Bounty(IssueRecord),
+}
+
+/// Full miner data dump returned by `/sudo/export` and accepted by
+/// `/sudo/import`.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ExportBundle {
+    pub exported_at_ms: i64,
+    pub miners: Vec<ExportMiner>,
+    pub bounties: Vec<IssueRecord>,
+}
+
+impl ExportBundle {
+    pub fn push(&mut self, row: ExportRow) {
+        match row {
+            ExportRow::Miner(miner) => self.miners.push(miner),
+            ExportRow::Bounty(issue) => self.bounties.push(issue),
+        }
+    }
+}
+
+/// Counts reported by `/sudo/import`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImportReport {
+    pub miners_imported: u32,
+    pub miners_skipped: u32,
+    pub bounties_imported: u32,
+    pub bounties_skipped: u32,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct HotkeyDetailsResponse {
     pub hotkey: String,
@@ -237,6 +421,26 @@ pub struct HotkeyDetailsResponse {
     pub is_penalized: bool,
     pub weight: f64,
     pub recent_issues: Vec<IssueShort>,
+    #[serde(default)]
+    pub weight_breakdown: WeightBreakdown,
+}
+
+/// How a miner's weight is derived from its balance.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct WeightBreakdown {
+    /// Points from valid issues (1 per issue).
+    pub issue_points: f64,
+    pub invalid_penalty: f64,
+    pub duplicate_penalty: f64,
+    pub malicious_penalty: f64,
+    pub star_bonus: f64,
+    /// `issue_points + star_bonus - penalties`, floored at 0.
+    pub net_points: f64,
+    /// Share of total weight after normalization across all miners.
+    pub normalized_weight: f64,
+    /// Miners with weight that `normalized_weight` is normalized against.
+    #[serde(default)]
+    pub miners: u32,
 }
 
 /// Metrics attached to each evaluation for multi-validator aggregation.
@@ -1,34 +1,322 @@
 use alloc::string::String;
 use alloc::vec::Vec;
+use serde::{Deserialize, Serialize};
 
 use crate::storage;
-use crate::types::{BountySubmission, ClaimResult, ClaimedIssue, IssueRecord, RejectedIssue};
+use crate::types::{
+    BountySubmission, ClaimResult, ClaimSummary, ClaimedIssue, IssueRecord, RejectedIssue,
+    UserRegistration, ValidationResponse,
+};
 
-pub fn validate_submission(submission: &BountySubmission) -> bool {
+/// Maximum number of issues a single submission or claim may carry until
+/// the sudo owner sets another limit.
+pub const MAX_CLAIM_BATCH: usize = 100;
+/// Upper bound on issue numbers; anything larger is a client bug.
+pub const MAX_ISSUE_NUMBER: u32 = 10_000_000;
+/// GitHub's limit on username length.
+pub const MAX_GITHUB_USERNAME_LEN: usize = 39;
+
+/// Whether `username` could be a GitHub login: 1 to 39 ASCII letters,
+/// digits and hyphens. Anything else is never sent to GitHub.
+pub fn is_valid_github_username(username: &str) -> bool {
+    !username.is_empty()
+        && username.len() <= MAX_GITHUB_USERNAME_LEN
+        && username
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || b == b'-')
+}
+
+/// Problems found in a submission's `issue_numbers`. Errors reject the
+/// submission; warnings (e.g. duplicates) are informational.
+#[derive(Debug, Default)]
+pub struct IssueNumberCheck {
+    pub errors: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+/// Check `issue_numbers` against a batch limit of `max_batch` issues.
+pub fn check_issue_numbers(issue_numbers: &[u32], max_batch: usize) -> IssueNumberCheck {
+    let mut check = IssueNumberCheck::default();
+
+    if issue_numbers.is_empty() {
+        check
+            .errors
+            .push(String::from("issue_numbers must not be empty"));
+    }
+    if issue_numbers.len() > max_batch {
+        check.errors.push(alloc::format!(
+            "issue_numbers has {} entries, max is {}",
+            issue_numbers.len(),
+            max_batch
+        ));
+    }
+
+    let mut seen = alloc::collections::BTreeSet::new();
+    for (i, &number) in issue_numbers.iter().enumerate() {
+        if number == 0 {
+            check
+                .errors
+                .push(alloc::format!("issue_numbers[{}] must be > 0", i));
+        } else if number > MAX_ISSUE_NUMBER {
+            check.errors.push(alloc::format!(
+                "issue_numbers[{}] must be <= {}",
+                i,
+                MAX_ISSUE_NUMBER
+            ));
+        }
+        if !seen.insert(number) {
+            check.warnings.push(alloc::format!(
+                "issue_numbers[{}] duplicates issue {}",
+                i,
+                number
+            ));
+        }
+    }
+
+    check
+}
+
+/// Field-level problems that make a submission invalid.
+pub fn submission_errors(submission: &BountySubmission) -> Vec<String> {
+    let mut errors = Vec::new();
     if submission.hotkey.is_empty() {
-        return false;
+        errors.push(String::from("missing hotkey"));
     }
     if submission.github_username.is_empty() {
-        return false;
-    }
-    if submission.issue_numbers.is_empty() {
-        return false;
+        errors.push(String::from("missing github_username"));
+    } else if !is_valid_github_username(&submission.github_username) {
+        errors.push(String::from("invalid github_username"));
     }
+    errors.extend(
+        check_issue_numbers(&submission.issue_numbers, storage::get_max_claim_batch()).errors,
+    );
     if submission.signature.is_empty() {
-        return false;
+        errors.push(String::from("missing signature"));
     }
     if submission.repo_owner.is_empty() || submission.repo_name.is_empty() {
-        return false;
+        errors.push(String::from("missing repo_owner or repo_name"));
+    }
+    errors
+}
+
+pub fn validate_submission(submission: &BountySubmission) -> bool {
+    submission_errors(submission).is_empty()
+}
+
+/// Advisory checks on the submission's `github_username`. `registration` is
+/// the participant's stored registration, and `user_exists` looks the
+/// username up on GitHub (`Some(false)` for a 404, `None` when the lookup
+/// itself failed, which is not reported). Clients may still proceed.
+pub fn identity_warnings<F>(
+    submission: &BountySubmission,
+    registration: Option<&UserRegistration>,
+    user_exists: F,
+) -> Vec<String>
+where
+    F: FnOnce(&str) -> Option<bool>,
+{
+    let mut warnings = Vec::new();
+    let username = submission.github_username.as_str();
+    if !is_valid_github_username(username) {
+        return warnings;
+    }
+
+    if user_exists(username) == Some(false) {
+        warnings.push(alloc::format!(
+            "github user '{}' does not exist on GitHub",
+            username
+        ));
+    }
+
+    match registration {
+        Some(reg) if reg.github_username.eq_ignore_ascii_case(username) => {}
+        Some(reg) => warnings.push(alloc::format!(
+            "github user '{}' is not registered to this hotkey (registered: '{}')",
+            username,
+            reg.github_username
+        )),
+        None => warnings.push(alloc::format!(
+            "hotkey is not registered; register github user '{}' before claiming",
+            username
+        )),
+    }
+
+    warnings
+}
+
+/// Validate `submission`, optionally adding the GitHub identity warnings
+/// (one storage read and one GitHub request, so off by default).
+pub fn validation_report(submission: &BountySubmission, check_github: bool) -> ValidationResponse {
+    let errors = submission_errors(submission);
+    let mut warnings =
+        check_issue_numbers(&submission.issue_numbers, storage::get_max_claim_batch()).warnings;
+    if check_github {
+        let registration = storage::get_user_by_hotkey(&submission.hotkey);
+        let token = crate::github_sync::env_github_token();
+        warnings.extend(identity_warnings(
+            submission,
+            registration.as_ref(),
+            |username| crate::github_sync::github_user_exists(username, token.as_deref()),
+        ));
+    }
+    ValidationResponse {
+        valid: errors.is_empty(),
+        errors,
+        warnings,
+    }
+}
+
+/// Parse a GitHub issue URL (`https://github.com/{owner}/{repo}/issues/{number}`)
+/// into `(owner, repo, number)`. Trailing slashes, query strings and `#` fragments
+/// are ignored.
+pub fn parse_issue_url(url: &str) -> Option<(String, String, u32)> {
+    let url = url.trim();
+    let url = url.split(['#', '?']).next().unwrap_or(url);
+    let url = url.trim_end_matches('/');
+
+    let parts: Vec<&str> = url.split('/').collect();
+    if parts.len() < 7 || parts[5] != "issues" {
+        return None;
     }
-    true
+
+    let issue_number: u32 = parts[6].parse().ok()?;
+    Some((String::from(parts[3]), String::from(parts[4]), issue_number))
 }
 
-pub fn validate_issue(issue: &IssueRecord, expected_author: &str) -> (bool, Option<String>) {
-    if !issue.has_ide_label {
-        return (false, Some(String::from("Issue missing 'ide' label")));
+/// Map a claimed repository to the one issues are synced from when it is the
+/// current repository under different casing or one of `former_names`
+/// (lowercase `owner/name`). Other repositories are returned unchanged.
+pub fn canonical_repo(owner: &str, name: &str, former_names: &[String]) -> (String, String) {
+    use crate::github_sync::{GITHUB_REPO_NAME, GITHUB_REPO_OWNER};
+
+    let is_current = owner.eq_ignore_ascii_case(GITHUB_REPO_OWNER)
+        && name.eq_ignore_ascii_case(GITHUB_REPO_NAME);
+    let full = alloc::format!("{}/{}", owner, name).to_lowercase();
+    if is_current || former_names.contains(&full) {
+        (
+            String::from(GITHUB_REPO_OWNER),
+            String::from(GITHUB_REPO_NAME),
+        )
+    } else {
+        (String::from(owner), String::from(name))
     }
-    if !issue.has_valid_label {
-        return (false, Some(String::from("Issue missing 'valid' label")));
+}
+
+/// What a synced issue must satisfy before it can be claimed. Set by the
+/// sudo owner through `/sudo/verification_policy`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VerificationPolicy {
+    /// Labels the issue must all carry, lowercase.
+    #[serde(default)]
+    pub required_labels: Vec<String>,
+    #[serde(default)]
+    pub require_closed: bool,
+    #[serde(default)]
+    pub require_assignee: bool,
+    /// Milestone title the issue must be filed under, compared
+    /// case-insensitively.
+    #[serde(default)]
+    pub required_milestone: Option<String>,
+}
+
+impl Default for VerificationPolicy {
+    fn default() -> Self {
+        Self::for_valid_label(storage::DEFAULT_VALID_LABEL)
+    }
+}
+
+impl VerificationPolicy {
+    /// The policy used until the sudo owner sets one: the `ide` label and
+    /// the configured valid label.
+    pub fn for_valid_label(valid_label: &str) -> Self {
+        Self {
+            required_labels: alloc::vec![String::from("ide"), valid_label.to_lowercase()],
+            require_closed: false,
+            require_assignee: false,
+            required_milestone: None,
+        }
+    }
+
+    /// Lowercase and trim the labels, dropping blanks and repeats, and
+    /// treat a blank milestone as none.
+    pub fn normalized(mut self) -> Self {
+        let mut labels: Vec<String> = Vec::new();
+        for label in &self.required_labels {
+            let label = label.trim().to_lowercase();
+            if !label.is_empty() && !labels.contains(&label) {
+                labels.push(label);
+            }
+        }
+        self.required_labels = labels;
+        self.required_milestone = self
+            .required_milestone
+            .map(|m| String::from(m.trim()))
+            .filter(|m| !m.is_empty());
+        self
+    }
+}
+
+/// The policy conditions an issue failed, each as a rejection reason.
+#[derive(Debug, Default, PartialEq)]
+pub struct VerificationResult {
+    pub failed: Vec<String>,
+}
+
+impl VerificationResult {
+    pub fn is_verified(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+pub fn verify_issue_validity(
+    issue: &IssueRecord,
+    policy: &VerificationPolicy,
+) -> VerificationResult {
+    let mut result = VerificationResult::default();
+    let found = if issue.labels.is_empty() {
+        String::from("none")
+    } else {
+        issue.labels.join(", ")
+    };
+    for label in &policy.required_labels {
+        if !issue.labels.contains(label) {
+            result.failed.push(alloc::format!(
+                "Issue missing '{}' label (found: {})",
+                label,
+                found
+            ));
+        }
+    }
+    if policy.require_closed && !issue.is_closed {
+        result.failed.push(String::from("Issue is not closed"));
+    }
+    if policy.require_assignee && issue.assignee.is_none() {
+        result.failed.push(String::from("Issue has no assignee"));
+    }
+    if let Some(milestone) = &policy.required_milestone {
+        let matches = issue
+            .milestone
+            .as_deref()
+            .is_some_and(|m| m.eq_ignore_ascii_case(milestone));
+        if !matches {
+            result
+                .failed
+                .push(alloc::format!("Issue not in milestone '{}'", milestone));
+        }
+    }
+    result
+}
+
+/// Check an issue against a claim: the verification policy first, then
+/// the checks every claim must pass.
+pub fn validate_issue(
+    issue: &IssueRecord,
+    expected_author: &str,
+    policy: &VerificationPolicy,
+) -> (bool, Option<String>) {
+    let verification = verify_issue_validity(issue, policy);
+    if !verification.is_verified() {
+        return (false, Some(verification.failed.join("; ")));
     }
     if issue.has_invalid_label {
         return (false, Some(String::from("Issue has 'invalid' label")));
@@ -46,55 +334,78 @@ pub fn validate_issue(issue: &IssueRecord, expected_author: &str) -> (bool, Opti
     (true, None)
 }
 
+/// A claim on an already-recorded issue counts as a duplicate only when the
+/// issue belongs to a different miner.
+pub fn is_duplicate_attempt(record: &IssueRecord, hotkey: &str) -> bool {
+    match record.claimed_by_hotkey.as_deref() {
+        Some(owner) => {
+            let owner = crate::ss58::normalize_hotkey(owner).unwrap_or_else(|| String::from(owner));
+            let hotkey =
+                crate::ss58::normalize_hotkey(hotkey).unwrap_or_else(|| String::from(hotkey));
+            owner != hotkey
+        }
+        None => true,
+    }
+}
+
 pub fn process_claims(submission: &BountySubmission, synced_issues: &[IssueRecord]) -> ClaimResult {
     let mut claimed = Vec::new();
     let mut rejected = Vec::new();
+    let policy = storage::get_verification_policy();
+    let (repo_owner, repo_name) = canonical_repo(
+        &submission.repo_owner,
+        &submission.repo_name,
+        &storage::get_former_repo_names(),
+    );
 
-    for &issue_number in &submission.issue_numbers {
-        if storage::is_issue_recorded(&submission.repo_owner, &submission.repo_name, issue_number) {
-            // Only count as duplicate if claimed by a different user
-            if let Some(record) = storage::get_issue_record(
-                &submission.repo_owner,
-                &submission.repo_name,
-                issue_number,
-            ) {
-                if record.claimed_by_hotkey.as_deref() != Some(&submission.hotkey) {
-                    storage::increment_duplicate_count(&submission.hotkey);
-                }
-            }
-            rejected.push(RejectedIssue {
-                issue_number,
-                reason: String::from("Issue already claimed"),
-            });
-            continue;
-        }
+    let mut already_had = 0u32;
 
+    for &issue_number in &submission.issue_numbers {
         let issue = synced_issues.iter().find(|i| {
-            i.issue_number == issue_number
-                && i.repo_owner == submission.repo_owner
-                && i.repo_name == submission.repo_name
+            i.issue_number == issue_number && i.repo_owner == repo_owner && i.repo_name == repo_name
         });
 
         match issue {
             Some(issue_record) => {
-                let (valid, reason) = validate_issue(issue_record, &submission.github_username);
+                let (valid, reason) =
+                    validate_issue(issue_record, &submission.github_username, &policy);
 
                 if valid {
-                    let recorded = storage::record_valid_issue(
+                    match storage::claim_issue(
                         issue_number,
-                        &submission.repo_owner,
-                        &submission.repo_name,
+                        &repo_owner,
+                        &repo_name,
                         &submission.github_username,
                         &submission.hotkey,
-                    );
-
-                    if recorded {
-                        claimed.push(ClaimedIssue { issue_number });
-                    } else {
-                        rejected.push(RejectedIssue {
-                            issue_number,
-                            reason: String::from("Failed to record issue"),
-                        });
+                    ) {
+                        storage::ClaimOutcome::Claimed => {
+                            claimed.push(ClaimedIssue { issue_number });
+                        }
+                        storage::ClaimOutcome::AlreadyClaimed(record) => {
+                            // Only count as duplicate if claimed by a different user
+                            match record.as_ref() {
+                                Some(r) if is_duplicate_attempt(r, &submission.hotkey) => {
+                                    storage::record_duplicate_attempt(
+                                        &submission.hotkey,
+                                        &repo_owner,
+                                        &repo_name,
+                                        issue_number,
+                                    );
+                                }
+                                Some(_) => already_had += 1,
+                                None => {}
+                            }
+                            rejected.push(RejectedIssue {
+                                issue_number,
+                                reason: String::from("Issue already claimed"),
+                            });
+                        }
+                        storage::ClaimOutcome::StorageError => {
+                            rejected.push(RejectedIssue {
+                                issue_number,
+                                reason: String::from("Failed to record issue"),
+                            });
+                        }
                     }
                 } else {
                     rejected.push(RejectedIssue {
@@ -112,14 +423,570 @@ pub fn process_claims(submission: &BountySubmission, synced_issues: &[IssueRecor
         }
     }
 
+    crate::metrics::record_claims(claimed.len(), rejected.len());
+
     let balance = storage::get_user_balance(&submission.hotkey);
     let score =
         crate::scoring::calculate_weight_from_points(balance.valid_count, balance.star_count);
 
-    ClaimResult {
+    let summary = ClaimSummary {
+        newly_claimed: claimed.len() as u32,
+        rejected: (rejected.len() as u32).saturating_sub(already_had),
+        already_had,
+    };
+    let mut result = ClaimResult {
         claimed,
         rejected,
         total_valid: balance.valid_count,
         score,
+        summary,
+        message: String::new(),
+    };
+    result.message = claim_message(&result);
+    result
+}
+
+/// Rejection kinds in the order [`claim_message`] lists them.
+const REJECTION_KINDS: [&str; 6] = [
+    "already claimed",
+    "author mismatch",
+    "not verified",
+    "invalid label",
+    "not found",
+    "storage error",
+];
+
+/// Short kind of a rejection reason produced by [`process_claims`].
+fn rejection_kind(reason: &str) -> &'static str {
+    if reason == "Issue already claimed" {
+        "already claimed"
+    } else if reason.starts_with("Author mismatch") {
+        "author mismatch"
+    } else if reason == "Issue has 'invalid' label" {
+        "invalid label"
+    } else if reason == "Issue not found in synced data" {
+        "not found"
+    } else if reason == "Failed to record issue" {
+        "storage error"
+    } else {
+        // Verification policy failures.
+        "not verified"
+    }
+}
+
+/// Describe `result` in one line from its summary and rejection reasons.
+pub fn claim_message(result: &ClaimResult) -> String {
+    use core::fmt::Write;
+
+    let summary = &result.summary;
+    let mut message = match summary.newly_claimed {
+        0 => String::from("No new issues claimed"),
+        1 => String::from("Claimed 1 new issue"),
+        n => alloc::format!("Claimed {} new issues", n),
+    };
+    if summary.already_had > 0 {
+        let _ = write!(message, "; {} already yours", summary.already_had);
+    }
+    if summary.rejected > 0 {
+        let mut counts = [0u32; REJECTION_KINDS.len()];
+        for rejected in &result.rejected {
+            let kind = rejection_kind(&rejected.reason);
+            if let Some(i) = REJECTION_KINDS.iter().position(|k| *k == kind) {
+                counts[i] += 1;
+            }
+        }
+        // The caller's own earlier claims are reported as "already yours".
+        counts[0] = counts[0].saturating_sub(summary.already_had);
+
+        let parts: Vec<String> = REJECTION_KINDS
+            .iter()
+            .zip(counts)
+            .filter(|(_, count)| *count > 0)
+            .map(|(kind, count)| alloc::format!("{} {}", count, kind))
+            .collect();
+        let _ = write!(
+            message,
+            "; {} rejected ({})",
+            summary.rejected,
+            parts.join(", ")
+        );
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::fixtures::IssueBuilder;
+
+    fn submission(github_username: &str) -> BountySubmission {
+        BountySubmission {
+            hotkey: String::from("miner-a"),
+            github_username: String::from(github_username),
+            issue_numbers: alloc::vec![1],
+            repo_owner: String::from("PlatformNetwork"),
+            repo_name: String::from("bounty-challenge"),
+            signature: alloc::vec![1],
+            timestamp: 0,
+        }
+    }
+
+    fn registration(github_username: &str) -> UserRegistration {
+        UserRegistration {
+            hotkey: String::from("miner-a"),
+            github_username: String::from(github_username),
+            registered_epoch: 1,
+        }
+    }
+
+    #[test]
+    fn test_identity_warnings_for_missing_github_user() {
+        let reg = registration("ghost");
+        let warnings = identity_warnings(&submission("ghost"), Some(&reg), |_| Some(false));
+        assert_eq!(warnings, ["github user 'ghost' does not exist on GitHub"]);
+    }
+
+    #[test]
+    fn test_identity_warnings_for_real_registered_user() {
+        let reg = registration("Alice");
+        let mut looked_up = String::new();
+        let warnings = identity_warnings(&submission("alice"), Some(&reg), |name| {
+            looked_up = String::from(name);
+            Some(true)
+        });
+        assert!(warnings.is_empty(), "{:?}", warnings);
+        assert_eq!(looked_up, "alice");
+
+        // An inconclusive lookup (rate limit, outage) is not reported.
+        assert!(identity_warnings(&submission("alice"), Some(&reg), |_| None).is_empty());
+    }
+
+    #[test]
+    fn test_identity_warnings_for_unlinked_user() {
+        let reg = registration("bob");
+        let warnings = identity_warnings(&submission("alice"), Some(&reg), |_| Some(true));
+        assert_eq!(
+            warnings,
+            ["github user 'alice' is not registered to this hotkey (registered: 'bob')"]
+        );
+
+        let warnings = identity_warnings(&submission("alice"), None, |_| Some(true));
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("not registered"));
+    }
+
+    #[test]
+    fn test_submission_errors() {
+        assert!(submission_errors(&submission("alice")).is_empty());
+        let mut bad = submission("");
+        bad.signature.clear();
+        assert_eq!(
+            submission_errors(&bad),
+            ["missing github_username", "missing signature"]
+        );
+        assert!(!validate_submission(&bad));
+
+        for username in ["../orgs/x", "alice?x=1", "a b", &"a".repeat(40)] {
+            assert_eq!(
+                submission_errors(&submission(username)),
+                ["invalid github_username"],
+                "{}",
+                username
+            );
+        }
+        assert!(is_valid_github_username(&"a".repeat(39)));
+        assert!(is_valid_github_username("Alice-99"));
+    }
+
+    #[test]
+    fn test_identity_warnings_skip_lookup_of_invalid_username() {
+        let warnings = identity_warnings(&submission("../users"), None, |_| {
+            panic!("invalid username looked up on GitHub")
+        });
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_second_miner_claim_counts_as_duplicate() {
+        storage::host::reset(2);
+        let synced = [IssueBuilder::new(42).build()];
+        let mut claim = submission("alice");
+        claim.issue_numbers = alloc::vec![42];
+        assert_eq!(process_claims(&claim, &synced).claimed.len(), 1);
+
+        // The owner claiming again is not penalized.
+        process_claims(&claim, &synced);
+        let owner = storage::get_user_balance("miner-a");
+        assert_eq!(owner.duplicate_count, 0);
+        assert!(!owner.is_penalized);
+
+        let mut other = claim.clone();
+        other.hotkey = String::from("miner-b");
+        let result = process_claims(&other, &synced);
+        assert_eq!(result.rejected[0].reason, "Issue already claimed");
+        let second = storage::get_user_balance("miner-b");
+        assert_eq!(second.duplicate_count, 1);
+        assert!(second.is_penalized);
+
+        // Repeating the same attempt is charged once.
+        process_claims(&other, &synced);
+        assert_eq!(storage::get_user_balance("miner-b").duplicate_count, 1);
+    }
+
+    #[test]
+    fn test_validate_issue_names_custom_label() {
+        let mut record = IssueBuilder::new(7).labels(&["ide", "accepted"]).build();
+        let policy = VerificationPolicy::for_valid_label("Accepted");
+
+        assert_eq!(validate_issue(&record, "alice", &policy), (true, None));
+
+        record.labels.pop();
+        let (valid, reason) = validate_issue(&record, "alice", &policy);
+        assert!(!valid);
+        assert_eq!(
+            reason.as_deref(),
+            Some("Issue missing 'accepted' label (found: ide)")
+        );
+
+        record.labels = alloc::vec![String::from("bug"), String::from("wontfix")];
+        let (_, reason) = validate_issue(&record, "alice", &policy);
+        assert_eq!(
+            reason.as_deref(),
+            Some("Issue missing 'ide' label (found: bug, wontfix); Issue missing 'accepted' label (found: bug, wontfix)")
+        );
+    }
+
+    #[test]
+    fn test_default_policy_requires_ide_and_valid_labels() {
+        let record = IssueBuilder::new(7).claimed_by("miner-a").build();
+        let policy = VerificationPolicy::default();
+        assert!(verify_issue_validity(&record, &policy).is_verified());
+
+        let mut open = record.clone();
+        open.is_closed = false;
+        assert!(verify_issue_validity(&open, &policy).is_verified());
+
+        let mut unlabeled = record;
+        unlabeled.labels = alloc::vec![String::from("valid")];
+        assert_eq!(
+            verify_issue_validity(&unlabeled, &policy).failed,
+            ["Issue missing 'ide' label (found: valid)"]
+        );
+    }
+
+    #[test]
+    fn test_policy_conditions_fail_independently() {
+        let policy = VerificationPolicy {
+            required_labels: alloc::vec![String::from("ide"), String::from("bounty")],
+            require_closed: true,
+            require_assignee: true,
+            required_milestone: Some(String::from("v2.0")),
+        };
+        let mut passing = IssueBuilder::new(7)
+            .claimed_by("miner-a")
+            .labels(&["ide", "bounty"])
+            .build();
+        passing.assignee = Some(String::from("alice"));
+        passing.milestone = Some(String::from("V2.0"));
+        assert!(verify_issue_validity(&passing, &policy).is_verified());
+
+        let mut unlabeled = passing.clone();
+        unlabeled.labels.retain(|l| l != "bounty");
+        assert_eq!(
+            verify_issue_validity(&unlabeled, &policy).failed,
+            ["Issue missing 'bounty' label (found: ide)"]
+        );
+
+        let mut open = passing.clone();
+        open.is_closed = false;
+        assert_eq!(
+            verify_issue_validity(&open, &policy).failed,
+            ["Issue is not closed"]
+        );
+
+        let mut unassigned = passing.clone();
+        unassigned.assignee = None;
+        assert_eq!(
+            verify_issue_validity(&unassigned, &policy).failed,
+            ["Issue has no assignee"]
+        );
+
+        let mut elsewhere = passing.clone();
+        elsewhere.milestone = Some(String::from("v1.0"));
+        assert_eq!(
+            verify_issue_validity(&elsewhere, &policy).failed,
+            ["Issue not in milestone 'v2.0'"]
+        );
+
+        let mut bare = passing;
+        bare.labels.clear();
+        bare.is_closed = false;
+        bare.assignee = None;
+        bare.milestone = None;
+        bare.claimed_by_hotkey = None;
+        assert_eq!(verify_issue_validity(&bare, &policy).failed.len(), 5);
+        let (valid, reason) = validate_issue(&bare, "alice", &policy);
+        assert!(!valid);
+        assert!(reason.unwrap().contains("; Issue is not closed;"));
+    }
+
+    #[test]
+    fn test_policy_normalization() {
+        let policy = VerificationPolicy {
+            required_labels: alloc::vec![
+                String::from(" IDE "),
+                String::from("ide"),
+                String::from(""),
+            ],
+            require_closed: false,
+            require_assignee: false,
+            required_milestone: Some(String::from("  ")),
+        }
+        .normalized();
+        assert_eq!(policy.required_labels, ["ide"]);
+        assert_eq!(policy.required_milestone, None);
+    }
+
+    #[test]
+    fn test_parse_issue_url_requires_issues_path() {
+        assert_eq!(
+            parse_issue_url("https://github.com/PlatformNetwork/bounty-challenge/issues/42/"),
+            Some((
+                String::from("PlatformNetwork"),
+                String::from("bounty-challenge"),
+                42
+            ))
+        );
+        for url in [
+            "https://github.com/PlatformNetwork/bounty-challenge/pull/42",
+            "https://github.com/PlatformNetwork/bounty-challenge/discussions/42",
+            "https://github.com/PlatformNetwork/bounty-challenge/issues/",
+        ] {
+            assert_eq!(parse_issue_url(url), None, "{}", url);
+        }
+    }
+
+    #[test]
+    fn test_claim_on_former_repo_name_matches_synced_issue() {
+        let record = IssueBuilder::new(7).build();
+        let former = [String::from("cortexlm/bounty-challenge")];
+
+        let (owner, repo, number) =
+            parse_issue_url("https://github.com/CortexLM/bounty-challenge/issues/7").unwrap();
+        let (owner, repo) = canonical_repo(&owner, &repo, &former);
+
+        assert_eq!(
+            (owner.as_str(), repo.as_str()),
+            ("PlatformNetwork", "bounty-challenge")
+        );
+        assert_eq!(
+            (
+                record.repo_owner.as_str(),
+                record.repo_name.as_str(),
+                record.issue_number
+            ),
+            (owner.as_str(), repo.as_str(), number)
+        );
+        assert_eq!(
+            validate_issue(&record, "alice", &VerificationPolicy::default()),
+            (true, None)
+        );
+
+        assert_eq!(
+            canonical_repo("platformnetwork", "Bounty-Challenge", &[]),
+            (
+                String::from("PlatformNetwork"),
+                String::from("bounty-challenge")
+            )
+        );
+        assert_eq!(
+            canonical_repo("someone", "else", &former),
+            (String::from("someone"), String::from("else"))
+        );
+    }
+
+    #[test]
+    fn test_process_claims_against_in_memory_storage() {
+        storage::host::reset(3);
+        let synced = [IssueBuilder::new(7).build()];
+
+        let first = process_claims(&submission("alice"), &synced);
+        assert_eq!(first.claimed.len(), 1);
+        assert!(first.rejected.is_empty());
+        let record = storage::get_issue_record("PlatformNetwork", "bounty-challenge", 7).unwrap();
+        assert_eq!(record.claimed_by_hotkey.as_deref(), Some("miner-a"));
+        assert_eq!(record.recorded_epoch, 3);
+
+        // The same miner retrying is not a duplicate; another miner is.
+        let retry = process_claims(&submission("alice"), &synced);
+        assert_eq!(retry.rejected[0].reason, "Issue already claimed");
+        assert_eq!(storage::get_user_balance("miner-a").duplicate_count, 0);
+
+        let mut other = submission("alice");
+        other.hotkey = String::from("miner-b");
+        assert!(process_claims(&other, &synced).claimed.is_empty());
+        assert_eq!(storage::get_user_balance("miner-b").duplicate_count, 1);
+    }
+
+    #[test]
+    fn test_claim_summary_counts_mixed_outcomes() {
+        storage::host::reset(3);
+        let synced: Vec<IssueRecord> = [7, 8, 9]
+            .into_iter()
+            .map(|n| IssueBuilder::new(n).build())
+            .collect();
+        let mut first = submission("alice");
+        first.issue_numbers = alloc::vec![7];
+        assert_eq!(
+            process_claims(&first, &synced).message,
+            "Claimed 1 new issue"
+        );
+
+        // 7 is already ours, 8 and 9 are new, 10 was never synced.
+        let mut again = submission("alice");
+        again.issue_numbers = alloc::vec![7, 8, 9, 10];
+        let result = process_claims(&again, &synced);
+        assert_eq!(
+            result.summary,
+            ClaimSummary {
+                newly_claimed: 2,
+                rejected: 1,
+                already_had: 1,
+            }
+        );
+        assert_eq!(
+            result.message,
+            "Claimed 2 new issues; 1 already yours; 1 rejected (1 not found)"
+        );
+
+        // Another miner's issue is a rejection, not "already yours".
+        let mut other = submission("alice");
+        other.hotkey = String::from("miner-b");
+        other.issue_numbers = alloc::vec![8];
+        let result = process_claims(&other, &synced);
+        assert_eq!(result.summary.rejected, 1);
+        assert_eq!(result.summary.already_had, 0);
+        assert_eq!(
+            result.message,
+            "No new issues claimed; 1 rejected (1 already claimed)"
+        );
+    }
+
+    #[test]
+    fn test_claim_message_groups_rejections() {
+        let rejected = |issue_number: u32, reason: &str| RejectedIssue {
+            issue_number,
+            reason: String::from(reason),
+        };
+        let mut result = ClaimResult {
+            claimed: (1..=3)
+                .map(|issue_number| ClaimedIssue { issue_number })
+                .collect(),
+            rejected: alloc::vec![
+                rejected(4, "Author mismatch: expected alice, got bob"),
+                rejected(5, "Issue already claimed"),
+            ],
+            total_valid: 3,
+            score: 0.06,
+            summary: ClaimSummary {
+                newly_claimed: 3,
+                rejected: 2,
+                already_had: 0,
+            },
+            message: String::new(),
+        };
+        assert_eq!(
+            claim_message(&result),
+            "Claimed 3 new issues; 2 rejected (1 already claimed, 1 author mismatch)"
+        );
+
+        result.claimed.clear();
+        result.rejected = alloc::vec![
+            rejected(
+                6,
+                "Issue missing 'valid' label (found: ide); Issue is not closed"
+            ),
+            rejected(7, "Issue missing 'ide' label (found: valid)"),
+        ];
+        result.summary = ClaimSummary {
+            newly_claimed: 0,
+            rejected: 2,
+            already_had: 0,
+        };
+        assert_eq!(
+            claim_message(&result),
+            "No new issues claimed; 2 rejected (2 not verified)"
+        );
+    }
+
+    #[test]
+    fn test_process_claims_credits_former_repo_name() {
+        storage::host::reset(1);
+        let synced = IssueBuilder::new(9).build();
+        assert!(storage::store_former_repo_names(&[String::from(
+            "CortexLM/bounty-challenge"
+        )]));
+
+        let mut sub = submission("alice");
+        sub.issue_numbers = alloc::vec![9];
+        sub.repo_owner = String::from("CortexLM");
+        let result = process_claims(&sub, &[synced]);
+
+        assert_eq!(result.claimed.len(), 1, "{:?}", result.rejected);
+        assert!(storage::is_issue_recorded(
+            "PlatformNetwork",
+            "bounty-challenge",
+            9
+        ));
+        assert!(!storage::is_issue_recorded(
+            "CortexLM",
+            "bounty-challenge",
+            9
+        ));
+    }
+
+    #[test]
+    fn test_check_issue_numbers_rejects_zero() {
+        let check = check_issue_numbers(&[12, 7, 40, 0], MAX_CLAIM_BATCH);
+
+        assert_eq!(check.errors, ["issue_numbers[3] must be > 0"]);
+        assert!(check.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_check_issue_numbers_warns_on_duplicates() {
+        let check = check_issue_numbers(&[5, 9, 5], MAX_CLAIM_BATCH);
+
+        assert!(check.errors.is_empty());
+        assert_eq!(check.warnings, ["issue_numbers[2] duplicates issue 5"]);
+    }
+
+    #[test]
+    fn test_check_issue_numbers_rejects_oversized_batch() {
+        let numbers: Vec<u32> = (1..=(MAX_CLAIM_BATCH as u32 + 1)).collect();
+
+        let check = check_issue_numbers(&numbers, MAX_CLAIM_BATCH);
+
+        assert_eq!(check.errors.len(), 1);
+        assert!(check.errors[0].contains("max is 100"));
+        assert!(
+            !check_issue_numbers(&[MAX_ISSUE_NUMBER + 1], MAX_CLAIM_BATCH)
+                .errors
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_submission_uses_stored_claim_batch_limit() {
+        storage::host::reset(1);
+        let mut batch = submission("alice");
+        batch.issue_numbers = (1..=3).collect();
+        assert!(submission_errors(&batch).is_empty());
+
+        assert!(storage::store_max_claim_batch(2));
+        assert_eq!(
+            submission_errors(&batch),
+            ["issue_numbers has 3 entries, max is 2"]
+        );
     }
 }
@@ -0,0 +1,64 @@
+//! Challenge API version and client compatibility rules.
+
+/// Version reported by `Challenge::version` and `GET /version`. Bump the
+/// major version on breaking route or payload changes.
+pub const VERSION: &str = "3.0.0";
+
+/// Parse `major.minor.patch` (a leading `v` and pre-release/build suffixes
+/// are ignored). Missing minor/patch components default to 0.
+fn parse(version: &str) -> Option<(u64, u64, u64)> {
+    let version = version.trim().trim_start_matches('v');
+    let core = version.split(['-', '+']).next().unwrap_or(version);
+    let mut parts = core.split('.');
+
+    let major = parts.next()?.parse().ok()?;
+    let minor = match parts.next() {
+        Some(p) => p.parse().ok()?,
+        None => 0,
+    };
+    let patch = match parts.next() {
+        Some(p) => p.parse().ok()?,
+        None => 0,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((major, minor, patch))
+}
+
+/// Semver compatibility: the major versions must match, and for `0.x`
+/// versions the minor must match too. Unparseable versions are incompatible.
+pub fn is_compatible(client: &str, server: &str) -> bool {
+    match (parse(client), parse(server)) {
+        (Some((0, client_minor, _)), Some((0, server_minor, _))) => client_minor == server_minor,
+        (Some((client_major, _, _)), Some((server_major, _, _))) => client_major == server_major,
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_version_is_compatible() {
+        assert!(is_compatible(VERSION, VERSION));
+        assert!(is_compatible("v3.0.0", "3.0.0"));
+    }
+
+    #[test]
+    fn test_minor_and_patch_differences_are_compatible() {
+        assert!(is_compatible("2.0.0", "2.3.1"));
+        assert!(is_compatible("2.4", "2.0.0-rc.1"));
+        assert!(is_compatible("0.3.1", "0.3.9"));
+    }
+
+    #[test]
+    fn test_incompatible_versions() {
+        assert!(!is_compatible("1.9.0", "2.0.0"));
+        assert!(!is_compatible("2.0.0", VERSION));
+        assert!(!is_compatible("0.3.0", "0.4.0"));
+        assert!(!is_compatible("2.0.0", "not-a-version"));
+        assert!(!is_compatible("2.0.0.1", "2.0.0"));
+    }
+}